@@ -0,0 +1,98 @@
+//! Cost of the individual stages behind `X32Console::process()` - buffer
+//! decode, node string parsing, meter blob decode - and of building the
+//! `full_update` request
+//!
+//! Save a baseline with `cargo bench -- --save-baseline main` and compare
+//! a change against it with `cargo bench -- --baseline main`
+#![expect(clippy::single_call_fn)]
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use x32_osc_state::enums::FaderIndex;
+use x32_osc_state::osc::{Buffer, Message};
+use x32_osc_state::x32::updates::MeterBlob;
+use x32_osc_state::x32::{ConsoleMessage, ConsoleRequest, MeterBank};
+
+/// A plain fader message, a node reply, and a meter blob, as received
+fn wire_buffers() -> Vec<(&'static str, Buffer)> {
+    let blob : Vec<u8> = (0..70_u16).flat_map(|v| (f32::from(v) / 70.0).to_le_bytes()).collect();
+
+    [
+        ("fader", Message::builder("/ch/01/mix/fader").float(0.75).build()),
+        ("node", Message::builder("node").string("/ch/01/mix ON  -6.0 ON +0 OFF   -oo\n").build()),
+        ("meters", Message::builder("/meters/1").blob(blob).build()),
+    ].into_iter()
+        .map(|(name, msg)| (name, Buffer::try_from(msg).expect("valid message")))
+        .collect()
+}
+
+/// Node lines for faders, cues, and scenes
+fn node_lines() -> Vec<(&'static str, Message)> {
+    [
+        ("mix", "/ch/01/mix ON  -6.0 ON +0 OFF   -oo"),
+        ("config", "/ch/01/config \"Lead Vocal\" 1 YE 1"),
+        ("cue", "/-show/showfile/cue/012 1200 \"Verse 2\" 1 12 -1 0 1 0 0"),
+        ("scene", "/-show/showfile/scene/012 \"Verse\" \"\" %111111110 1"),
+    ].into_iter()
+        .map(|(name, line)| (name, Message::new_with_string("node", line)))
+        .collect()
+}
+
+/// Decode raw buffers into messages
+fn buffer_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+
+    for (name, buffer) in wire_buffers() {
+        group.throughput(Throughput::Bytes(buffer.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &buffer, |b, buffer| {
+            b.iter(|| black_box(Message::try_from(buffer.clone())));
+        });
+    }
+
+    group.finish();
+}
+
+/// Parse node lines into console messages
+fn node_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("node");
+
+    for (name, msg) in node_lines() {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &msg, |b, msg| {
+            b.iter(|| black_box(ConsoleMessage::try_from(msg)));
+        });
+    }
+
+    group.finish();
+}
+
+/// Decode meter blobs into typed meters
+fn meter_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("meters");
+
+    for id in [0, 1, 2] {
+        let count = MeterBank::from_id(id).value_count().unwrap_or(16);
+        let blob = MeterBlob::from((0..count).flat_map(|_| 0.5_f32.to_le_bytes()).collect::<Vec<u8>>());
+
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(id), &blob, |b, blob| {
+            b.iter(|| black_box(blob.decode(id)));
+        });
+    }
+
+    group.finish();
+}
+
+/// Build the full update request - shared, copied, and encoded from scratch
+fn full_update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("full_update");
+
+    group.bench_function("shared", |b| b.iter(|| black_box(ConsoleRequest::full_update_buffers())));
+    group.bench_function("copied", |b| b.iter(|| black_box(ConsoleRequest::full_update())));
+    group.bench_function("encoded", |b| b.iter(|| {
+        black_box(FaderIndex::all().flat_map(ConsoleRequest::Fader).collect::<Vec<Buffer>>())
+    }));
+
+    group.finish();
+}
+
+criterion_group!(benches, buffer_decode, node_parse, meter_decode, full_update);
+criterion_main!(benches);
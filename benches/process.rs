@@ -0,0 +1,80 @@
+//! `X32Console::process()` throughput for representative traffic
+//!
+//! Save a baseline with `cargo bench -- --save-baseline main` and compare
+//! a change against it with `cargo bench -- --baseline main`
+#![expect(clippy::single_call_fn)]
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use x32_osc_state::osc::{Buffer, Message};
+use x32_osc_state::X32Console;
+
+/// Standard fader, mute, name and color messages across every bank
+fn fader_storm() -> Vec<Buffer> {
+    let mut traffic = vec![];
+
+    for bank in ["ch", "bus", "mtx", "auxin"] {
+        for idx in 1..=6 {
+            let base = format!("/{bank}/{idx:02}");
+            traffic.push(Message::builder(&format!("{base}/mix/fader")).float(0.75).build());
+            traffic.push(Message::builder(&format!("{base}/mix/on")).int(1).build());
+            traffic.push(Message::builder(&format!("{base}/config/name")).string(&format!("{bank} {idx}")).build());
+            traffic.push(Message::builder(&format!("{base}/config/color")).int(3).build());
+        }
+    }
+
+    traffic.into_iter().map(|msg| Buffer::try_from(msg).expect("valid message")).collect()
+}
+
+/// Meter blobs, as sent by `/meters/0` and friends
+fn meter_stream() -> Vec<Buffer> {
+    (0..4)
+        .map(|bank| {
+            let blob : Vec<u8> = (0..70_u16).flat_map(|v| (f32::from(v) / 70.0).to_le_bytes()).collect();
+            Buffer::try_from(Message::builder(&format!("/meters/{bank}")).blob(blob).build()).expect("valid message")
+        })
+        .collect()
+}
+
+/// Node replies for mix, config and the show file
+fn node_dump() -> Vec<Buffer> {
+    let mut lines = vec![];
+
+    for idx in 1..=32 {
+        lines.push(format!("/ch/{idx:02}/mix ON  -6.0 ON +0 OFF   -oo"));
+        lines.push(format!("/ch/{idx:02}/config \"Chan {idx}\" 1 YE {idx}"));
+    }
+    for idx in 0..16 {
+        lines.push(format!("/-show/showfile/cue/{idx:03} {}00 \"Cue {idx}\" 1 {idx} -1 0 1 0 0", idx + 1));
+        lines.push(format!("/-show/showfile/scene/{idx:03} \"Scene {idx}\" \"\" %111111110 1"));
+    }
+
+    lines.into_iter()
+        .map(|line| Buffer::try_from(Message::builder("node").string(&line).build()).expect("valid message"))
+        .collect()
+}
+
+/// Run each traffic mix through a fresh console
+fn process_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("process");
+
+    for (name, traffic) in [
+        ("fader_storm", fader_storm()),
+        ("meter_stream", meter_stream()),
+        ("node_dump", node_dump()),
+    ] {
+        group.throughput(Throughput::Elements(traffic.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), &traffic, |b, traffic| {
+            let mut console = X32Console::new();
+            b.iter(|| {
+                for buffer in traffic {
+                    black_box(console.process(buffer.clone()));
+                }
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, process_throughput);
+criterion_main!(benches);
@@ -0,0 +1,265 @@
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::enums::{Fader, FaderChange, ShowMode};
+use crate::x32::{ConnectionState, Snapshot};
+use crate::X32ProcessResult;
+
+/// Events buffered per subscriber before it is considered lagging
+const CAPACITY : usize = 1024;
+
+/// Names of the [`FaderChange`] flags, as sent in `changed`
+const CHANGE_NAMES : [(FaderChange, &str); 8] = [
+    (FaderChange::LEVEL, "level"),
+    (FaderChange::MUTE, "mute"),
+    (FaderChange::LABEL, "label"),
+    (FaderChange::COLOR, "color"),
+    (FaderChange::PAN, "pan"),
+    (FaderChange::MUTE_GROUPS, "mute_groups"),
+    (FaderChange::DCA_GROUPS, "dca_groups"),
+    (FaderChange::TRIM, "trim"),
+];
+
+// MARK: Frame
+/// JSON frame sent to subscribers, tagged by `type`
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Frame<'a> {
+    /// [`X32ProcessResult::Fader`]
+    Fader {
+        /// new fader state
+        fader : &'a Fader,
+        /// changed parameters
+        changed : Vec<&'static str>,
+    },
+    /// [`X32ProcessResult::CurrentCue`]
+    Cue {
+        /// current cue display string
+        cue : &'a str,
+    },
+    /// [`X32ProcessResult::CueListChanged`]
+    CueList {
+        /// list edited
+        kind : ShowMode,
+        /// entry index
+        index : usize,
+    },
+    /// [`X32ProcessResult::Meters`]
+    Meters {
+        /// meter message index
+        index : usize,
+        /// decoded values
+        values : Vec<f32>,
+    },
+    /// [`X32ProcessResult::Subscription`]
+    Subscription {
+        /// subscription alias
+        alias : &'a str,
+        /// decoded values
+        values : Vec<f32>,
+    },
+    /// [`X32ProcessResult::Solo`]
+    Solo {
+        /// anything soloed
+        active : bool,
+    },
+    /// [`X32ProcessResult::Connection`]
+    Connection {
+        /// link state
+        state : ConnectionState,
+    },
+    /// [`X32ProcessResult::Unhandled`]
+    Unhandled {
+        /// OSC address
+        address : &'a str,
+    },
+    /// full state
+    Snapshot {
+        /// tracked state
+        state : &'a Snapshot,
+    },
+}
+
+/// JSON text for a result, as sent by [`Bridge::publish`]
+///
+/// Frames are objects tagged by `type` - `fader` (with the `fader` state
+/// and the `changed` parameter names), `cue`, `cue_list`, `meters`,
+/// `subscription`, `solo`, `connection`, and `unhandled`.  Meter values
+/// are decoded floats.  [`X32ProcessResult::NoOperation`] and fader
+/// updates that changed nothing have no frame.
+///
+/// ```
+/// use x32_osc_state::{bridge, X32ProcessResult};
+///
+/// let frame = bridge::to_json(&X32ProcessResult::CurrentCue(String::from("1.0.0 :: Intro"))).expect("frame");
+/// assert_eq!(frame, r#"{"type":"cue","cue":"1.0.0 :: Intro"}"#);
+/// assert_eq!(bridge::to_json(&X32ProcessResult::NoOperation), None);
+/// ```
+#[must_use]
+pub fn to_json(result : &X32ProcessResult) -> Option<String> {
+    let frame = match result {
+        X32ProcessResult::NoOperation => return None,
+        X32ProcessResult::Fader((_, change)) if change.is_empty() => return None,
+        X32ProcessResult::Fader((fader, change)) => Frame::Fader {
+            fader,
+            changed : CHANGE_NAMES.iter()
+                .filter(|(flag, _)| change.contains(*flag))
+                .map(|(_, name)| *name)
+                .collect(),
+        },
+        X32ProcessResult::CurrentCue(cue) => Frame::Cue { cue },
+        X32ProcessResult::CueListChanged { kind, index } => Frame::CueList { kind : *kind, index : *index },
+        X32ProcessResult::Meters((index, blob)) => Frame::Meters { index : *index, values : blob.to_vec() },
+        X32ProcessResult::Subscription((alias, blob)) => Frame::Subscription { alias, values : blob.to_vec() },
+        X32ProcessResult::Solo(active) => Frame::Solo { active : *active },
+        X32ProcessResult::Connection(state) => Frame::Connection { state : *state },
+        X32ProcessResult::Unhandled { address } => Frame::Unhandled { address },
+    };
+    serde_json::to_string(&frame).ok()
+}
+
+/// JSON text for a snapshot frame, `{"type":"snapshot","state":{...}}`
+#[must_use]
+pub fn snapshot_json(snapshot : &Snapshot) -> Option<String> {
+    serde_json::to_string(&Frame::Snapshot { state : snapshot }).ok()
+}
+
+// MARK: Bridge
+/// WebSocket server publishing console results as JSON
+///
+/// Every connected client receives the frames from [`to_json`] for each
+/// [`Bridge::publish`]ed result.  The latest [`Bridge::set_snapshot`] is
+/// sent when a client connects, when it sends the text `snapshot`, and in
+/// place of the events a slow client missed.  Incoming text is otherwise
+/// ignored - the bridge is read-only.  The server stops when the bridge
+/// is dropped.
+///
+/// ```no_run
+/// # async fn run() -> std::io::Result<()> {
+/// use x32_osc_state::bridge::Bridge;
+/// use x32_osc_state::x32::Client;
+///
+/// let bridge = Bridge::bind("0.0.0.0:8032".parse().expect("address")).await?;
+/// let mut client = Client::connect("192.168.1.77:10023".parse().expect("address")).await?;
+/// while let Some(result) = client.recv().await {
+///     bridge.publish(&result);
+///     bridge.set_snapshot(&client.console().snapshot());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Bridge {
+    /// JSON frames for subscribers
+    events : broadcast::Sender<Arc<str>>,
+    /// latest snapshot frame
+    snapshot : Arc<Mutex<Option<Arc<str>>>>,
+    /// listening address
+    local : SocketAddr,
+    /// accept task
+    task : JoinHandle<()>,
+}
+
+impl Bridge {
+    /// Listen for WebSocket clients on `addr`
+    ///
+    /// # Errors
+    /// fails if the listener can't be bound
+    pub async fn bind(addr : SocketAddr) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let local = listener.local_addr()?;
+        let (events, _) = broadcast::channel(CAPACITY);
+        let snapshot = Arc::new(Mutex::new(None));
+
+        let task = tokio::spawn(Self::accept_loop(listener, events.clone(), Arc::clone(&snapshot)));
+
+        Ok(Self { events, snapshot, local, task })
+    }
+
+    /// Send a result to every connected client
+    pub fn publish(&self, result : &X32ProcessResult) {
+        if let Some(text) = to_json(result) {
+            let _ = self.events.send(Arc::from(text));
+        }
+    }
+
+    /// Replace the snapshot sent to new, requesting, and lagging clients
+    pub fn set_snapshot(&self, snapshot : &Snapshot) {
+        *Self::lock(&self.snapshot) = snapshot_json(snapshot).map(Arc::from);
+    }
+
+    /// Send a snapshot to every connected client now (and keep it, see
+    /// [`Bridge::set_snapshot`])
+    pub fn publish_snapshot(&self, snapshot : &Snapshot) {
+        self.set_snapshot(snapshot);
+        if let Some(text) = Self::lock(&self.snapshot).clone() {
+            let _ = self.events.send(text);
+        }
+    }
+
+    /// Number of connected clients
+    #[must_use]
+    pub fn subscribers(&self) -> usize {
+        self.events.receiver_count()
+    }
+
+    /// Listening address
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local
+    }
+
+    /// Lock the snapshot, ignoring poisoning
+    fn lock(snapshot : &Mutex<Option<Arc<str>>>) -> MutexGuard<'_, Option<Arc<str>>> {
+        snapshot.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Accept clients, each served by its own task
+    #[expect(clippy::single_call_fn)]
+    async fn accept_loop(listener : TcpListener, events : broadcast::Sender<Arc<str>>, snapshot : Arc<Mutex<Option<Arc<str>>>>) {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(Self::serve(stream, events.subscribe(), Arc::clone(&snapshot)));
+        }
+    }
+
+    /// Handshake and forward frames to one client until it disconnects
+    /// or the bridge is dropped
+    #[expect(clippy::single_call_fn)]
+    async fn serve(stream : TcpStream, mut events : broadcast::Receiver<Arc<str>>, snapshot : Arc<Mutex<Option<Arc<str>>>>) {
+        let Ok(socket) = tokio_tungstenite::accept_async(stream).await else { return };
+        let (mut sink, mut source) = socket.split();
+
+        let mut frame = Self::lock(&snapshot).clone();
+        loop {
+            if let Some(text) = frame.take() {
+                if sink.send(Message::text(&*text)).await.is_err() { return }
+            }
+
+            frame = tokio::select! {
+                event = events.recv() => match event {
+                    Ok(text) => Some(text),
+                    Err(RecvError::Lagged(_)) => Self::lock(&snapshot).clone(),
+                    Err(RecvError::Closed) => return,
+                },
+                request = source.next() => match request {
+                    Some(Ok(Message::Text(text))) if text.trim() == "snapshot" => Self::lock(&snapshot).clone(),
+                    Some(Ok(_)) => None,
+                    Some(Err(_)) | None => return,
+                },
+            };
+        }
+    }
+}
+
+impl Drop for Bridge {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
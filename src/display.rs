@@ -0,0 +1,139 @@
+use super::enums::{CueSummary, ResolvedCue};
+
+// MARK: CueFormat
+/// Cue display formats, used by [`crate::X32Console::active_cue`]
+///
+/// Templates replace `{num}`, `{name}`, `{scene}`, and `{snippet}`
+/// (scene and snippet are `{index:02}:{name}` or `--`)
+///
+/// ```
+/// use x32_osc_state::display::CueFormat;
+///
+/// let fmt = CueFormat::Template(String::from("{num} {name} ({scene})"));
+///
+/// assert_eq!(fmt.format(None), "0.0.0 -- (--)");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CueFormat {
+    /// `{num} :: {name} [{scene}] [{snippet}]`
+    #[default]
+    Full,
+    /// `{num} {name}`, for small displays
+    Short,
+    /// User supplied template
+    Template(String),
+}
+
+impl CueFormat {
+    /// Template string for this format
+    #[must_use]
+    pub fn template(&self) -> &str {
+        match self {
+            Self::Full => "{num} :: {name} [{scene}] [{snippet}]",
+            Self::Short => "{num} {name}",
+            Self::Template(v) => v,
+        }
+    }
+
+    /// Format a cue (or the placeholder for no cue)
+    #[must_use]
+    pub fn format(&self, cue : Option<&ResolvedCue<'_>>) -> String {
+        self.format_summary(cue.map(|v| CueSummary::from(*v)).as_ref())
+    }
+
+    /// Format a cue summary (or the placeholder for no cue), see
+    /// [`crate::X32Console::active_cue_info`]
+    #[must_use]
+    pub fn format_summary(&self, cue : Option<&CueSummary>) -> String {
+        let (num, name, scene, snippet) = cue.map_or_else(
+            || (String::from("0.0.0"), String::from("--"), String::from("--"), String::from("--")),
+            |v| (v.number.clone(), v.name.clone(), v.scene_label(), v.snippet_label())
+        );
+
+        // one pass, so placeholders inside cue or scene names are kept
+        let mut out = String::new();
+        let mut rest = self.template();
+
+        while let Some(start) = rest.find('{') {
+            let (before, tail) = rest.split_at(start);
+            out.push_str(before);
+
+            let field = [("{num}", &num), ("{name}", &name), ("{scene}", &scene), ("{snippet}", &snippet)]
+                .into_iter()
+                .find(|(key, _)| tail.starts_with(key));
+
+            rest = if let Some((key, value)) = field {
+                out.push_str(value);
+                tail.get(key.len()..).unwrap_or_default()
+            } else {
+                out.push('{');
+                tail.get(1..).unwrap_or_default()
+            };
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+// MARK: ExportFormat
+/// Cue list table formats, used by [`crate::X32Console::export_cues`]
+///
+/// Columns are cue number, name, scene, and snippet (scene and snippet
+/// are `{index:02}:{name}` or `--`)
+///
+/// ```
+/// use x32_osc_state::display::ExportFormat;
+///
+/// assert_eq!(ExportFormat::Csv.format(std::iter::empty()), "Cue,Name,Scene,Snippet\n");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values, quoted as needed
+    #[default]
+    Csv,
+    /// Markdown (GitHub flavored) table
+    Markdown,
+}
+
+impl ExportFormat {
+    /// Table header
+    const HEADER : [&'static str; 4] = ["Cue", "Name", "Scene", "Snippet"];
+
+    /// Format cues as a table, one row per cue
+    #[must_use]
+    pub fn format<'a>(&self, cues : impl Iterator<Item = ResolvedCue<'a>>) -> String {
+        let mut out = self.row(&Self::HEADER.map(String::from));
+
+        if *self == Self::Markdown {
+            out.push_str("|---|---|---|---|\n");
+        }
+
+        for cue in cues {
+            out.push_str(&self.row(&[
+                cue.cue.cue_number.clone(),
+                cue.cue.name.clone(),
+                ResolvedCue::link_label(cue.cue.scene, cue.scene),
+                ResolvedCue::link_label(cue.cue.snippet, cue.snippet),
+            ]));
+        }
+        out
+    }
+
+    /// Format a single row, escaping cells
+    fn row(self, cells : &[String; 4]) -> String {
+        match self {
+            Self::Csv => {
+                let cells:Vec<String> = cells.iter().map(|v| if v.contains([',', '"', '\n']) {
+                    format!("\"{}\"", v.replace('"', "\"\""))
+                } else {
+                    v.clone()
+                }).collect();
+                format!("{}\n", cells.join(","))
+            },
+            Self::Markdown => {
+                let cells:Vec<String> = cells.iter().map(|v| v.replace('|', "\\|")).collect();
+                format!("| {} |\n", cells.join(" | "))
+            },
+        }
+    }
+}
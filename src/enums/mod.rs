@@ -1,7 +1,11 @@
+#[cfg(feature = "serde")]
 use serde::ser::{Serialize, Serializer, SerializeStruct};
 use std::fmt;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use super::osc;
 
 /// Pull fader level from node string
@@ -9,11 +13,6 @@ static LVL_STRING: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"^(?<level>[+\-0-9.]+)").expect("unable to compile pattern")
 });
 
-/// Split node string on whitespace, skipping quoted items
-pub static NODE_STRING: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"[^\s"]+|"([^"]*)""#).expect("unable to compile pattern")
-});
-
 /// bundle tag, `#bundle` (8-byte)
 pub const BUNDLE_TAG:[u8;8] = [0x23, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x0];
 /// simple ignored node message - `-prefs/name`, 44-bytes returned
@@ -84,7 +83,8 @@ pub enum PacketError {
     InvalidMessage,
     /// Type conversion failed
     InvalidTypesForMessage,
-    
+    /// Nesting depth or element count over the decoder limit
+    LimitExceeded,
 }
 
 impl fmt::Display for PacketError {
@@ -96,6 +96,7 @@ impl fmt::Display for PacketError {
             Self::InvalidBuffer => "buffer contains invalid data",
             Self::InvalidMessage => "message conversion invalid",
             Self::InvalidTypesForMessage => "type conversion invalid",
+            Self::LimitExceeded => "decoder depth or element limit exceeded",
         })
     }
 }
@@ -120,6 +121,8 @@ pub enum OSCError {
     InvalidTimeUnderflow,
     /// Time overflow
     InvalidTimeOverflow,
+    /// Requested argument does not exist
+    MissingArgument,
 }
 
 impl fmt::Display for OSCError {
@@ -132,6 +135,7 @@ impl fmt::Display for OSCError {
             Self::InvalidTypeConversion => "type conversion invalid",
             Self::InvalidTimeUnderflow => "time too early to represent",
             Self::InvalidTimeOverflow => "time too late to represent",
+            Self::MissingArgument => "argument index out of range",
         })
     }
 }
@@ -147,7 +151,13 @@ pub enum X32Error {
     /// Packet was not understood
     UnimplementedPacket,
     /// Packet was poorly formed (missing data?)
-    MalformedPacket
+    MalformedPacket,
+    /// Snapshot was written by a newer, incompatible version
+    SnapshotVersion,
+    /// Snapshot could not be read
+    InvalidSnapshot,
+    /// Show or snippet file could not be read
+    InvalidFile,
 }
 
 impl fmt::Display for X32Error {
@@ -156,6 +166,9 @@ impl fmt::Display for X32Error {
             Self::InvalidFader => "invalid fader",
             Self::UnimplementedPacket => "unhandled message",
             Self::MalformedPacket => "packet format invalid - not enough arguments",
+            Self::SnapshotVersion => "snapshot version is newer than supported",
+            Self::InvalidSnapshot => "snapshot format invalid",
+            Self::InvalidFile => "show file format invalid",
         })
     }
 }
@@ -164,10 +177,12 @@ impl std::error::Error for X32Error { }
 
 
 // MARK: ShowMode
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Show Control Mode
 pub enum ShowMode {
     /// Tracking cues
+    #[default]
     Cues,
     /// Tracking scenes
     Scenes,
@@ -201,7 +216,12 @@ impl ShowMode {
 
 // MARK: Show Cue
 /// Show cue structure
-#[derive(Debug, Clone)]
+///
+/// The console's cue record has no break or link fields - after the
+/// active flag, scene, and snippet it only carries the cue's MIDI event -
+/// so the scene and snippet are the only links a cue has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShowCue {
     /// Displayed cue number
     pub cue_number : String,
@@ -211,6 +231,89 @@ pub struct ShowCue {
     pub snippet : Option<usize>,
     /// associated scene (or None)
     pub scene : Option<usize>,
+    /// skipped when stepping through the list (shown greyed out on the console)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skip : bool,
+}
+
+// MARK: Resolved Cue
+/// Show cue with its scene and snippet names looked up
+///
+/// Displays using [`super::display::CueFormat::Full`], where unset or
+/// unknown scenes and snippets are shown as `--`
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedCue<'a> {
+    /// index in cue list
+    pub index : usize,
+    /// the cue
+    pub cue : &'a ShowCue,
+    /// associated scene name (or None)
+    pub scene : Option<&'a str>,
+    /// associated snippet name (or None)
+    pub snippet : Option<&'a str>,
+}
+
+impl ResolvedCue<'_> {
+    /// Format a linked scene or snippet (`{index:02}:{name}` or `--`)
+    #[must_use]
+    pub fn link_label(index : Option<usize>, name : Option<&str>) -> String {
+        match (index, name) {
+            (Some(d), Some(t)) => format!("{d:02}:{t}"),
+            _ => String::from("--"),
+        }
+    }
+}
+
+// MARK: CueSummary
+/// Active cue, scene, or snippet, for UIs that lay it out themselves -
+/// see [`crate::X32Console::active_cue_info`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CueSummary {
+    /// cue number (`1.2.0`), or the scene / snippet index (`05`)
+    pub number : String,
+    /// cue, scene, or snippet name
+    pub name : String,
+    /// linked (or active) scene, index and name (if known)
+    pub scene : Option<(usize, Option<String>)>,
+    /// linked (or active) snippet, index and name (if known)
+    pub snippet : Option<(usize, Option<String>)>,
+}
+
+impl CueSummary {
+    /// Linked (or active) scene, see [`ResolvedCue::link_label`]
+    #[must_use]
+    pub fn scene_label(&self) -> String {
+        Self::label(self.scene.as_ref())
+    }
+
+    /// Linked (or active) snippet, see [`ResolvedCue::link_label`]
+    #[must_use]
+    pub fn snippet_label(&self) -> String {
+        Self::label(self.snippet.as_ref())
+    }
+
+    /// Format a link
+    fn label(link : Option<&(usize, Option<String>)>) -> String {
+        link.map_or_else(|| String::from("--"), |(i, name)| ResolvedCue::link_label(Some(*i), name.as_deref()))
+    }
+}
+
+impl From<ResolvedCue<'_>> for CueSummary {
+    fn from(v : ResolvedCue<'_>) -> Self {
+        Self {
+            number : v.cue.cue_number.clone(),
+            name : v.cue.name.clone(),
+            scene : v.cue.scene.map(|i| (i, v.scene.map(str::to_owned))),
+            snippet : v.cue.snippet.map(|i| (i, v.snippet.map(str::to_owned))),
+        }
+    }
+}
+
+impl fmt::Display for ResolvedCue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&super::display::CueFormat::Full.format(Some(self)))
+    }
 }
 
 // MARK: Fader Index
@@ -252,7 +355,7 @@ impl FaderIndex {
             Self::Aux(v) => format!("Aux{v:02}"),
             Self::Matrix(v) => format!("Mtx{v:02}"),
             Self::Main(v) => if *v == 2 { String::from("M/C") } else { String::from("Main") },
-            Self::Channel(v) => format!("Ch{v:02}",),
+            Self::Channel(v) => format!("Ch{v:02}"),
             Self::Dca(v) => format!("DCA{v}"),
             Self::Bus(v) => format!("MixBus{v:02}"),
             Self::Unknown => String::new(),
@@ -282,6 +385,34 @@ impl FaderIndex {
         }
     }
 
+    /// Every valid fader, in console order (channels, aux, bus, matrix, dca, main)
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::channels()
+            .chain(Self::aux_inputs())
+            .chain(Self::buses())
+            .chain(Self::matrices())
+            .chain(Self::dcas())
+            .chain(Self::mains())
+    }
+
+    /// Channels, 1-32
+    pub fn channels() -> impl Iterator<Item = Self> { (1..=32).map(Self::Channel) }
+
+    /// Aux inputs, 1-8
+    pub fn aux_inputs() -> impl Iterator<Item = Self> { (1..=8).map(Self::Aux) }
+
+    /// Mix buses, 1-16
+    pub fn buses() -> impl Iterator<Item = Self> { (1..=16).map(Self::Bus) }
+
+    /// Matrix sends, 1-6
+    pub fn matrices() -> impl Iterator<Item = Self> { (1..=6).map(Self::Matrix) }
+
+    /// DCAs, 1-8
+    pub fn dcas() -> impl Iterator<Item = Self> { (1..=8).map(Self::Dca) }
+
+    /// Main stereo and mono
+    pub fn mains() -> impl Iterator<Item = Self> { (1..=2).map(Self::Main) }
+
     /// Get a vector of OSC messages that will force
     /// the X32 to update this fader
     #[must_use]
@@ -301,6 +432,34 @@ impl FaderIndex {
     }
 }
 
+impl fmt::Display for FaderIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.get_x32_address())
+    }
+}
+
+impl std::str::FromStr for FaderIndex {
+    type Err = Error;
+
+    /// Parse a symbolic name - `ch/05`, `ch5`, `dca3`, `bus/12`, `main/st`,
+    /// `main/m`.  The `/` is optional, a leading `/` and case are ignored,
+    /// and `channel`, `aux`, and `matrix` are accepted as bank names
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().trim_start_matches('/').to_ascii_lowercase();
+        let (bank, index) = name.split_once('/')
+            .unwrap_or_else(|| name.split_at(name.find(|c : char| c.is_ascii_digit()).unwrap_or(name.len())));
+
+        match bank {
+            "main" if index.is_empty() => Ok(Self::Main(1)),
+            "channel" => Self::from_parts("ch", index),
+            "aux" => Self::from_parts("auxin", index),
+            "matrix" => Self::from_parts("mtx", index),
+            _ => Self::from_parts(bank, index),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
 impl Serialize for FaderIndex {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -322,6 +481,35 @@ impl Serialize for FaderIndex {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FaderIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// serialized form, `name` is ignored
+        #[derive(serde::Deserialize)]
+        struct Raw {
+            /// 1-based index
+            index : usize,
+            /// bank name
+            #[serde(rename = "type")]
+            kind : String,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let bank = match raw.kind.as_str() {
+            "unknown" => return Ok(Self::Unknown),
+            "aux" => "auxin",
+            "matrix" => "mtx",
+            "channel" => "ch",
+            other => other,
+        };
+
+        Self::from_bank_index(bank, raw.index).map_err(serde::de::Error::custom)
+    }
+}
+
 // MARK: FaderIndexParse
 /// Fader Index parsers
 pub enum FaderIndexParse {
@@ -335,33 +523,54 @@ impl TryFrom<FaderIndexParse> for FaderIndex {
     type Error = Error;
 
     fn try_from(value: FaderIndexParse) -> Result<Self, Self::Error> {
-        let invalid_fader = Error::X32(X32Error::InvalidFader);
-
-        let index = match &value {
-            FaderIndexParse::Integer(_, d) => usize::try_from(*d).map_err(|_| invalid_fader)?,
-            FaderIndexParse::String(s, d) => {
-                if s.as_str() == "main" {
-                    if d.as_str() == "m" { 2 } else { 1 }
-                } else {
-                    d.parse::<usize>().map_err(|_| invalid_fader)?
-                }
-            },
+        match value {
+            FaderIndexParse::Integer(s, d) => Self::from_bank_index(
+                &s,
+                usize::try_from(d).map_err(|_| Error::X32(X32Error::InvalidFader))?
+            ),
+            FaderIndexParse::String(s, d) => Self::from_parts(&s, &d),
+        }
+    }
+}
+
+impl FaderIndex {
+    /// Parse from the first two parts of an OSC address (bank name and 1-based index)
+    ///
+    /// # Errors
+    /// fails on unknown banks and out of range indexes
+    pub fn from_parts(bank : &str, index : &str) -> Result<Self, Error> {
+        let index = if bank == "main" {
+            Self::main_from_part(index)?
+        } else {
+            index.parse::<usize>().map_err(|_| Error::X32(X32Error::InvalidFader))?
         };
 
-        match value {
-            FaderIndexParse::Integer(s, _) |
-            FaderIndexParse::String(s, _) => {
-                match s.as_str() {
-                    _ if index == 0 => Err(invalid_fader),
-                    "mtx" if index <= 6 => Ok(Self::Matrix(index)),
-                    "auxin" if index <= 8 => Ok(Self::Aux(index)),
-                    "dca" if index <= 8 => Ok(Self::Dca(index)),
-                    "main" if index <= 2 => Ok(Self::Main(index)),
-                    "ch" if index <= 32 => Ok(Self::Channel(index)),
-                    "bus" if index <= 16 => Ok(Self::Bus(index)),
-                    _ => Err(invalid_fader)
-                }
-            },
+        Self::from_bank_index(bank, index)
+    }
+
+    /// Main bus index from an address part - `st` is the stereo bus (1) and
+    /// `m` the mono / center bus (2).  The numbered forms are accepted for
+    /// symbolic names and VOR addresses, anything else is not a main bus
+    #[expect(clippy::single_call_fn)]
+    fn main_from_part(part : &str) -> Result<usize, Error> {
+        match part {
+            "st" | "1" | "01" => Ok(1),
+            "m" | "2" | "02" => Ok(2),
+            _ => Err(Error::X32(X32Error::InvalidFader)),
+        }
+    }
+
+    /// Build from bank name and 1-based index
+    fn from_bank_index(bank : &str, index : usize) -> Result<Self, Error> {
+        match bank {
+            _ if index == 0 => Err(Error::X32(X32Error::InvalidFader)),
+            "mtx" if index <= 6 => Ok(Self::Matrix(index)),
+            "auxin" if index <= 8 => Ok(Self::Aux(index)),
+            "dca" if index <= 8 => Ok(Self::Dca(index)),
+            "main" if index <= 2 => Ok(Self::Main(index)),
+            "ch" if index <= 32 => Ok(Self::Channel(index)),
+            "bus" if index <= 16 => Ok(Self::Bus(index)),
+            _ => Err(Error::X32(X32Error::InvalidFader))
         }
     }
 }
@@ -369,7 +578,8 @@ impl TryFrom<FaderIndexParse> for FaderIndex {
 
 /// Fader color
 #[expect(missing_docs)]
-#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FaderColor {
     Off,
     Red,
@@ -432,21 +642,364 @@ impl FaderColor {
             _ => Self::White,
         }
     }
+
+    /// Get the color index (inverse of [`FaderColor::parse_int`])
+    #[must_use]
+    pub fn as_int(&self) -> i32 {
+        match self {
+            Self::Off => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::RedInverted => 9,
+            Self::GreenInverted => 10,
+            Self::YellowInverted => 11,
+            Self::BlueInverted => 12,
+            Self::MagentaInverted => 13,
+            Self::CyanInverted => 14,
+            Self::WhiteInverted => 15,
+        }
+    }
+
+    /// Get the pre-defined color string (inverse of [`FaderColor::parse_str`])
+    #[must_use]
+    pub fn as_const(&self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Red => "RD",
+            Self::Green => "GN",
+            Self::Yellow => "YE",
+            Self::Blue => "BL",
+            Self::Magenta => "MG",
+            Self::Cyan => "CY",
+            Self::White => "WH",
+            Self::RedInverted => "RDi",
+            Self::GreenInverted => "GNi",
+            Self::YellowInverted => "YEi",
+            Self::BlueInverted => "BLi",
+            Self::MagentaInverted => "MGi",
+            Self::CyanInverted => "CYi",
+            Self::WhiteInverted => "WHi",
+        }
+    }
+
+    /// ANSI sequence to reset colors after [`FaderColor::ansi`]
+    pub const ANSI_RESET : &'static str = "\x1b[0m";
+
+    /// Is this an inverted color (dark text on a lit strip)
+    #[must_use]
+    pub const fn is_inverted(&self) -> bool {
+        matches!(self, Self::RedInverted | Self::GreenInverted | Self::YellowInverted |
+            Self::BlueInverted | Self::MagentaInverted | Self::CyanInverted | Self::WhiteInverted)
+    }
+
+    /// Strip color as RGB - inverted colors share the hue of the plain
+    /// color, `Off` is black
+    #[must_use]
+    pub const fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Self::Off => (0, 0, 0),
+            Self::Red | Self::RedInverted => (255, 0, 0),
+            Self::Green | Self::GreenInverted => (0, 255, 0),
+            Self::Yellow | Self::YellowInverted => (255, 255, 0),
+            Self::Blue | Self::BlueInverted => (0, 0, 255),
+            Self::Magenta | Self::MagentaInverted => (255, 0, 255),
+            Self::Cyan | Self::CyanInverted => (0, 255, 255),
+            Self::White | Self::WhiteInverted => (255, 255, 255),
+        }
+    }
+
+    /// Strip color as a CSS hex string, e.g. `#FF0000`
+    #[must_use]
+    pub fn hex(&self) -> String {
+        let (r, g, b) = self.rgb();
+        format!("#{r:02X}{g:02X}{b:02X}")
+    }
+
+    /// ANSI SGR sequence to draw text the way the strip shows it - colored
+    /// text for plain colors, black text on a colored background for
+    /// inverted colors, dim text for `Off`.  Follow with [`FaderColor::ANSI_RESET`]
+    #[must_use]
+    pub const fn ansi(&self) -> &'static str {
+        match self {
+            Self::Off => "\x1b[90m",
+            Self::Red => "\x1b[91m",
+            Self::Green => "\x1b[92m",
+            Self::Yellow => "\x1b[93m",
+            Self::Blue => "\x1b[94m",
+            Self::Magenta => "\x1b[95m",
+            Self::Cyan => "\x1b[96m",
+            Self::White => "\x1b[97m",
+            Self::RedInverted => "\x1b[30;101m",
+            Self::GreenInverted => "\x1b[30;102m",
+            Self::YellowInverted => "\x1b[30;103m",
+            Self::BlueInverted => "\x1b[30;104m",
+            Self::MagentaInverted => "\x1b[30;105m",
+            Self::CyanInverted => "\x1b[30;106m",
+            Self::WhiteInverted => "\x1b[30;107m",
+        }
+    }
+}
+
+// MARK: text width
+/// Leading whole graphemes of `text` that fit in `width` terminal columns,
+/// and the columns they take
+fn fit_columns(text : &str, width : usize) -> (&str, usize) {
+    let mut used = 0;
+    let mut end = 0;
+
+    for (offset, grapheme) in text.grapheme_indices(true) {
+        let columns = grapheme.width();
+        if used + columns > width { break }
+        used += columns;
+        end = offset + grapheme.len();
+    }
+    (text.get(..end).unwrap_or_default(), used)
+}
+
+/// Truncate or right-pad text to exactly `width` terminal columns (left
+/// aligned) - wide characters count as two, and are never split
+#[must_use]
+pub fn fit_width(text : &str, width : usize) -> String {
+    let (text, used) = fit_columns(text, width);
+    text.to_owned() + &" ".repeat(width - used)
+}
+
+/// Truncate or left-pad text to exactly `width` terminal columns (right
+/// aligned) - wide characters count as two, and are never split
+#[must_use]
+pub fn fit_width_right(text : &str, width : usize) -> String {
+    let (text, used) = fit_columns(text, width);
+    " ".repeat(width - used) + text
+}
+
+// MARK: Field Diff
+/// A single parameter that differs between two items
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldDiff {
+    /// parameter name
+    pub field : &'static str,
+    /// display value on the first item
+    pub a : String,
+    /// display value on the second item
+    pub b : String,
+}
+
+impl FieldDiff {
+    /// Compare two lists of named display values, keeping the ones that
+    /// differ.  Fields are matched by name, fields missing from one side
+    /// are reported with an empty value
+    #[must_use]
+    pub fn compare(a : &[(&'static str, String)], b : &[(&'static str, String)]) -> Vec<Self> {
+        let mut diffs:Vec<Self> = a.iter()
+            .filter_map(|(field, a_value)| {
+                let b_value = b.iter().find(|(name, _)| name == field).map_or("", |(_, v)| v.as_str());
+                (a_value != b_value).then(|| Self { field, a : a_value.clone(), b : b_value.to_owned() })
+            })
+            .collect();
+
+        diffs.extend(b.iter()
+            .filter(|(field, _)| !a.iter().any(|(name, _)| name == field))
+            .map(|(field, b_value)| Self { field, a : String::new(), b : b_value.clone() }));
+
+        diffs
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.a, self.b)
+    }
+}
+
+// MARK: Fader Change
+/// Fader parameters changed by an update, as a set of flags
+///
+/// ```
+/// use x32_osc_state::enums::FaderChange;
+///
+/// let change = FaderChange::LEVEL | FaderChange::MUTE;
+/// assert!(change.contains(FaderChange::LEVEL));
+/// assert!(!change.contains(FaderChange::LABEL));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FaderChange(u8);
+
+impl FaderChange {
+    /// Nothing changed
+    pub const NONE : Self = Self(0);
+    /// Fader level
+    pub const LEVEL : Self = Self(1);
+    /// On / off (mute)
+    pub const MUTE : Self = Self(1 << 1);
+    /// Scribble strip label
+    pub const LABEL : Self = Self(1 << 2);
+    /// Scribble strip color
+    pub const COLOR : Self = Self(1 << 3);
+    /// Pan
+    pub const PAN : Self = Self(1 << 4);
+    /// Mute group membership
+    pub const MUTE_GROUPS : Self = Self(1 << 5);
+    /// DCA membership
+    pub const DCA_GROUPS : Self = Self(1 << 6);
+    /// Input trim
+    pub const TRIM : Self = Self(1 << 7);
+
+    /// Are all the flags in `other` set
+    #[must_use]
+    pub fn contains(self, other : Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Nothing changed
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Raw flag bits
+    #[must_use]
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+impl std::ops::BitOr for FaderChange {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output { Self(self.0 | rhs.0) }
+}
+
+impl std::ops::BitOrAssign for FaderChange {
+    fn bitor_assign(&mut self, rhs: Self) { self.0 |= rhs.0; }
+}
+
+/// Last update time of a fader
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Touched(Option<Instant>);
+
+// MARK: Taper
+/// Fader position (0.0 - 1.0) to dB curve
+pub trait Taper : fmt::Debug + Send + Sync {
+    /// dB at a fader position
+    fn level_to_db(&self, level : f32) -> f32;
+
+    /// Fader position for a dB value, clamped to the curve's range
+    fn db_to_level(&self, db : f32) -> f32;
+
+    /// dB at the bottom of the fader, displayed as `-oo`
+    fn min_db(&self) -> f32 { -90_f32 }
+}
+
+/// The X32 / M32 four segment taper, -90 (-oo) to +10 dB
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct X32Taper;
+
+impl Taper for X32Taper {
+    fn level_to_db(&self, level : f32) -> f32 {
+        match level {
+            d if d >= 0.5 => level * 40_f32 - 30_f32,
+            d if d >= 0.25 => level * 80_f32 - 50_f32,
+            d if d >= 0.0625 => level * 160_f32 - 70_f32,
+            _ => level * 480_f32 - 90_f32
+        }
+    }
+
+    fn db_to_level(&self, db : f32) -> f32 {
+        match db.clamp(-90_f32, 10_f32) {
+            d if d < -60.0_f32 => (d + 90.0_f32) / 480.0_f32,
+            d if d < -30.0_f32 => (d + 70.0_f32) / 160.0_f32,
+            d if d < -10.0_f32 => (d + 50.0_f32) / 80.0_f32,
+            d => (d + 30.0_f32) / 40.0_f32,
+        }
+    }
+}
+
+/// Straight line taper between two dB values, e.g. for tests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearTaper {
+    /// dB at the bottom of the fader
+    min_db : f32,
+    /// dB at the top of the fader
+    max_db : f32,
+}
+
+impl LinearTaper {
+    /// New linear taper from `min_db` (-oo) to `max_db`
+    ///
+    /// If the two are equal the taper is a step - any dB below `min_db` is
+    /// the bottom of the fader, anything else the top
+    #[must_use]
+    pub const fn new(min_db : f32, max_db : f32) -> Self {
+        Self { min_db, max_db }
+    }
+}
+
+impl Taper for LinearTaper {
+    fn level_to_db(&self, level : f32) -> f32 {
+        level.clamp(0_f32, 1_f32).mul_add(self.max_db - self.min_db, self.min_db)
+    }
+
+    fn db_to_level(&self, db : f32) -> f32 {
+        let span = self.max_db - self.min_db;
+        if span.abs() < f32::EPSILON { return if db < self.min_db { 0_f32 } else { 1_f32 } }
+        ((db - self.min_db) / span).clamp(0_f32, 1_f32)
+    }
+
+    fn min_db(&self) -> f32 { self.min_db }
+}
+
+/// Taper of a fader - ignored when comparing faders
+#[derive(Debug, Clone, Copy)]
+struct FaderTaper(&'static dyn Taper);
+
+impl Default for FaderTaper {
+    fn default() -> Self { Self(&X32Taper) }
+}
+
+impl PartialEq for FaderTaper {
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+
+impl PartialOrd for FaderTaper {
+    fn partial_cmp(&self, _other: &Self) -> Option<std::cmp::Ordering> { Some(std::cmp::Ordering::Equal) }
 }
 
 /// Internal fader tracking
+///
+/// Equality includes the last update time (see [`Fader::age`]) - use
+/// [`Fader::changes_from`] to compare just the tracked parameters
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct Fader {
     /// fader index, with type. 
     source : FaderIndex,
-    /// scribble strip label
-    label : String,
+    /// scribble strip label, shared with [`Fader::shared_label`]
+    label : Arc<str>,
+    /// label or default name, shared with [`Fader::shared_name`]
+    name : Arc<str>,
     /// level of fader, as number
     level : f32,
     /// mute status, as bool
     is_on : bool,
     /// Fader color
     color : FaderColor,
+    /// pan (0.0 left - 0.5 center - 1.0 right)
+    pan : f32,
+    /// mute group membership (bit 0 is group 1)
+    mute_groups : u8,
+    /// DCA membership (bit 0 is DCA 1)
+    dca_groups : u8,
+    /// input trim in dB, `None` until reported (and on strips without one)
+    trim : Option<f32>,
+    /// last update from the console
+    touched : Touched,
+    /// level to dB curve
+    taper : FaderTaper,
 }
 
 
@@ -455,22 +1008,88 @@ impl Fader {
     #[must_use]
     pub fn new(source : FaderIndex) -> Self {
         Self {
+            name : Arc::from(source.default_label()),
             source,
             color : FaderColor::default(),
-            label : String::new(),
+            label : Arc::from(""),
             level : 0_f32,
-            is_on : false
+            is_on : false,
+            pan : 0.5_f32,
+            mute_groups : 0,
+            dca_groups : 0,
+            trim : None,
+            touched : Touched::default(),
+            taper : FaderTaper::default(),
         }
     }
 
+    /// Use a different level to dB curve (default [`X32Taper`])
+    #[must_use]
+    pub const fn with_taper(mut self, taper : &'static dyn Taper) -> Self {
+        self.taper = FaderTaper(taper);
+        self
+    }
+
+    /// Change the level to dB curve
+    pub const fn set_taper(&mut self, taper : &'static dyn Taper) {
+        self.taper = FaderTaper(taper);
+    }
+
+    /// Level to dB curve
+    #[must_use]
+    pub const fn taper(&self) -> &'static dyn Taper { self.taper.0 }
+
     /// get fader label or default name
     #[must_use]
-    pub fn name(&self) -> String {
-        if self.label.is_empty() {
-            self.source.default_label()
-        } else {
-            self.label.clone()
-        }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// get fader label or default name, as a cheap clone to hold on to
+    #[must_use]
+    pub fn shared_name(&self) -> Arc<str> {
+        Arc::clone(&self.name)
+    }
+
+    /// Width of the on/off field in the vor format
+    pub const VOR_ON_WIDTH: usize = 3;
+    /// Width of the level field in the vor format
+    pub const VOR_LEVEL_WIDTH: usize = 8;
+
+    /// get fader index
+    #[must_use]
+    pub fn source(&self) -> &FaderIndex {
+        &self.source
+    }
+
+    /// get fader label (empty when unset, see [`Fader::name`])
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// get fader label, as a cheap clone to hold on to
+    #[must_use]
+    pub fn shared_label(&self) -> Arc<str> {
+        Arc::clone(&self.label)
+    }
+
+    /// get fader label or default name, truncated or padded to `width` columns
+    #[must_use]
+    pub fn display_name(&self, width : usize) -> String {
+        fit_width(self.name(), width)
+    }
+
+    /// get the on/off status as a vor field (3 characters, right aligned)
+    #[must_use]
+    pub fn vor_on_field(&self) -> String {
+        fit_width_right(&self.is_on().1, Self::VOR_ON_WIDTH)
+    }
+
+    /// get the level as a vor field (8 characters, right aligned)
+    #[must_use]
+    pub fn vor_level_field(&self) -> String {
+        fit_width_right(&self.level().1, Self::VOR_LEVEL_WIDTH)
     }
 
     /// Get color
@@ -482,7 +1101,28 @@ impl Fader {
     /// get fader level
     #[must_use]
     pub fn level(&self) -> (f32, String) {
-        ( self.level, Self::level_to_string(self.level) )
+        ( self.level, Self::level_to_string_with(self.taper.0, self.level) )
+    }
+
+    /// get fader level in dB on this fader's taper, [`Taper::min_db`] is
+    /// -oo.  Use [`Taper::db_to_level`] (or [`Fader::level_from_db`]) to turn
+    /// a dB value back into a fader position
+    #[must_use]
+    pub fn level_db(&self) -> f32 {
+        self.taper.0.level_to_db(self.level)
+    }
+
+    /// get fader pan, with `L50` / `C` / `R50` display string
+    #[must_use]
+    pub fn pan(&self) -> (f32, String) {
+        ( self.pan, Self::pan_to_string(self.pan) )
+    }
+
+    /// get input trim, with `+3.0 dB` display string - `None` until
+    /// the console reports it
+    #[must_use]
+    pub fn trim(&self) -> Option<(f32, String)> {
+        self.trim.map(|v| (v, Self::trim_to_string(v)))
     }
 
     /// get fader mute status
@@ -491,37 +1131,204 @@ impl Fader {
         ( self.is_on, String::from(if self.is_on { "ON" } else { "OFF" }) )
     }
 
+    /// get mute group membership, as a bitmask (bit 0 is group 1)
+    #[must_use]
+    pub fn mute_groups(&self) -> u8 {
+        self.mute_groups
+    }
+
+    /// is this fader assigned to a mute group (1 - 6)
+    #[must_use]
+    pub fn in_mute_group(&self, group : usize) -> bool {
+        group.checked_sub(1).is_some_and(|v| v < 6 && self.mute_groups & (1 << v) != 0)
+    }
+
+    /// time since the console last reported this fader, `None` if it never has
+    #[must_use]
+    pub fn age(&self) -> Option<Duration> {
+        self.touched.0.map(|v| v.elapsed())
+    }
+
+    /// forget when this fader was last reported (see [`Fader::age`])
+    pub fn clear_age(&mut self) {
+        self.touched = Touched::default();
+    }
+
+    /// get DCA membership, as a bitmask (bit 0 is DCA 1)
+    #[must_use]
+    pub fn dca_groups(&self) -> u8 {
+        self.dca_groups
+    }
+
+    /// DCAs this fader is assigned to
+    pub fn dcas(&self) -> impl Iterator<Item = FaderIndex> + '_ {
+        (1..=8).filter(|v| self.dca_groups & (1 << (v - 1)) != 0).map(FaderIndex::Dca)
+    }
+
+    /// is this fader silenced - either muted itself, or assigned to an
+    /// active mute group.  `groups` is the on / off state of mute groups 1 - 6
+    /// (see [`crate::X32Console::mute_groups`])
+    #[must_use]
+    pub fn is_effectively_muted(&self, groups : &[bool; 6]) -> bool {
+        !self.is_on || groups.iter().enumerate().any(|(i, active)| *active && self.in_mute_group(i + 1))
+    }
+
+    /// Strip parameters as named display values (see [`FieldDiff::compare`]) -
+    /// label, level, on, pan, trim, and color
+    ///
+    /// This is the subset [`crate::x32::ConsoleCommand::copy_strip`] can
+    /// send.  Mute group and DCA assignments, EQ ([`crate::X32Console::eqs`]),
+    /// and sends ([`crate::X32Console::sends`]) are not listed, and dynamics
+    /// are not tracked at all
+    #[must_use]
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("label", self.label.to_string()),
+            ("level", self.level().1),
+            ("on", self.is_on().1),
+            ("pan", self.pan().1),
+            ("trim", self.trim().map(|v| v.1).unwrap_or_default()),
+            ("color", self.color.as_const().to_owned()),
+        ]
+    }
+
     /// Get the vor update message for this fader
     #[must_use]
     pub fn vor_message(&self) -> super::osc::Packet {
         super::osc::Packet::Message(super::osc::Message::new_with_string(
             &self.source.get_vor_address(),
-            &format!("[{:02}] {:>3} {:>8} {}",
+            &format!("[{:02}] {} {} {}",
                 self.source.get_index(),
-                self.is_on().1,
-                self.level().1,
+                self.vor_on_field(),
+                self.vor_level_field(),
                 self.name()
             )
         ))
     }
 
-    /// update fader from OSC data
-    pub fn update(&mut self, update : super::x32::updates::FaderUpdate) {
-        if let Some(new_level) = update.level {
+    /// Buffers that push the stored level, mute, label, and color back
+    /// to the console
+    #[must_use]
+    pub fn to_console_messages(&self) -> Vec<osc::Buffer> {
+        super::x32::FaderLevel::new(self.level)
+            .map(|v| super::x32::ConsoleRequest::SetLevel(self.source.clone(), v))
+            .into_iter()
+            .chain([
+                super::x32::ConsoleRequest::SetMute(self.source.clone(), !self.is_on),
+                super::x32::ConsoleRequest::SetLabel(self.source.clone(), self.label.to_string()),
+                super::x32::ConsoleRequest::SetColor(self.source.clone(), self.color),
+            ])
+            .flat_map(Vec::<osc::Buffer>::from).collect()
+    }
+
+    /// update fader from OSC data, returning what changed
+    pub fn update(&mut self, update : super::x32::updates::FaderUpdate) -> FaderChange {
+        self.update_at(update, Instant::now())
+    }
+
+    /// update fader from OSC data received at `now`, returning what changed
+    pub fn update_at(&mut self, update : super::x32::updates::FaderUpdate, now : Instant) -> FaderChange {
+        let mut change = FaderChange::NONE;
+        self.touched = Touched(Some(now));
+
+        let level = update.level_db.map(|db| Self::quantize_level(self.taper.0.db_to_level(db))).or(update.level);
+        if let Some(new_level) = level {
+            if (new_level - self.level).abs() > f32::EPSILON { change |= FaderChange::LEVEL; }
             self.level = new_level;
         }
 
         if let Some(new_is_on) = update.is_on {
+            if new_is_on != self.is_on { change |= FaderChange::MUTE; }
             self.is_on = new_is_on;
         }
 
         if let Some(new_label) = update.label {
-            self.label = new_label;
+            if *new_label != *self.label {
+                change |= FaderChange::LABEL;
+                self.name = if new_label.is_empty() { Arc::from(self.source.default_label()) } else { Arc::from(new_label.as_str()) };
+                self.label = if new_label.is_empty() { Arc::from("") } else { Arc::clone(&self.name) };
+            }
         }
 
         if let Some(new_color) = update.color {
+            if new_color != self.color { change |= FaderChange::COLOR; }
             self.color = new_color;
         }
+
+        if let Some(new_pan) = update.pan {
+            if (new_pan - self.pan).abs() > f32::EPSILON { change |= FaderChange::PAN; }
+            self.pan = new_pan;
+        }
+
+        if let Some(new_groups) = update.mute_groups {
+            if new_groups != self.mute_groups { change |= FaderChange::MUTE_GROUPS; }
+            self.mute_groups = new_groups;
+        }
+
+        if let Some(new_groups) = update.dca_groups {
+            if new_groups != self.dca_groups { change |= FaderChange::DCA_GROUPS; }
+            self.dca_groups = new_groups;
+        }
+
+        if let Some(new_trim) = update.trim {
+            if self.trim.is_none_or(|v| (new_trim - v).abs() > f32::EPSILON) { change |= FaderChange::TRIM; }
+            self.trim = Some(new_trim);
+        }
+
+        change
+    }
+
+    /// Parameters that differ from `old`, e.g. after the state was replaced
+    #[must_use]
+    pub fn changes_from(&self, old : &Self) -> FaderChange {
+        let differs = |a : f32, b : f32| (a - b).abs() > f32::EPSILON;
+        let mut change = FaderChange::NONE;
+
+        if differs(self.level, old.level) { change |= FaderChange::LEVEL; }
+        if self.is_on != old.is_on { change |= FaderChange::MUTE; }
+        if self.label != old.label { change |= FaderChange::LABEL; }
+        if self.color != old.color { change |= FaderChange::COLOR; }
+        if differs(self.pan, old.pan) { change |= FaderChange::PAN; }
+        if self.mute_groups != old.mute_groups { change |= FaderChange::MUTE_GROUPS; }
+        if self.dca_groups != old.dca_groups { change |= FaderChange::DCA_GROUPS; }
+        match (self.trim, old.trim) {
+            (Some(a), Some(b)) if !differs(a, b) => (),
+            (None, None) => (),
+            _ => change |= FaderChange::TRIM,
+        }
+        change
+    }
+
+    /// Get pan display string (`L100` - `C` - `R100`) from float
+    #[must_use]
+    pub fn pan_to_string(v : f32) -> String {
+        #[expect(clippy::cast_possible_truncation)]
+        let steps = ((v - 0.5) * 200_f32).round() as i32;
+
+        match steps {
+            0 => String::from("C"),
+            d if d < 0 => format!("L{}", -d),
+            d => format!("R{d}"),
+        }
+    }
+
+    /// Get trim display string from dB
+    #[must_use]
+    pub fn trim_to_string(v : f32) -> String {
+        format!("{v:+.1} dB")
+    }
+
+    /// Get pan float from a node pan string (`-100` - `+100`)
+    #[must_use]
+    pub fn pan_from_string(v : &str) -> Option<f32> {
+        #[expect(clippy::cast_precision_loss)]
+        v.parse::<i32>().ok().map(|d| (d.clamp(-100, 100) as f32 + 100_f32) / 200_f32)
+    }
+
+    /// Get a group bitmask from a node group string (`%000101`, rightmost is group 1)
+    #[must_use]
+    pub fn groups_from_string(v : &str) -> Option<u8> {
+        u8::from_str_radix(v.strip_prefix('%')?, 2).ok()
     }
 
     /// Get is on property from ON/OFF
@@ -529,45 +1336,62 @@ impl Fader {
     #[inline]
     pub fn is_on_from_string(v : &str) -> bool { v == "ON" }
 
+    /// Get level in dB (-90 is -oo) from float
+    #[must_use]
+    pub fn level_to_db(v : f32) -> f32 {
+        X32Taper.level_to_db(v)
+    }
+
+    /// Get float level from dB (-90 - +10), the inverse of [`Fader::level_to_db`]
+    #[must_use]
+    pub fn level_from_db(d : f32) -> f32 {
+        X32Taper.db_to_level(d)
+    }
+
+    /// Snap a float level to the nearest of the console's 1024 fader steps
+    #[must_use]
+    pub fn quantize_level(v : f32) -> f32 {
+        let f_lvl = (v * 1023.5).trunc() / 1023.0;
+        (f_lvl * 10000.0).round() / 10000.0
+    }
+
     /// Get string level from float
     #[must_use]
     pub fn level_to_string(v : f32) -> String {
-        let c_value = match v {
-            d if d >= 0.5 => v * 40_f32 - 30_f32,
-            d if d >= 0.25 => v * 80_f32 - 50_f32,
-            d if d >= 0.0625 => v * 160_f32 - 70_f32,
-            _ => v * 480_f32 - 90_f32
-        };
+        Self::level_to_string_with(&X32Taper, v)
+    }
+
+    /// Get string level from float, on a taper
+    #[must_use]
+    pub fn level_to_string_with(taper : &dyn Taper, v : f32) -> String {
+        let c_value = taper.level_to_db(v);
 
         match c_value {
             d if (-0.05..=0.05).contains(&d)  => String::from("+0.0 dB"),
-            d if d <= -89.9 => String::from("-oo dB"),
+            d if d <= taper.min_db() + 0.1 => String::from("-oo dB"),
             d if d < 0_f32   => format!("{c_value:.1} dB"),
             _ => format!("+{c_value:.1} dB")
         }
     }
 
-    /// get level as float from String
+    /// get level as float from String, on the X32 taper
     #[must_use]
     pub fn level_from_string(input : &str) -> f32 {
+        Self::db_from_string(input).map_or(0_f32, |d| Self::quantize_level(Self::level_from_db(d)))
+    }
+
+    /// get level in dB from String, `-oo` is [`f32::NEG_INFINITY`]
+    #[must_use]
+    pub fn db_from_string(input : &str) -> Option<f32> {
         if input.starts_with("-oo") {
-            0_f32
-        } else if let Some(caps) = LVL_STRING.captures(input) {
-            let lvl = match caps["level"].parse::<f32>() {
-                Ok(d) if d < -60.0_f32 => (d + 90.0_f32) / 480.0_f32,
-                Ok(d) if d < -30.0_f32 => (d + 70.0_f32) / 160.0_f32,
-                Ok(d) if d < -10.0_f32 => (d + 50.0_f32) / 80.0_f32,
-                Ok(d) => (d + 30.0_f32) / 40.0_f32,
-                Err(_) => 0_f32
-            };
-            let f_lvl = (lvl * 1023.5).trunc() / 1023.0;
-            (f_lvl * 10000.0).round() / 10000.0
+            Some(f32::NEG_INFINITY)
         } else {
-            0_f32
+            LVL_STRING.captures(input).and_then(|caps| caps["level"].parse::<f32>().ok())
         }
     }
 }
 
+#[cfg(feature = "serde")]
 impl Serialize for Fader {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -578,14 +1402,15 @@ impl Serialize for Fader {
         x.serialize_field("color", &self.color)?;
         x.serialize_field("level", &self.level().1)?;
         x.serialize_field("is_on", &self.is_on)?;
-        x.serialize_field("label", &self.label)?;
+        x.serialize_field("label", &*self.label)?;
         x.end()
     }
 }
 
 
 /// Full tracked fader banks
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FaderBank {
     /// main and mono
     main : [Fader;2],
@@ -633,16 +1458,20 @@ impl FaderBank {
 
     /// Get vor messages for an entire bank
     pub fn vor_bundle(&self, key : &FaderBankKey) -> Vec<super::osc::Packet> {
-        let a = match key {
-            FaderBankKey::Main => self.main.to_vec(),
-            FaderBankKey::Matrix => self.matrix.to_vec(),
-            FaderBankKey::Aux => self.aux.to_vec(),
-            FaderBankKey::Bus => self.bus.to_vec(),
-            FaderBankKey::Dca => self.dca.to_vec(),
-            FaderBankKey::Channel => self.channel.to_vec(),
-        };
+        self.bank(key).iter().map(Fader::vor_message).collect()
+    }
 
-        a.iter().map(Fader::vor_message).collect()
+    /// Get vor messages for the faders of a bank listed in `changes`
+    pub fn vor_bundle_changed(&self, key : &FaderBankKey, changes : &crate::x32::Changes) -> Vec<super::osc::Packet> {
+        self.bank(key).iter()
+            .filter(|v| changes.keys.contains(&crate::x32::ChangeKey::Fader(v.source.clone())))
+            .map(Fader::vor_message)
+            .collect()
+    }
+
+    /// Use a different level to dB curve on every fader (default [`X32Taper`])
+    pub fn set_taper(&mut self, taper : &'static dyn Taper) {
+        self.faders_mut().for_each(|f| f.set_taper(taper));
     }
 
     /// Reset faders
@@ -652,27 +1481,138 @@ impl FaderBank {
             level: Some(0_f32),
             is_on: Some(false),
             color: Some(FaderColor::White),
+            pan: Some(0.5_f32),
+            mute_groups: Some(0),
+            dca_groups: Some(0),
             ..Default::default() };
 
-        self.main.iter_mut().for_each(|f| f.update(update.clone()));
-        self.aux.iter_mut().for_each(|f| f.update(update.clone()));
-        self.bus.iter_mut().for_each(|f| f.update(update.clone()));
-        self.dca.iter_mut().for_each(|f| f.update(update.clone()));
-        self.channel.iter_mut().for_each(|f| f.update(update.clone()));
-        self.matrix.iter_mut().for_each(|f| f.update(update.clone()));
+        self.faders_mut().for_each(|f| {
+            f.update(update.clone());
+            f.trim = None;
+        });
+        self.clear_ages();
+    }
+
+    /// Forget when every fader was last reported (see [`Fader::age`])
+    pub fn clear_ages(&mut self) {
+        self.faders_mut().for_each(Fader::clear_age);
+    }
+
+    /// Every fader, mutable
+    fn faders_mut(&mut self) -> impl Iterator<Item = &mut Fader> {
+        self.main.iter_mut()
+            .chain(self.aux.iter_mut())
+            .chain(self.bus.iter_mut())
+            .chain(self.dca.iter_mut())
+            .chain(self.channel.iter_mut())
+            .chain(self.matrix.iter_mut())
+    }
+
+    /// Every fader, in console order
+    fn faders(&self) -> impl Iterator<Item = &Fader> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Iterate every fader, in console order (channels, aux, bus, matrix, dca, main)
+    #[must_use]
+    pub fn iter(&self) -> FaderIter<'_> {
+        FaderIter {
+            inner : [
+                self.channel.iter(),
+                self.aux.iter(),
+                self.bus.iter(),
+                self.matrix.iter(),
+                self.dca.iter(),
+                self.main.iter(),
+            ].into_iter().flatten(),
+        }
+    }
+
+    /// Iterate the faders of one bank
+    pub fn iter_bank(&self, key : &FaderBankKey) -> impl Iterator<Item = (&FaderIndex, &Fader)> {
+        self.bank(key).iter().map(|v| (&v.source, v))
+    }
+
+    /// Faders of one bank
+    fn bank(&self, key : &FaderBankKey) -> &[Fader] {
+        match key {
+            FaderBankKey::Main => &self.main,
+            FaderBankKey::Matrix => &self.matrix,
+            FaderBankKey::Aux => &self.aux,
+            FaderBankKey::Bus => &self.bus,
+            FaderBankKey::Dca => &self.dca,
+            FaderBankKey::Channel => &self.channel,
+        }
+    }
+
+    /// Faders whose name (label, or default name when unlabeled) is exactly `name`
+    #[must_use]
+    pub fn find_by_name(&self, name : &str) -> Vec<FaderIndex> {
+        self.faders().filter(|v| v.name() == name).map(|v| v.source.clone()).collect()
+    }
+
+    /// Faders whose name matches `name`, ignoring case and surrounding space
+    #[must_use]
+    pub fn find_by_name_ignore_case(&self, name : &str) -> Vec<FaderIndex> {
+        let name = name.trim().to_lowercase();
+        self.faders().filter(|v| v.name().trim().to_lowercase() == name).map(|v| v.source.clone()).collect()
+    }
+
+    /// Faders loosely matching `query` - every word of the query starts a
+    /// word of the name, ignoring case and punctuation ("pastor" and
+    /// "past mic" both find "Pastor Mic").  Best matches first: exact,
+    /// then fewest extra words, then console order
+    #[must_use]
+    pub fn find_by_name_fuzzy(&self, query : &str) -> Vec<FaderIndex> {
+        /// lowercase alphanumeric words
+        fn words(v : &str) -> Vec<String> {
+            v.split(|c : char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .map(str::to_lowercase)
+                .collect()
+        }
+
+        let wanted = words(query);
+        if wanted.is_empty() { return vec![] }
+
+        let mut found:Vec<(usize, FaderIndex)> = self.faders().filter_map(|fader| {
+            let have = words(fader.name());
+            let matched = wanted.iter().all(|w| have.iter().any(|h| h.starts_with(w.as_str())));
+            let extra = if have == wanted { 0 } else { 1 + have.len().saturating_sub(wanted.len()) };
+
+            matched.then(|| (extra, fader.source.clone()))
+        }).collect();
+
+        found.sort_by_key(|(extra, _)| *extra);
+        found.into_iter().map(|(_, v)| v).collect()
+    }
+
+    /// Buffers that push every stored fader back to the console, in
+    /// console order - see [`Fader::to_console_messages`]
+    #[must_use]
+    pub fn to_console_messages(&self) -> Vec<osc::Buffer> {
+        FaderIndex::all()
+            .filter_map(|v| self.get(&v))
+            .flat_map(|v| v.to_console_messages())
+            .collect()
     }
 
     /// Update a fader
     pub fn update(&mut self, update : crate::x32::updates::FaderUpdate) -> crate::X32ProcessResult {
+        self.update_at(update, Instant::now())
+    }
+
+    /// Update a fader from data received at `now`
+    pub fn update_at(&mut self, update : crate::x32::updates::FaderUpdate, now : Instant) -> crate::X32ProcessResult {
         self.get_mut(&update.source).map_or(crate::X32ProcessResult::NoOperation, |fader| {
-            fader.update(update);
-            crate::X32ProcessResult::Fader(fader.clone())
+            let change = fader.update_at(update, now);
+            crate::X32ProcessResult::Fader((fader.clone(), change))
         })
     }
 
     /// Get a mutable fader, zero based index
     pub fn get_mut(&mut self, f_type: &FaderIndex) -> Option<&mut Fader> {
-        let index = f_type.get_index() - 1;
+        let index = f_type.get_index().checked_sub(1)?;
         match f_type {
             FaderIndex::Aux(_) => self.aux.get_mut(index),
             FaderIndex::Matrix(_) => self.matrix.get_mut(index),
@@ -685,16 +1625,24 @@ impl FaderBank {
     }
 
     /// Get a fader, zero based index
+    ///
+    /// Clones the fader, prefer [`FaderBank::get_ref`]
     #[must_use]
     pub fn get(&self, f_type: &FaderIndex) -> Option<Fader> {
-        let index = f_type.get_index() - 1;
+        self.get_ref(f_type).cloned()
+    }
+
+    /// Borrow a fader, zero based index
+    #[must_use]
+    pub fn get_ref(&self, f_type: &FaderIndex) -> Option<&Fader> {
+        let index = f_type.get_index().checked_sub(1)?;
         match f_type {
-            FaderIndex::Aux(_) => self.aux.get(index).cloned(),
-            FaderIndex::Matrix(_) => self.matrix.get(index).cloned(),
-            FaderIndex::Main(_) => self.main.get(index).cloned(),
-            FaderIndex::Channel(_) => self.channel.get(index).cloned(),
-            FaderIndex::Dca(_) => self.dca.get(index).cloned(),
-            FaderIndex::Bus(_) => self.bus.get(index).cloned(),
+            FaderIndex::Aux(_) => self.aux.get(index),
+            FaderIndex::Matrix(_) => self.matrix.get(index),
+            FaderIndex::Main(_) => self.main.get(index),
+            FaderIndex::Channel(_) => self.channel.get(index),
+            FaderIndex::Dca(_) => self.dca.get(index),
+            FaderIndex::Bus(_) => self.bus.get(index),
             FaderIndex::Unknown => None,
         }
     }
@@ -703,3 +1651,63 @@ impl FaderBank {
 impl Default for FaderBank {
     fn default() -> Self { Self::new() }
 }
+
+// MARK: FaderIter
+/// Iterator over every fader in a bank, see [`FaderBank::iter`]
+#[derive(Debug, Clone)]
+pub struct FaderIter<'a> {
+    /// flattened banks, in console order
+    inner : std::iter::Flatten<std::array::IntoIter<std::slice::Iter<'a, Fader>, 6>>,
+}
+
+impl<'a> Iterator for FaderIter<'a> {
+    type Item = (&'a FaderIndex, &'a Fader);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|v| (&v.source, v))
+    }
+}
+
+impl<'a> IntoIterator for &'a FaderBank {
+    type Item = (&'a FaderIndex, &'a Fader);
+    type IntoIter = FaderIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// Owned iterator over one bank of `N` faders
+type BankIntoIter<const N : usize> = std::array::IntoIter<Fader, N>;
+/// Channel, aux, then bus banks
+type StripIntoIter = std::iter::Chain<std::iter::Chain<BankIntoIter<32>, BankIntoIter<8>>, BankIntoIter<16>>;
+/// Matrix, DCA, then main banks
+type MasterIntoIter = std::iter::Chain<std::iter::Chain<BankIntoIter<6>, BankIntoIter<8>>, BankIntoIter<2>>;
+
+// MARK: FaderIntoIter
+/// Owning iterator over every fader in a bank, see [`FaderBank::into_iter`]
+#[derive(Debug, Clone)]
+pub struct FaderIntoIter {
+    /// chained banks, in console order
+    inner : std::iter::Chain<StripIntoIter, MasterIntoIter>,
+}
+
+impl Iterator for FaderIntoIter {
+    type Item = Fader;
+
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next() }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl ExactSizeIterator for FaderIntoIter {}
+
+impl IntoIterator for FaderBank {
+    type Item = Fader;
+    type IntoIter = FaderIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FaderIntoIter {
+            inner : self.channel.into_iter().chain(self.aux).chain(self.bus)
+                .chain(self.matrix.into_iter().chain(self.dca).chain(self.main)),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use super::osc::{Buffer, Message, Packet};
+use super::X32Console;
+
+// MARK: fuzz_target
+/// Feed arbitrary bytes through the decoder and the console state machine
+///
+/// The bytes are first decoded as a datagram with [`Packet::try_from`] and
+/// processed by a fresh [`X32Console`], then reinterpreted as an arbitrary
+/// [`Message`] so well-formed addresses with odd arguments (short or
+/// malformed `node` strings, wrong argument types) reach the parsers too.
+/// Errors are expected - only panics are bugs.
+///
+/// For `cargo fuzz`, with the `arbitrary` feature enabled:
+///
+/// ```ignore
+/// #![no_main]
+/// libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+///     x32_osc_state::fuzz::fuzz_target(data);
+/// });
+/// ```
+pub fn fuzz_target(data : &[u8]) {
+    let mut console = X32Console::new();
+    let buffer = Buffer::from(data.to_vec());
+
+    if let Ok(packet) = Packet::try_from(buffer.clone()) {
+        let _ = console.process_packet(&packet);
+    }
+    let _ = console.process(buffer);
+
+    if let Ok(message) = Message::arbitrary_take_rest(Unstructured::new(data)) {
+        if let Ok(encoded) = Buffer::try_from(message.clone()) {
+            let _ = Packet::try_from(encoded);
+        }
+        let _ = console.process(message);
+    }
+}
@@ -1,52 +1,152 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs)]
 
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Display formatting
+pub mod display;
 /// Enums and static data
 pub mod enums;
 /// Low-level OSC message handling
 pub mod osc;
 /// X32 Types and OSC Reflections
 pub mod x32;
+/// GUI view models
+#[cfg(feature = "ui")]
+pub mod ui;
+/// Soak test traffic generation
+#[cfg(feature = "test-support")]
+pub mod test_support;
+/// Fuzzing entry points
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+/// WebSocket JSON bridge
+#[cfg(feature = "bridge")]
+pub mod bridge;
 
 /// [`X32Console::process`] results
 /// 
 /// Note that a lot of understood messages still return [`X32ProcessResult::NoOperation`],
-/// particularly cue type messages
+/// particularly routing and preamp messages.  Messages the crate does not
+/// understand return [`X32ProcessResult::Unhandled`]
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[non_exhaustive]
 pub enum X32ProcessResult {
     /// No operation should be taken
     NoOperation,
-    /// A fader was changed
-    Fader(enums::Fader),
+    /// A fader was updated, with the parameters that actually changed
+    /// (empty when the update repeated the tracked values)
+    Fader((enums::Fader, enums::FaderChange)),
     /// The current cue was changed
     CurrentCue(String),
+    /// A cue, scene, or snippet list entry was added, edited, or removed
+    CueListChanged {
+        /// list edited - [`enums::ShowMode::Cues`], [`enums::ShowMode::Scenes`], or [`enums::ShowMode::Snippets`]
+        kind : enums::ShowMode,
+        /// entry index
+        index : usize,
+    },
     /// Meter info
     /// the first item of the tuple is the meter message index.
-    /// note that the first element in the Vec is nonsense - it *should*
-    /// be an integer equal to the size of the vector, but that would
+    /// note that the first element in the blob is nonsense - it *should*
+    /// be an integer equal to the size of the blob, but that would
     /// complicate working with the data - it is left intact so that
-    /// the vector indexes line up better with the data.
-    Meters((usize, Vec<f32>))
+    /// the blob indexes line up better with the data.
+    Meters((usize, x32::updates::MeterBlob)),
+    /// Subscription data - the alias given to
+    /// [`x32::ConsoleRequest::BatchSubscribe`] or [`x32::ConsoleRequest::FormatSubscribe`],
+    /// and the returned values (laid out like [`X32ProcessResult::Meters`])
+    Subscription((String, x32::updates::MeterBlob)),
+    /// Solo state changed - `true` while anything is soloed (see [`x32::SoloState::is_active`])
+    Solo(bool),
+    /// Connection health changed, see [`x32::LinkMonitor`]
+    Connection(x32::ConnectionState),
+    /// A well formed message the crate does not understand (also counted
+    /// in [`X32Console::compatibility_report`])
+    Unhandled {
+        /// OSC address of the message
+        address : String,
+    },
 }
 
 // MARK: X32State
 /// X32 State
+///
+/// With the `serde` feature the tracked state (de)serializes as an
+/// [`x32::Snapshot`] - persist it on shutdown and restore it at startup
+/// rather than polling the whole console again.  Deserializing checks the
+/// schema version and migrates older snapshots, as [`x32::Snapshot::from_json`]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "x32::Snapshot", try_from = "serde_json::Value"))]
+// the tracked state is public, the bookkeeping `process` relies on is not
+#[expect(clippy::partial_pub_fields)]
 pub struct X32Console {
     /// Faders
     pub faders : enums::FaderBank,
 
-    /// Full Cue List
-    pub cues : [Option<enums::ShowCue>; 500],
-    /// Full Snippet List
-    pub snippets : [Option<String>; 100],
-    /// Full Scene List
-    pub scenes : [Option<String>; 100],
+    /// Cue List, by index
+    pub cues : BTreeMap<usize, enums::ShowCue>,
+    /// Snippet List, by index
+    pub snippets : BTreeMap<usize, String>,
+    /// Scene List, by index
+    pub scenes : BTreeMap<usize, String>,
 
     /// Board tracking method
     pub show_mode : enums::ShowMode,
     /// Current Cue
     pub current_cue : Option<usize>,
+
+    /// Messages not understood, grouped by address
+    compatibility : x32::CompatibilityReport,
+    /// Decoding options for incoming buffers
+    pub decode_options : osc::DecodeOptions,
+    /// Cue display format used by [`X32Console::active_cue`]
+    pub cue_format : display::CueFormat,
+    /// Address rewriting for consoles behind OSC middleware
+    pub address_map : x32::AddressMap,
+    /// Console family spoken on the wire, see [`x32::Dialect`]
+    pub dialect : x32::Dialect,
+    /// Bus and matrix send levels
+    pub sends : x32::SendMatrix,
+    /// Preamp state, by channel
+    pub preamps : BTreeMap<enums::FaderIndex, x32::Preamp>,
+    /// EQ state, by strip
+    pub eqs : BTreeMap<enums::FaderIndex, x32::Equalizer>,
+    /// Mute group on / off state, groups 1 - 6
+    pub mute_groups : [bool; 6],
+    /// Solo switches and lamp
+    pub solo : x32::SoloState,
+    /// Front panel USB recorder
+    pub tape : x32::Recorder,
+    /// Expansion card (X-USB / X-Live) recorder
+    pub card : x32::Recorder,
+    /// Connected console identity, see [`x32::ConsoleRequest::Info`]
+    pub device : Option<x32::DeviceInfo>,
+    /// Output patching
+    pub routing : x32::Routing,
+    /// User assignable control banks A - C
+    pub user_controls : [x32::UserBank; 3],
+    /// Talkback and monitor section
+    pub monitor : x32::MonitorState,
+    /// Scene safes, see [`X32Console::safes`]
+    pub safes : x32::Safes,
+    /// Change observers, see [`X32Console::subscribe`]
+    observers : x32::Observers,
+    /// Time since the last packet, see [`X32Console::check_link`]
+    link : x32::LinkMonitor,
+    /// Generation counted changes, see [`X32Console::changes_since`]
+    changes : x32::ChangeLog,
+    /// Last update, by subsystem
+    updated : BTreeMap<x32::Subsystem, std::time::Instant>,
+    /// Last reply, by node address (`ch/01/config`) - only the nodes
+    /// [`x32::ConsoleRequest::full_update`] asks for, see
+    /// [`X32Console::refresh_requests`]
+    replied : BTreeMap<String, std::time::Instant>,
+    /// Traffic counters, see [`X32Console::stats`]
+    stats : x32::Stats,
+    /// Subscription reply aliases, see [`X32Console::add_subscription`]
+    subscriptions : BTreeSet<String>,
 }
 
 impl X32Console {
@@ -55,29 +155,169 @@ impl X32Console {
     pub fn new() -> Self {
         Self {
             faders: enums::FaderBank::default(),
-            cues: [(); 500].map(|()| None),
-            snippets: [(); 100].map(|()| None),
-            scenes: [(); 100].map(|()| None),
+            cues: BTreeMap::new(),
+            snippets: BTreeMap::new(),
+            scenes: BTreeMap::new(),
             show_mode: enums::ShowMode::Cues,
             current_cue: None,
+            compatibility: x32::CompatibilityReport::default(),
+            decode_options: osc::DecodeOptions::default(),
+            cue_format: display::CueFormat::default(),
+            address_map: x32::AddressMap::default(),
+            dialect: x32::Dialect::default(),
+            sends: x32::SendMatrix::default(),
+            preamps: BTreeMap::new(),
+            eqs: BTreeMap::new(),
+            mute_groups: [false; 6],
+            solo: x32::SoloState::default(),
+            tape: x32::Recorder::default(),
+            card: x32::Recorder::default(),
+            device: None,
+            routing: x32::Routing::default(),
+            user_controls: Default::default(),
+            monitor: x32::MonitorState::default(),
+            safes: x32::Safes::default(),
+            observers: x32::Observers::default(),
+            link: x32::LinkMonitor::new(),
+            changes: x32::ChangeLog::default(),
+            updated: BTreeMap::new(),
+            replied: BTreeMap::new(),
+            stats: x32::Stats::new(),
+            subscriptions: BTreeSet::new(),
         }
     }
 
     // MARK: ~fader
     /// Get a fader, 1 based index
     #[must_use]
-    pub fn fader(&self, f_type:&enums::FaderIndex) -> Option<enums::Fader> {
-        self.faders.get(f_type)
+    pub fn fader(&self, f_type:&enums::FaderIndex) -> Option<&enums::Fader> {
+        self.faders.get_ref(f_type)
+    }
+
+    /// Parameters that differ between two strips, for "make channel 12
+    /// sound like channel 11" - only those in [`enums::Fader::fields`], not
+    /// EQ, sends, or dynamics.  Empty if either strip is not tracked
+    #[must_use]
+    pub fn compare_strips(&self, a : &enums::FaderIndex, b : &enums::FaderIndex) -> Vec<enums::FieldDiff> {
+        let (Some(a), Some(b)) = (self.fader(a), self.fader(b)) else { return vec![] };
+        enums::FieldDiff::compare(&a.fields(), &b.fields())
+    }
+
+    /// Is a fader silenced, by its own mute or an active mute group
+    /// (see [`enums::Fader::is_effectively_muted`])
+    #[must_use]
+    pub fn is_effectively_muted(&self, f_type : &enums::FaderIndex) -> Option<bool> {
+        self.fader(f_type).map(|v| v.is_effectively_muted(&self.mute_groups))
+    }
+
+    /// Level actually reaching the mix - the fader level offset by the levels
+    /// of every assigned DCA, and `-oo` when the fader, an assigned DCA, or an
+    /// active mute group mutes it.  Each fader's dB comes from its own
+    /// taper, the result is on the fader's taper, as [`enums::Fader::level`]
+    #[must_use]
+    pub fn effective_level(&self, f_type : &enums::FaderIndex) -> Option<(f32, String)> {
+        let fader = self.fader(f_type)?;
+        let taper = fader.taper();
+        let dcas:Vec<&enums::Fader> = fader.dcas().filter_map(|v| self.fader(&v)).collect();
+
+        let silent = |v : &enums::Fader| v.level_db() <= v.taper().min_db();
+        let level = if fader.is_effectively_muted(&self.mute_groups) || dcas.iter().any(|v| !v.is_on().0 || silent(v)) || silent(fader) {
+            0_f32
+        } else {
+            let db = dcas.iter().fold(fader.level_db(), |db, v| db + v.level_db());
+            if db <= taper.min_db() { 0_f32 } else { taper.db_to_level(db) }
+        };
+
+        Some((level, enums::Fader::level_to_string_with(taper, level)))
+    }
+
+    /// Connected console model, [`x32::ConsoleModel::Unknown`] until the
+    /// console answers [`x32::ConsoleRequest::Info`] - the reply also sets
+    /// [`X32Console::dialect`]
+    #[must_use]
+    pub fn model(&self) -> x32::ConsoleModel {
+        self.device.as_ref().map_or(x32::ConsoleModel::Unknown, x32::DeviceInfo::console_model)
+    }
+
+    /// Get a recorder's state
+    #[must_use]
+    pub fn recorder(&self, kind : x32::RecorderKind) -> &x32::Recorder {
+        match kind {
+            x32::RecorderKind::Tape => &self.tape,
+            x32::RecorderKind::Card => &self.card,
+        }
+    }
+
+    /// Get a user assignable bank (`'A'`, `'B'`, or `'C'`) - see
+    /// [`x32::UserBank::button_assignment`] for what each control does
+    #[must_use]
+    pub fn user_bank(&self, bank : char) -> Option<&x32::UserBank> {
+        let index = x32::UserControlUpdate::bank_from_part(bank.encode_utf8(&mut [0; 4])).ok()?;
+        self.user_controls.get(index)
+    }
+
+    /// Strips and parameter groups protected from scene recalls - warn
+    /// when a recall will not touch a strip with [`x32::Safes::is_strip_safe`]
+    #[must_use]
+    pub fn safes(&self) -> &x32::Safes {
+        &self.safes
+    }
+
+    /// Get a send level, e.g. channel 5 to bus 3
+    #[must_use]
+    pub fn send(&self, source : &enums::FaderIndex, target : &enums::FaderIndex) -> Option<x32::BusSend> {
+        self.sends.get(source, target)
+    }
+
+    /// Get the preamp state of a channel (`None` until the console reports it)
+    #[must_use]
+    pub fn preamp(&self, source : &enums::FaderIndex) -> Option<x32::Preamp> {
+        self.preamps.get(source).copied()
+    }
+
+    /// Get the EQ of a strip (`None` until the console reports it)
+    #[must_use]
+    pub fn eq(&self, source : &enums::FaderIndex) -> Option<&x32::Equalizer> {
+        self.eqs.get(source)
     }
 
     // MARK: ~active_cue
-    /// Get active cue, scene, or snippet
+    /// Get active cue, scene, or snippet, formatted for display - see
+    /// [`X32Console::active_cue_info`] for the parts
     #[must_use]
     pub fn active_cue(&self) -> String {
+        let info = self.active_cue_info();
+        let label = |link : fn(&enums::CueSummary) -> String| info.as_ref().map_or_else(|| String::from("--"), link);
+
+        match self.show_mode {
+            enums::ShowMode::Cues => format!("Cue: {}" ,self.cue_format.format_summary(info.as_ref())),
+            enums::ShowMode::Scenes => format!("Scene: {}" ,label(enums::CueSummary::scene_label)),
+            enums::ShowMode::Snippets => format!("Snippet: {}" ,label(enums::CueSummary::snippet_label)),
+        }
+    }
+
+    /// Get active cue, scene, or snippet (by show mode), `None` if nothing
+    /// is active or the list entry is unknown
+    #[must_use]
+    pub fn active_cue_info(&self) -> Option<enums::CueSummary> {
+        let index = self.current_cue?;
+        let entry = |name : &str| enums::CueSummary {
+            number : format!("{index:02}"),
+            name : name.to_owned(),
+            scene : None,
+            snippet : None,
+        };
+
         match self.show_mode {
-            enums::ShowMode::Cues => format!("Cue: {}" ,self.cue_name(self.current_cue)),
-            enums::ShowMode::Scenes => format!("Scene: {}" ,self.scene_name(self.current_cue)),
-            enums::ShowMode::Snippets => format!("Snippet: {}" ,self.snip_name(self.current_cue)),
+            enums::ShowMode::Cues => self.resolve_cue(index).map(enums::CueSummary::from),
+            enums::ShowMode::Scenes => self.scene(index).map(|name| enums::CueSummary {
+                scene : Some((index, Some(name.to_owned()))),
+                ..entry(name)
+            }),
+            enums::ShowMode::Snippets => self.snippet(index).map(|name| enums::CueSummary {
+                snippet : Some((index, Some(name.to_owned()))),
+                ..entry(name)
+            }),
         }
     }
 
@@ -86,81 +326,714 @@ impl X32Console {
     #[must_use]
     pub fn cue_list_size(&self) -> (usize, usize, usize) {
         (
-            self.cues.iter().filter(|v| v.is_some()).count(),
-            self.scenes.iter().filter(|v| v.is_some()).count(),
-            self.snippets.iter().filter(|v| v.is_some()).count(),
+            self.cues.len(),
+            self.scenes.len(),
+            self.snippets.len(),
         )
     }
 
     // MARK: ~reset
-    /// Reset the state machine
+    /// Reset the state machine - every fader and cue list entry that
+    /// changes is recorded (see [`X32Console::changes_since`]) and
+    /// reported to the observers
     pub fn reset(&mut self) {
+        self.replace_state(Self::clear_state);
+    }
+
+    /// Replace the tracked state with `apply`, recording and reporting
+    /// the faders and cue list entries it changed
+    pub(crate) fn replace_state<F : FnOnce(&mut Self)>(&mut self, apply : F) {
+        let faders = self.faders.clone();
+        let lists = (self.cues.clone(), self.scenes.clone(), self.snippets.clone());
+        let current = (self.show_mode, self.current_cue);
+
+        apply(self);
+
+        let mut results:Vec<X32ProcessResult> = (&self.faders).into_iter().zip(&faders)
+            .filter_map(|((_, fader), (_, old))| {
+                let change = fader.changes_from(old);
+                (!change.is_empty()).then(|| X32ProcessResult::Fader((fader.clone(), change)))
+            })
+            .collect();
+
+        results.extend(Self::changed_entries(enums::ShowMode::Cues, &lists.0, &self.cues));
+        results.extend(Self::changed_entries(enums::ShowMode::Scenes, &lists.1, &self.scenes));
+        results.extend(Self::changed_entries(enums::ShowMode::Snippets, &lists.2, &self.snippets));
+
+        for result in &results {
+            let key = match result {
+                X32ProcessResult::CueListChanged { kind : enums::ShowMode::Cues, index } => Some(x32::ChangeKey::Cue(*index)),
+                X32ProcessResult::CueListChanged { kind : enums::ShowMode::Scenes, index } => Some(x32::ChangeKey::Scene(*index)),
+                X32ProcessResult::CueListChanged { kind : enums::ShowMode::Snippets, index } => Some(x32::ChangeKey::Snippet(*index)),
+                _ => x32::ChangeKey::from_result(result),
+            };
+            if let Some(key) = key { self.changes.record(key); }
+            self.observers.notify(result);
+        }
+
+        if current != (self.show_mode, self.current_cue) {
+            self.changes.record(x32::ChangeKey::CurrentCue);
+            self.observers.notify(&X32ProcessResult::CurrentCue(self.active_cue()));
+        }
+    }
+
+    /// [`X32ProcessResult::CueListChanged`] for every list entry that
+    /// differs between `old` and `new`
+    fn changed_entries<T : PartialEq>(kind : enums::ShowMode, old : &BTreeMap<usize, T>, new : &BTreeMap<usize, T>) -> Vec<X32ProcessResult> {
+        old.keys().chain(new.keys().filter(|v| !old.contains_key(v)))
+            .filter(|index| old.get(index) != new.get(index))
+            .map(|index| X32ProcessResult::CueListChanged { kind, index : *index })
+            .collect()
+    }
+
+    /// Reset the tracked state, without recording or reporting it
+    #[expect(clippy::single_call_fn)]
+    pub(crate) fn clear_state(&mut self) {
         self.clear_cues();
         self.faders.reset();
+        self.sends.clear();
+        self.preamps.clear();
+        self.eqs.clear();
+        self.mute_groups = [false; 6];
+        self.solo.clear();
+        self.tape = x32::Recorder::default();
+        self.card = x32::Recorder::default();
+        self.device = None;
+        self.routing.clear();
+        self.user_controls = Default::default();
+        self.monitor = x32::MonitorState::default();
+        self.safes = x32::Safes::default();
+        self.updated.clear();
+        self.replied.clear();
     }
 
     /// Clear cue list.
     pub fn clear_cues(&mut self) {
-        self.cues = [(); 500].map(|()| None);
-        self.snippets = [(); 100].map(|()| None);
-        self.scenes = [(); 100].map(|()| None);
-    }
-
-    // MARK: ~cue_name
-    /// get formatted cue name from index (includes scene and snippet)
-    fn cue_name(&self, index: Option<usize> ) -> String {
-        let default = String::from("0.0.0 :: -- [--] [--]");
-
-        match index {
-            Some(d) if d < 500 => {
-                self.cues[d].as_ref().map_or(default, |t| format!("{} :: {} [{}] [{}]",
-                    t.cue_number,
-                    t.name,
-                    self.scene_name(t.scene),
-                    self.snip_name(t.snippet)
-                ))
-            },
-            _ => default
-        }
+        self.cues.clear();
+        self.snippets.clear();
+        self.scenes.clear();
     }
 
-    /// get scene name from index
-    fn scene_name(&self, index: Option<usize> ) -> String {
-        let default = String::from("--");
+    /// Remove a cue, scene, or snippet list entry, e.g. one deleted on the
+    /// console.  Returns [`X32ProcessResult::CueListChanged`] (observers are
+    /// notified too) if there was an entry to remove
+    pub fn remove_cue(&mut self, kind : enums::ShowMode, index : usize) -> X32ProcessResult {
+        self.clear_range(kind, index..=index).into_iter().next()
+            .unwrap_or(X32ProcessResult::NoOperation)
+    }
 
-        match index {
-            Some(d) if d < 100 =>
-                self.scenes[d].as_ref().map_or(default, |t| format!("{d:02}:{t}")),
-            _ => default
-        }
+    /// Remove every cue, scene, or snippet list entry in `range` - e.g. the
+    /// entries past the end of a re-synced list.  Returns a
+    /// [`X32ProcessResult::CueListChanged`] for each removed entry (observers
+    /// are notified too)
+    pub fn clear_range<R : std::ops::RangeBounds<usize>>(&mut self, kind : enums::ShowMode, range : R) -> Vec<X32ProcessResult> {
+        let removed = match kind {
+            enums::ShowMode::Cues => Self::remove_list_range(&mut self.cues, range),
+            enums::ShowMode::Scenes => Self::remove_list_range(&mut self.scenes, range),
+            enums::ShowMode::Snippets => Self::remove_list_range(&mut self.snippets, range),
+        };
+
+        removed.into_iter().map(|index| {
+            self.changes.record(match kind {
+                enums::ShowMode::Cues => x32::ChangeKey::Cue(index),
+                enums::ShowMode::Scenes => x32::ChangeKey::Scene(index),
+                enums::ShowMode::Snippets => x32::ChangeKey::Snippet(index),
+            });
+
+            let result = X32ProcessResult::CueListChanged { kind, index };
+            self.observers.notify(&result);
+            result
+        }).collect()
+    }
+
+    // MARK: ~cue lookup
+    /// Get a cue by index
+    #[must_use]
+    pub fn cue(&self, index : usize) -> Option<&enums::ShowCue> {
+        self.cues.get(&index)
+    }
+
+    /// Get a scene name by index
+    #[must_use]
+    pub fn scene(&self, index : usize) -> Option<&str> {
+        self.scenes.get(&index).map(String::as_str)
+    }
+
+    /// Get a snippet name by index
+    #[must_use]
+    pub fn snippet(&self, index : usize) -> Option<&str> {
+        self.snippets.get(&index).map(String::as_str)
+    }
+
+    /// Get the name of the scene linked to a cue
+    #[must_use]
+    pub fn cue_scene(&self, cue : &enums::ShowCue) -> Option<&str> {
+        cue.scene.and_then(|d| self.scene(d))
+    }
+
+    /// Get the name of the snippet linked to a cue
+    #[must_use]
+    pub fn cue_snippet(&self, cue : &enums::ShowCue) -> Option<&str> {
+        cue.snippet.and_then(|d| self.snippet(d))
+    }
+
+    /// Get a cue by index, with scene and snippet names resolved
+    #[must_use]
+    pub fn resolve_cue(&self, index : usize) -> Option<enums::ResolvedCue<'_>> {
+        self.cue(index).map(|cue| enums::ResolvedCue {
+            index, cue,
+            scene : self.cue_scene(cue),
+            snippet : self.cue_snippet(cue),
+        })
+    }
+
+    /// Cue list as a CSV or Markdown table - cue number, name, scene,
+    /// and snippet - for printing a cue sheet
+    #[must_use]
+    pub fn export_cues(&self, format : display::ExportFormat) -> String {
+        format.format(self.cues.keys().filter_map(|v| self.resolve_cue(*v)))
+    }
+
+    // MARK: ~snapshot
+    /// Point in time copy of the tracked state
+    #[must_use]
+    pub fn snapshot(&self) -> x32::Snapshot {
+        x32::Snapshot::from(self)
+    }
+
+    /// Replace the tracked state with a snapshot - changes are recorded and
+    /// reported as for [`X32Console::reset`]
+    pub fn restore(&mut self, snapshot : &x32::Snapshot) {
+        snapshot.restore(self);
+    }
+
+    /// Restore a snapshot from the last session and start a verification
+    /// sync - see [`x32::WarmStart`].  Send the returned buffers to the console
+    pub fn warm_start(&mut self, snapshot : &x32::Snapshot) -> (x32::WarmStart, Vec<osc::Buffer>) {
+        x32::WarmStart::begin(self, snapshot)
+    }
+
+    // MARK: ~request
+    /// Buffers for a [`x32::ConsoleRequest`], rewritten for [`X32Console::dialect`]
+    /// and by [`X32Console::address_map`]
+    ///
+    /// [`x32::ConsoleRequest::NextCue`] and [`x32::ConsoleRequest::PrevCue`]
+    /// are resolved against the tracked list for the current show mode,
+    /// and send nothing past either end.  Meter requests for banks the
+    /// [`X32Console::model`] does not have send nothing
+    #[must_use]
+    pub fn request(&self, request : x32::ConsoleRequest) -> Vec<osc::Buffer> {
+        let request = match request {
+            x32::ConsoleRequest::NextCue() => self.step_cue(true),
+            x32::ConsoleRequest::PrevCue() => self.step_cue(false),
+            x32::ConsoleRequest::Meters(bank, _) | x32::ConsoleRequest::RenewMeters(bank)
+                if !self.model().has_meter_bank(bank) => None,
+            v => Some(v),
+        };
+
+        request.map(|v| self.address_map.outgoing(v.encode_for(self.dialect))).unwrap_or_default()
+    }
+
+    /// Expect replies for a `/batchsubscribe` or `/formatsubscribe` - a
+    /// single blob sent to the alias is reported as
+    /// [`X32ProcessResult::Subscription`], otherwise it is
+    /// [`X32ProcessResult::Unhandled`]
+    pub fn add_subscription(&mut self, subscription : &x32::Subscription) {
+        self.subscriptions.insert(subscription.alias.clone());
+    }
+
+    /// Stop expecting replies at a subscription alias
+    pub fn remove_subscription(&mut self, alias : &str) {
+        self.subscriptions.remove(alias);
+    }
+
+    /// Aliases replies are expected at, see [`X32Console::add_subscription`]
+    #[must_use]
+    pub const fn subscriptions(&self) -> &BTreeSet<String> {
+        &self.subscriptions
+    }
+
+    /// Full update requests for [`X32Console::dialect`], rewritten by
+    /// [`X32Console::address_map`] - see [`x32::ConsoleRequest::full_update_for`]
+    #[must_use]
+    pub fn full_update(&self) -> Vec<osc::Buffer> {
+        self.address_map.outgoing(x32::ConsoleRequest::full_update_for(self.dialect))
+    }
+
+    /// [`X32Console::full_update`] packed into bundles of at most `max_len`
+    /// bytes, see [`x32::ConsoleRequest::pack`]
+    #[must_use]
+    pub fn full_update_packed(&self, max_len : usize) -> Vec<osc::Buffer> {
+        x32::ConsoleRequest::pack(self.full_update(), max_len)
+    }
+
+    /// Fire request for the populated cue, scene, or snippet next to the
+    /// current one - skipped cues are stepped over
+    fn step_cue(&self, forward : bool) -> Option<x32::ConsoleRequest> {
+        let populated:Vec<usize> = match self.show_mode {
+            enums::ShowMode::Cues => self.cues.iter().filter(|(_, cue)| !cue.skip).map(|(i, _)| *i).collect(),
+            enums::ShowMode::Scenes => self.scenes.keys().copied().collect(),
+            enums::ShowMode::Snippets => self.snippets.keys().copied().collect(),
+        };
+
+        let target = match (self.current_cue, forward) {
+            (None, true) => populated.first().copied(),
+            (None, false) => None,
+            (Some(current), true) => populated.into_iter().find(|v| *v > current),
+            (Some(current), false) => populated.into_iter().rev().find(|v| *v < current),
+        }?;
+
+        Some(match self.show_mode {
+            enums::ShowMode::Cues => x32::ConsoleRequest::GoCue(target),
+            enums::ShowMode::Scenes => x32::ConsoleRequest::LoadScene(target),
+            enums::ShowMode::Snippets => x32::ConsoleRequest::LoadSnippet(target),
+        })
+    }
+
+    // MARK: ~subscribe
+    /// Get notified of results matching `filter` - e.g. "channel 5 level"
+    /// or "current cue" - instead of matching every [`X32ProcessResult`]
+    /// in the receive loop
+    ///
+    /// ```
+    /// use x32_osc_state::{osc, x32, X32Console, enums::{FaderChange, FaderIndex}};
+    ///
+    /// let mut state = X32Console::new();
+    /// let levels = state.subscribe(x32::EventFilter::FaderParameter(FaderIndex::Channel(5), FaderChange::LEVEL));
+    ///
+    /// state.process(osc::Message::builder("/ch/05/mix/on").int(1).build());
+    /// state.process(osc::Message::builder("/ch/05/mix/fader").float(0.5).build());
+    ///
+    /// assert_eq!(levels.try_iter().count(), 1);
+    /// ```
+    pub fn subscribe(&mut self, filter : x32::EventFilter) -> std::sync::mpsc::Receiver<X32ProcessResult> {
+        self.observers.subscribe(filter)
+    }
+
+    /// Receive every error [`X32Console::process`] would otherwise drop -
+    /// undecodable data and messages with bad arguments
+    pub fn subscribe_errors(&mut self) -> std::sync::mpsc::Receiver<enums::Error> {
+        self.observers.subscribe_errors()
+    }
+
+    /// Registered observers, see [`X32Console::subscribe`]
+    #[must_use]
+    pub const fn observers(&self) -> &x32::Observers {
+        &self.observers
+    }
+
+    // MARK: ~staleness
+    /// Time since the console last updated a subsystem, `None` if it never has
+    #[must_use]
+    pub fn subsystem_age(&self, subsystem : x32::Subsystem) -> Option<std::time::Duration> {
+        self.updated.get(&subsystem).map(std::time::Instant::elapsed)
+    }
+
+    /// When the console last updated a subsystem, `None` if it never has
+    #[must_use]
+    pub fn subsystem_updated(&self, subsystem : x32::Subsystem) -> Option<std::time::Instant> {
+        self.updated.get(&subsystem).copied()
+    }
+
+    /// Faders the console has (see [`X32Console::dialect`]) not reported
+    /// within `threshold` (or ever) -
+    /// re-request just these rather than polling everything
+    #[must_use]
+    pub fn stale_faders(&self, threshold : std::time::Duration) -> Vec<enums::FaderIndex> {
+        self.dialect.faders()
+            .filter(|v| self.fader(v).and_then(enums::Fader::age).is_none_or(|age| age > threshold))
+            .collect()
     }
 
-    /// get snippet name from index
-    fn snip_name(&self, index: Option<usize> ) -> String {
-        let default = String::from("--");
+    /// Check the link for silence at `now` - call this from a timer.
+    /// Returns [`X32ProcessResult::Connection`] when the state changes
+    /// (observers are notified too), so the UI can show the console offline
+    /// rather than freezing on stale data
+    pub fn check_link(&mut self, now : std::time::Instant) -> X32ProcessResult {
+        let result = self.link.poll(now).map_or(X32ProcessResult::NoOperation, X32ProcessResult::Connection);
+        self.observers.notify(&result);
+        result
+    }
+
+    /// Link health as of the last packet or [`X32Console::check_link`]
+    #[must_use]
+    pub const fn link(&self) -> &x32::LinkMonitor {
+        &self.link
+    }
+
+    /// Last reply, by node address (`ch/01/config`) - only the nodes
+    /// [`x32::ConsoleRequest::full_update`] asks for
+    #[must_use]
+    pub const fn replied(&self) -> &BTreeMap<String, std::time::Instant> { &self.replied }
+
+    /// Time since a node (`ch/01/config`) was last replied to, `None` if
+    /// it never has been
+    #[must_use]
+    pub fn node_age(&self, node : &str) -> Option<std::time::Duration> {
+        self.replied.get(node).map(std::time::Instant::elapsed)
+    }
+
+    /// Requests that fill the gaps in the tracked state - the nodes of
+    /// [`x32::ConsoleRequest::full_update`] not replied to within
+    /// `threshold`, and the cue list if it never has been - instead of
+    /// every request every time.  Rewritten by [`X32Console::address_map`]
+    #[must_use]
+    pub fn refresh_requests(&self, threshold : std::time::Duration) -> Vec<osc::Buffer> {
+        let mut requests:Vec<osc::Buffer> = vec![];
+
+        if self.subsystem_age(x32::Subsystem::CueList).is_none() {
+            requests.extend(self.request(x32::ConsoleRequest::ShowInfo()));
+        }
 
-        match index {
-            Some(d) if d < 100 =>
-                self.snippets[d].as_ref().map_or(default, |t| format!("{d:02}:{t}")),
-            _ => default
+        #[cfg(feature = "wing")]
+        if self.dialect == x32::Dialect::Wing {
+            // no node replies - go by when each fader was last reported
+            if self.subsystem_age(x32::Subsystem::CurrentCue).is_none() {
+                requests.extend(self.request(x32::ConsoleRequest::ShowMode()));
+                requests.extend(self.request(x32::ConsoleRequest::CurrentCue()));
+            }
+            requests.extend(self.stale_faders(threshold).into_iter().flat_map(|v| self.request(x32::ConsoleRequest::Fader(v))));
+            return requests;
         }
+
+        let nodes = [x32::ConsoleRequest::ShowMode(), x32::ConsoleRequest::CurrentCue()].into_iter()
+            .chain(self.dialect.faders().map(x32::ConsoleRequest::Fader))
+            .flat_map(Vec::<osc::Buffer>::from)
+            .filter(|buffer| {
+                let node = osc::Message::try_from(buffer.clone()).map(|msg| msg.first_default(String::new())).unwrap_or_default();
+                self.node_age(&node).is_none_or(|age| age > threshold)
+            });
+
+        requests.extend(self.address_map.outgoing(self.dialect.outgoing(nodes)));
+        requests
+    }
+
+    // MARK: ~changes
+    /// Faders and cues changed after generation `since` (use `0` for
+    /// everything), with the current generation to pass next time -
+    /// lets low-frequency pollers sync without handling the OSC stream.
+    /// Nothing is removed, any number of pollers can ask
+    #[must_use]
+    pub fn changes_since(&self, since : u64) -> x32::Changes {
+        self.changes.since(since)
+    }
+
+    /// Generation counted changes, see [`X32Console::changes_since`]
+    #[must_use]
+    pub const fn change_log(&self) -> &x32::ChangeLog {
+        &self.changes
+    }
+
+    /// Vor messages for the faders of a bank changed after generation
+    /// `since` (use `0` for everything reported), with the current
+    /// generation to pass next time
+    #[must_use]
+    pub fn vor_bundle_since(&self, key : &enums::FaderBankKey, since : u64) -> (u64, Vec<osc::Packet>) {
+        let changes = self.changes.since(since);
+        (changes.generation, self.faders.vor_bundle_changed(key, &changes))
+    }
+
+    /// Turn datagrams received on `socket` into a stream of results,
+    /// see [`x32::ResultStream`]
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn into_stream(self, socket : tokio::net::UdpSocket) -> x32::ResultStream {
+        x32::ResultStream::new(self, socket)
+    }
+
+    // MARK: ~compatibility
+    /// Summary of messages that were not understood
+    #[must_use]
+    pub fn compatibility_report(&self) -> &x32::CompatibilityReport {
+        &self.compatibility
+    }
+
+    /// Clear the compatibility report, e.g. after the console firmware changes
+    pub fn reset_compatibility(&mut self) {
+        self.compatibility.reset();
+    }
+
+    // MARK: ~stats
+    /// Traffic counters since creation or the last [`X32Console::take_stats`]
+    #[must_use]
+    pub fn stats(&self) -> &x32::Stats {
+        &self.stats
+    }
+
+    /// Return the traffic counters and start a new period - call from a
+    /// timer for per period figures
+    pub fn take_stats(&mut self) -> x32::Stats {
+        std::mem::take(&mut self.stats)
     }
 
     // MARK: ~process
     /// Process OSC data from the X32
     /// 
-    /// This takes a well formed [`osc::Buffer`] or [`osc::Message`]
+    /// This takes a well formed [`osc::Buffer`] or [`osc::Message`],
+    /// buffers are decoded using [`X32Console::decode_options`], and
+    /// addresses rewritten by [`X32Console::address_map`]
     /// 
     /// Returns [`X32ProcessResult`]
-    pub fn process<T: TryInto<x32::ConsoleMessage>>(&mut self, v : T) -> X32ProcessResult {
-        v.try_into().map_or(X32ProcessResult::NoOperation, |v| self.update(v))
+    pub fn process<T: x32::ConsoleInput>(&mut self, v : T) -> X32ProcessResult {
+        self.try_process(v).unwrap_or(X32ProcessResult::NoOperation)
+    }
+
+    /// Process OSC data from the X32, returning decode and parse errors
+    /// rather than [`X32ProcessResult::NoOperation`].  Errors are also
+    /// counted in [`X32Console::compatibility_report`] and sent to
+    /// [`X32Console::subscribe_errors`] receivers
+    ///
+    /// # Errors
+    /// - [`enums::Error::Packet`] and [`enums::Error::OSC`] for undecodable data
+    /// - [`enums::Error::X32`] for messages with missing or bad arguments
+    pub fn try_process<T: x32::ConsoleInput>(&mut self, v : T) -> Result<X32ProcessResult, enums::Error> {
+        self.try_process_at(v, std::time::Instant::now())
+    }
+
+    /// Process OSC data received at `now` - see [`X32Console::process`]
+    ///
+    /// `now` is recorded as the receive time for the link monitor, fader
+    /// and subsystem ages, and change events.  Use this when replaying a
+    /// capture or draining a buffered channel, where the current time is
+    /// not when the packet arrived
+    pub fn process_at<T: x32::ConsoleInput>(&mut self, v : T, now : std::time::Instant) -> X32ProcessResult {
+        self.try_process_at(v, now).unwrap_or(X32ProcessResult::NoOperation)
+    }
+
+    /// Process OSC data received at `now`, returning decode and parse
+    /// errors - see [`X32Console::try_process`] and [`X32Console::process_at`]
+    ///
+    /// The first message after a silence (see [`X32Console::check_link`])
+    /// returns [`X32ProcessResult::Connection`] instead of its own result -
+    /// the state is still updated, and observers receive both.
+    /// [`X32Console::process_all`] returns both.  The very first message
+    /// returns its own result, only observers see that connection
+    ///
+    /// # Errors
+    /// - [`enums::Error::Packet`] and [`enums::Error::OSC`] for undecodable data
+    /// - [`enums::Error::X32`] for messages with missing or bad arguments
+    pub fn try_process_at<T: x32::ConsoleInput>(&mut self, v : T, now : std::time::Instant) -> Result<X32ProcessResult, enums::Error> {
+        let (link, result) = self.process_input(v, now);
+        result.map(|v| link.unwrap_or(v))
+    }
+
+    /// Process one input - the link transition it caused (if any), and
+    /// its own result
+    fn process_input<T: x32::ConsoleInput>(&mut self, v : T, now : std::time::Instant) -> (Option<X32ProcessResult>, Result<X32ProcessResult, enums::Error>) {
+        self.stats.record(v.wire_len());
+        let msg = match v.into_message(&self.decode_options) {
+            Ok(msg) => msg,
+            Err(e) => {
+                self.malformed(e);
+                return (None, Err(e));
+            },
+        };
+
+        // only decodable data counts as the console being there - the very
+        // first contact goes to observers, but doesn't replace a result
+        let first_contact = self.link.silence(now).is_none();
+        let link = self.link.received(now).map(X32ProcessResult::Connection);
+        if let Some(result) = &link { self.observers.notify(result); }
+
+        (link.filter(|_| !first_contact), self.process_message(msg, now))
+    }
+
+    /// Process a decoded message
+    fn process_message(&mut self, mut msg : osc::Message, now : std::time::Instant) -> Result<X32ProcessResult, enums::Error> {
+        self.address_map.apply_incoming(&mut msg);
+        self.dialect.apply_incoming(&mut msg);
+        self.stats.record_address(&msg);
+        if let Some(node) = Self::reply_node(&msg).filter(|v| x32::ConsoleRequest::is_update_node(v)) {
+            match self.replied.get_mut(node) {
+                Some(replied) => *replied = now,
+                None => { self.replied.insert(node.to_owned(), now); },
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("process", address = %msg.address).entered();
+
+        let parsed = self.subscriptions.contains(&msg.address)
+            .then(|| x32::ConsoleMessage::subscription(&msg))
+            .flatten()
+            .map_or_else(|| self.dialect.parse(&msg), Ok);
+
+        match parsed {
+            Ok(update) => {
+                self.compatibility.add_known();
+                Ok(self.update_at(update, now))
+            },
+            Err(enums::Error::X32(enums::X32Error::UnimplementedPacket)) => {
+                self.compatibility.add_unknown(&msg);
+                #[cfg(feature = "tracing")]
+                tracing::debug!("unhandled message");
+
+                let result = X32ProcessResult::Unhandled { address : msg.address };
+                self.observers.notify(&result);
+                Ok(result)
+            },
+            Err(e) => {
+                self.malformed(e);
+                Err(e)
+            },
+        }
+    }
+
+    /// Node a message answers - the node address of a `node` reply, or the
+    /// address of a single value node (`/-show/prepos/current`)
+    #[expect(clippy::single_call_fn)]
+    fn reply_node(msg : &osc::Message) -> Option<&str> {
+        match (msg.address.as_str(), msg.args.first()) {
+            ("node", Some(osc::Type::String(v))) => v.split_whitespace().next().map(|v| v.trim_start_matches('/')),
+            ("node", _) => None,
+            (address, _) => Some(address.trim_start_matches('/')),
+        }
+    }
+
+    /// Count and report a decode or parse error
+    fn malformed(&mut self, error : enums::Error) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(error = %error, "malformed message");
+
+        self.compatibility.add_malformed();
+        self.stats.record_failure(error);
+        self.observers.notify_error(error);
+    }
+
+    /// Process several inputs in order, returning every result that is not
+    /// [`X32ProcessResult::NoOperation`] - a [`X32ProcessResult::Connection`]
+    /// change comes before the result of the message that caused it
+    pub fn process_all<I>(&mut self, inputs : I) -> Vec<X32ProcessResult> where
+        I: IntoIterator,
+        I::Item: x32::ConsoleInput,
+    {
+        inputs.into_iter()
+            .flat_map(|v| {
+                let (link, result) = self.process_input(v, std::time::Instant::now());
+                link.into_iter().chain(result.ok())
+            })
+            .filter(|v| *v != X32ProcessResult::NoOperation)
+            .collect()
+    }
+
+    /// Process every message in a packet, descending into bundles, returning
+    /// every result that is not [`X32ProcessResult::NoOperation`]
+    pub fn process_packet(&mut self, packet : &osc::Packet) -> Vec<X32ProcessResult> {
+        self.process_all(packet.messages().cloned())
+    }
+
+    /// Process a datagram that may be a message or a bundle - see
+    /// [`X32Console::process_packet`].  Undecodable data returns no results
+    /// (it is counted as a failure, see [`X32Console::stats`])
+    pub fn process_datagram(&mut self, buffer : osc::Buffer) -> Vec<X32ProcessResult> {
+        let bytes = buffer.len();
+
+        match osc::Packet::decode(buffer, &self.decode_options) {
+            Ok(packet) => {
+                self.stats.record_bytes(bytes);
+                self.process_packet(&packet)
+            },
+            Err(error) => {
+                self.stats.record(bytes);
+                self.malformed(error);
+                vec![]
+            },
+        }
     }
 
     /// Update the state machine from processed OSC data
     pub fn update(&mut self, update :x32::ConsoleMessage ) -> X32ProcessResult {
+        self.update_at(update, std::time::Instant::now())
+    }
+
+    /// Update the state machine from processed OSC data received at `now`
+    pub fn update_at(&mut self, update :x32::ConsoleMessage, now : std::time::Instant) -> X32ProcessResult {
+        let cue_key = x32::ChangeKey::from_message(&update);
+        self.updated.insert(update.subsystem(), now);
+        #[cfg(feature = "tracing")]
+        let subsystem = update.subsystem();
+
+        let result = self.apply_update(update, now);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(?subsystem, ?result, "state updated");
+
+        if let Some(key) = cue_key.or_else(|| x32::ChangeKey::from_result(&result)) {
+            self.changes.record(key);
+        }
+
+        self.observers.notify(&result);
+        result
+    }
+
+    /// Apply processed OSC data to the tracked state
+    fn apply_update(&mut self, update :x32::ConsoleMessage, now : std::time::Instant) -> X32ProcessResult {
         match update {
             x32::ConsoleMessage::Meters(v) => X32ProcessResult::Meters(v),
-            x32::ConsoleMessage::Fader(update) => self.faders.update(update),
+            x32::ConsoleMessage::Subscription(v) => X32ProcessResult::Subscription(v),
+            x32::ConsoleMessage::Fader(update) => self.faders.update_at(update, now),
+            x32::ConsoleMessage::Send(update) => {
+                self.sends.update(update);
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::Eq(update) => {
+                if let Some(eq) = x32::Equalizer::new(&update.source) {
+                    self.eqs.entry(update.source.clone()).or_insert(eq).update(&update);
+                }
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::MuteGroups(update) => {
+                self.mute_groups.iter_mut().zip(update).for_each(|(group, v)| {
+                    if let Some(v) = v { *group = v; }
+                });
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::Solo(update) => {
+                self.solo.update(update);
+                X32ProcessResult::Solo(self.solo.is_active())
+            },
+            x32::ConsoleMessage::Recorder(update) => {
+                match update.recorder {
+                    x32::RecorderKind::Tape => self.tape.update(&update),
+                    x32::RecorderKind::Card => self.card.update(&update),
+                }
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::DeviceInfo(info) => {
+                match &mut self.device {
+                    Some(device) => device.update(info),
+                    None => self.device = Some(info),
+                }
+                if let Some(dialect) = self.model().dialect() { self.dialect = dialect; }
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::Routing(update) => {
+                self.routing.update(update);
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::UserControl(update) => {
+                if let Some(bank) = self.user_controls.get_mut(update.bank) { bank.update(update); }
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::Monitor(update) => {
+                self.monitor.update(&update);
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::Safes(update) => {
+                self.safes.update(&update);
+                X32ProcessResult::NoOperation
+            },
+            x32::ConsoleMessage::Preamp(update) => {
+                self.preamps.entry(update.source.clone()).or_default().update(&update);
+                match update.trim {
+                    Some(trim) => self.faders.update_at(x32::updates::FaderUpdate {
+                        source : update.source,
+                        trim : Some(trim),
+                        ..Default::default()
+                    }, now),
+                    None => X32ProcessResult::NoOperation,
+                }
+            },
 
             #[expect(clippy::cast_sign_loss)]
             x32::ConsoleMessage::CurrentCue(v) => {
@@ -168,37 +1041,48 @@ impl X32Console {
                 X32ProcessResult::CurrentCue(self.active_cue())
             },
 
+            // a recall only moves the current position in the list the
+            // show control mode is tracking
+            #[expect(clippy::cast_sign_loss)]
+            x32::ConsoleMessage::Recall(kind, v) => {
+                if kind != self.show_mode || v < 0 { return X32ProcessResult::NoOperation }
+                self.current_cue = Some(v as usize);
+                X32ProcessResult::CurrentCue(self.active_cue())
+            },
+
             x32::ConsoleMessage::ShowMode(v) => {
                 self.show_mode = v;
                 X32ProcessResult::CurrentCue(self.active_cue())
             },
     
-            x32::ConsoleMessage::Cue(v) => {
-                if v.index <= 500 {
-                    self.cues[v.index] = Some(enums::ShowCue{
-                        cue_number: v.cue_number,
-                        name: v.name,
-                        snippet: v.snippet,
-                        scene: v.scene,
-                    });
-                }
-                X32ProcessResult::NoOperation
-            },
+            x32::ConsoleMessage::Cue(v) => Self::store_list_entry(&mut self.cues, enums::ShowMode::Cues, v.index, enums::ShowCue {
+                cue_number: v.cue_number,
+                name: v.name,
+                snippet: v.snippet,
+                scene: v.scene,
+                skip: v.skip,
+            }),
+            x32::ConsoleMessage::Snippet(v) => Self::store_list_entry(&mut self.snippets, enums::ShowMode::Snippets, v.index, v.name),
+            x32::ConsoleMessage::Scene(v) => Self::store_list_entry(&mut self.scenes, enums::ShowMode::Scenes, v.index, v.name),
+        }
+    }
 
-            x32::ConsoleMessage::Snippet(v) => {
-                if v.index <= 500 {
-                    self.snippets[v.index] = Some(v.name.clone());
-                }
-                X32ProcessResult::NoOperation
-            },
+    /// Remove the entries of a cue, scene, or snippet list in `range`, returning their indexes
+    fn remove_list_range<T, R : std::ops::RangeBounds<usize>>(list : &mut BTreeMap<usize, T>, range : R) -> Vec<usize> {
+        // `retain` rather than `range` - an inverted range is empty, not a panic
+        let mut removed = vec![];
+        list.retain(|k, _| {
+            let hit = range.contains(k);
+            if hit { removed.push(*k); }
+            !hit
+        });
+        removed
+    }
 
-            x32::ConsoleMessage::Scene(v) => {
-                if v.index <= 500 {
-                    self.scenes[v.index] = Some(v.name.clone());
-                }
-                X32ProcessResult::NoOperation
-            },
-        }
+    /// Store a cue, scene, or snippet list entry
+    fn store_list_entry<T>(list : &mut BTreeMap<usize, T>, kind : enums::ShowMode, index : usize, entry : T) -> X32ProcessResult {
+        list.insert(index, entry);
+        X32ProcessResult::CueListChanged { kind, index }
     }
 }
 
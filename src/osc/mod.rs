@@ -1,24 +1,23 @@
-/// OSC Decoder and Encoder
-///
-/// Supported types
-/// s :: string - string value (String padded to 32 bit block with nulls)
-/// f :: float - numeric value
-/// d :: double - numeric value
-/// i :: integer - numeric value
-/// h :: big int - numeric value
-/// T :: true - no value (0 bits)
-/// F :: false - no value (0 bits)
-/// N :: null - no value (0 bits)
-/// I :: bang - no value (0 bits)
-/// r :: color - rgbA as an array [R(0-255),G,B,A] (`[u8;4]`)
-/// c :: char - Character
-/// t :: time tag - numeric value (date -> `[u32;2]`)
-/// 
-/// Unsupported types
-/// 
-/// b :: blob (error)
-/// [] :: arrays (ignored)
-
+//! OSC Decoder and Encoder
+//!
+//! Supported types
+//! s :: string - string value (String padded to 32 bit block with nulls)
+//! f :: float - numeric value
+//! d :: double - numeric value
+//! i :: integer - numeric value
+//! h :: big int - numeric value
+//! T :: true - no value (0 bits)
+//! F :: false - no value (0 bits)
+//! N :: null - no value (0 bits)
+//! I :: bang - no value (0 bits)
+//! r :: color - rgbA as an array [R(0-255),G,B,A] (`[u8;4]`)
+//! c :: char - Character
+//! t :: time tag - numeric value (date -> `[u32;2]`)
+//! 
+//! Unsupported types
+//! 
+//! b :: blob (error)
+//! [] :: arrays (ignored)
 
 use std::fmt;
 use std::fmt::Write;
@@ -27,16 +26,29 @@ use std::fmt::Write;
 mod types;
 /// [`Packet`] definitions
 mod packet;
+/// [`Router`] definitions
+mod router;
+/// [`DecodeOptions`] definitions
+mod options;
+/// [`Scheduler`] definitions
+mod scheduler;
+/// [`BufferWriter`] definitions
+mod writer;
 
 use super::enums;
 
-pub use types::Type;
-pub use packet::{Packet, Bundle, Message};
+pub use types::{Type, TimeTag};
+pub use packet::{Packet, Bundle, Message, MessageBuilder, Messages};
+pub use router::{Router, Handler, address_matches};
+pub use options::{DecodeOptions, TextDecoding};
+pub use scheduler::Scheduler;
+pub use writer::BufferWriter;
 
 
 // MARK: Buffer
 /// Buffer with extra methods
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Buffer {
     /// Internal vector data
     data : Vec<u8>,
@@ -63,6 +75,35 @@ impl fmt::Display for Buffer {
     }
 }
 
+/// Classic hex dump - offset, 16 bytes of hex, and printable ASCII
+///
+/// ```text
+/// 00000000  2f 63 68 2f 30 31 2f 6d  69 78 2f 6f 6e 00 00 00  |/ch/01/mix/on...|
+/// 00000010  2c 69 00 00 00 00 00 01                           |,i......|
+/// ```
+impl fmt::LowerHex for Buffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (line, chunk) in self.data.chunks(16).enumerate() {
+            write!(f, "{:08x} ", line * 16)?;
+
+            for i in 0..16 {
+                if i == 8 { f.write_char(' ')?; }
+                match chunk.get(i) {
+                    Some(v) => write!(f, " {v:02x}")?,
+                    None => f.write_str("   ")?,
+                }
+            }
+
+            let ascii:String = chunk.iter()
+                .map(|v| if (32..=126).contains(v) { char::from(*v) } else { '.' })
+                .collect();
+
+            writeln!(f, "  |{ascii}|")?;
+        }
+        Ok(())
+    }
+}
+
 // MARK: Vec<u8>->Buffer
 impl From<Vec<u8>> for Buffer {
     fn from(data: Vec<u8>) -> Self { Self { data } }
@@ -71,7 +112,7 @@ impl From<Vec<u8>> for Buffer {
 // MARK: Vec<ch>->Buffer
 impl From<Vec<char>> for Buffer {
     fn from(data: Vec<char>) -> Self {
-        let data:Vec<u8> = data.into_iter().map(|v| (v as u8)).collect();
+        let data:Vec<u8> = data.into_iter().map(|v| v as u8).collect();
         Self { data }
     }
 }
@@ -79,13 +120,13 @@ impl From<Vec<char>> for Buffer {
 // MARK: Iter<Type>->Buffer
 impl FromIterator<types::Type> for Buffer {
     fn from_iter<T: IntoIterator<Item = types::Type>>(iter: T) -> Self {
-        let mut buffer:Vec<u8> = vec![];
+        let mut writer = BufferWriter::new();
 
         for i in iter {
-            buffer.extend(<types::Type as Into<Vec<u8>>>::into(i));
+            writer.write_type(&i);
         }
 
-        Self::from(buffer)
+        writer.finish()
     }
 }
 
@@ -109,7 +150,25 @@ impl Buffer {
 
     /// check if buffer has a valid length
     #[must_use]
-    pub fn is_valid(&self) -> bool { self.data.len() % 4 == 0 }
+    pub fn is_valid(&self) -> bool { self.data.len().is_multiple_of(4) }
+
+    /// JSON debug representation - length, hex string, and the decoded
+    /// packet (`null` if the buffer does not decode)
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let hex = self.data.iter().fold(String::new(), |mut output, v| {
+            let _ = write!(output, "{v:02x}");
+            output
+        });
+        let decoded = Packet::try_from(self.clone()).ok().map(|v| v.to_string());
+
+        serde_json::json!({
+            "length" : self.data.len(),
+            "hex" : hex,
+            "decoded" : decoded,
+        }).to_string()
+    }
 
     /// check if buffer is empty
     #[must_use]
@@ -144,15 +203,10 @@ impl Buffer {
         } else if !self.is_valid() {
             Err(enums::Error::Packet(enums::PacketError::NotFourByte))
         } else {
-            let mut this_buffer = vec![];
-            while this_buffer.last() != Some(&0_u8) {
-                if self.data.len() < 4 {
-                    return Err(enums::Error::Packet(enums::PacketError::UnterminatedString));
-                }
-                this_buffer.extend(self.data[0..4].to_vec());
-                self.data = self.data[4 .. ].to_vec();
-            }
-            Ok(this_buffer)
+            let Some(end) = self.data.chunks_exact(4).position(|v| v[3] == 0_u8) else {
+                return Err(enums::Error::Packet(enums::PacketError::UnterminatedString));
+            };
+            Ok(self.data.drain(0..(end + 1) * 4).collect())
         }
     }
 
@@ -167,15 +221,12 @@ impl Buffer {
             Ok(vec![])
         } else if self.is_empty() {
             Err(enums::Error::Packet(enums::PacketError::Underrun))
-        } else if !self.is_valid() || length % 4 != 0 {
+        } else if !self.is_valid() || !length.is_multiple_of(4) {
             Err(enums::Error::Packet(enums::PacketError::NotFourByte))
         } else if self.len() < length {
             Err(enums::Error::Packet(enums::PacketError::Underrun))
         } else {
-            let mut this_buffer = vec![];
-            self.data[0..length].clone_into(&mut this_buffer);
-            self.data = self.data[length..].to_vec();
-            Ok(this_buffer)
+            Ok(self.data.drain(0..length).collect())
         }
     }
 
@@ -193,18 +244,13 @@ impl Buffer {
         } else {
             let len_act_buff = [self.data[0], self.data[1], self.data[2], self.data[3]];
             
-            #[expect(clippy::cast_sign_loss)]
-            let len_act = i32::from_be_bytes(len_act_buff) as usize;
-            let len_tot = if len_act % 4 == 0 { len_act } else { len_act + (4 - (len_act % 4)) };
-            let chunk_tot = len_tot + 4;
+            let len_act = u32::from_be_bytes(len_act_buff) as usize;
+            let chunk_tot = len_act.div_ceil(4).saturating_mul(4).saturating_add(4);
 
             if self.data.len() < ( chunk_tot ) {
                 Err(enums::Error::Packet(enums::PacketError::Underrun))
             } else {
-                let mut this_buffer = vec![];
-                self.data[0..chunk_tot].clone_into(&mut this_buffer);
-                self.data = self.data[chunk_tot..].to_vec();
-                Ok(this_buffer)
+                Ok(self.data.drain(0..chunk_tot).collect())
             }
         }
     }
@@ -223,15 +269,12 @@ impl Buffer {
         } else {
             let len_act_buff = [self.data[0], self.data[1], self.data[2], self.data[3]];
 
-            #[expect(clippy::cast_sign_loss)]
-            let chunk_tot = (i32::from_be_bytes(len_act_buff) as usize) + 4;
+            let chunk_tot = (u32::from_be_bytes(len_act_buff) as usize).saturating_add(4);
 
             if self.data.len() < ( chunk_tot ) {
                 Err(enums::Error::Packet(enums::PacketError::Underrun))
             } else {
-                let mut this_buffer = vec![];
-                self.data[4..chunk_tot].clone_into(&mut this_buffer);
-                self.data = self.data[chunk_tot..].to_vec();
+                let this_buffer:Vec<u8> = self.data.drain(0..chunk_tot).skip(4).collect();
                 Ok(Self::from(this_buffer))
             }
         }
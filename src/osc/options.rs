@@ -0,0 +1,65 @@
+use super::super::enums;
+
+// MARK: TextDecoding
+/// Decoding strategy for string arguments
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TextDecoding {
+    /// Reject anything that is not valid UTF-8
+    #[default]
+    Utf8Strict,
+    /// Replace invalid UTF-8 sequences with `U+FFFD`
+    Utf8Lossy,
+    /// Treat every byte as ISO-8859-1 (older firmware scribble strips)
+    Latin1,
+}
+
+impl TextDecoding {
+    /// Decode a (null padded) string buffer
+    ///
+    /// # Errors
+    /// fails on invalid UTF-8 when using [`TextDecoding::Utf8Strict`]
+    pub fn decode(self, arr : &[u8]) -> Result<String, enums::Error> {
+        let decoded = match self {
+            Self::Utf8Strict => std::str::from_utf8(arr)
+                .map_err(|_| enums::Error::OSC(enums::OSCError::ConvertFromString))?
+                .to_owned(),
+            Self::Utf8Lossy => String::from_utf8_lossy(arr).into_owned(),
+            Self::Latin1 => arr.iter().map(|v| char::from(*v)).collect(),
+        };
+
+        Ok(decoded.trim_end_matches(char::from(0)).to_owned())
+    }
+}
+
+// MARK: DecodeOptions
+/// Options used when decoding a [`super::Buffer`]
+///
+/// The `TryFrom<Buffer>` implementations use the defaults.  The limits
+/// keep hostile or corrupted datagrams from recursing or allocating
+/// without bound - exceeding either returns [`enums::PacketError::LimitExceeded`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DecodeOptions {
+    /// String argument decoding
+    pub text : TextDecoding,
+    /// Maximum bundle nesting depth (a bundle inside a bundle is depth 2)
+    pub max_depth : usize,
+    /// Maximum packets in a single bundle, or arguments in a single message
+    pub max_elements : usize,
+}
+
+impl DecodeOptions {
+    /// Default maximum bundle nesting depth
+    pub const DEFAULT_MAX_DEPTH: usize = 8;
+    /// Default maximum elements per bundle or message
+    pub const DEFAULT_MAX_ELEMENTS: usize = 1024;
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self {
+            text : TextDecoding::default(),
+            max_depth : Self::DEFAULT_MAX_DEPTH,
+            max_elements : Self::DEFAULT_MAX_ELEMENTS,
+        }
+    }
+}
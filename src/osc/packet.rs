@@ -5,23 +5,29 @@ use super::super::enums;
 use super::types::TimeTag;
 use super::types::Type;
 use super::Buffer;
+use super::options::DecodeOptions;
+use super::writer::BufferWriter;
 
 
 // MARK: Message
 /// OSC Single Message
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Message {
     /// Address bit
     pub address : String,
     /// Arguments vector
     pub args : Vec<Type>,
     /// Force empty argument list output
+    #[cfg_attr(feature = "serde", serde(default))]
     pub force_empty_args : bool,
 }
 
 // MARK: Bundle
 /// OSC Bundle
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bundle {
     /// Time tag for message
     pub time : TimeTag,
@@ -32,6 +38,7 @@ pub struct Bundle {
 // MARK: Packet
 /// OSC Data Enum
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Packet {
     /// Message Type
     Message(Message),
@@ -47,6 +54,61 @@ impl From<Bundle> for Packet {
     fn from(v: Bundle) -> Self { Self::Bundle(v) }
 }
 
+// MARK: Messages
+/// Iterator over every [`Message`] in a [`Packet`], descending into nested
+/// bundles.  Each message is paired with the time tag of the innermost
+/// bundle containing it (`None` for a bare message)
+///
+/// See [`Packet::messages_with_time`] and [`Packet::messages`]
+#[derive(Debug, Clone)]
+pub struct Messages<'a> {
+    /// Bare message packet, not yet returned
+    single : Option<&'a Message>,
+    /// Bundles being walked, with their time tag
+    stack : Vec<(TimeTag, std::slice::Iter<'a, Packet>)>,
+}
+
+impl<'a> Iterator for Messages<'a> {
+    type Item = (Option<TimeTag>, &'a Message);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(msg) = self.single.take() {
+            return Some((None, msg));
+        }
+
+        loop {
+            let (time, item) = match self.stack.last_mut() {
+                None => return None,
+                Some((time, iter)) => (*time, iter.next()),
+            };
+
+            match item {
+                Some(Packet::Message(msg)) => return Some((Some(time), msg)),
+                Some(Packet::Bundle(bundle)) => self.stack.push((bundle.time, bundle.messages.iter())),
+                None => { self.stack.pop(); },
+            }
+        }
+    }
+}
+
+// MARK: Packet impl
+impl Packet {
+    /// Iterate every message, recursively descending nested bundles
+    pub fn messages(&self) -> impl Iterator<Item = &Message> {
+        self.messages_with_time().map(|(_, msg)| msg)
+    }
+
+    /// Iterate every message paired with its effective time tag
+    /// (the innermost containing bundle), recursively descending nested bundles
+    #[must_use]
+    pub fn messages_with_time(&self) -> Messages<'_> {
+        match self {
+            Self::Message(msg) => Messages { single : Some(msg), stack : vec![] },
+            Self::Bundle(bundle) => Messages { single : None, stack : vec![(bundle.time, bundle.messages.iter())] },
+        }
+    }
+}
+
 // MARK: Bundle impl
 impl Bundle {
     /// Make a new bundle
@@ -85,12 +147,96 @@ impl Bundle {
         let v = v.into();
         self.messages.push(v);
     }
+
+    /// Pack packets, in order, into as few datagrams as fit in `max_len`
+    /// bytes each (see [`Packet::encoded_len`])
+    ///
+    /// Runs of packets are wrapped in immediate bundles, a packet that
+    /// ends up alone is returned as is, and a packet too long to fit on
+    /// its own is returned alone (and still too long).
+    ///
+    /// ```
+    /// use x32_osc_state::osc::{Bundle, Message, Packet};
+    ///
+    /// let packets:Vec<Packet> = (1..=8).map(|v| Message::new(&format!("/ch/0{v}/mix/fader")).into()).collect();
+    ///
+    /// let packed = Bundle::pack(packets.clone(), 128);
+    /// assert_eq!(packed.len(), 2);
+    /// assert!(packed.iter().all(|v| v.encoded_len() <= 128));
+    ///
+    /// assert_eq!(Bundle::pack(packets.clone(), 16), packets);
+    /// ```
+    pub fn pack<T: IntoIterator<Item = Packet>>(packets : T, max_len : usize) -> Vec<Packet> {
+        /// bundle tag and time tag
+        const HEADER : usize = enums::BUNDLE_TAG.len() + 8;
+
+        let mut packed = vec![];
+        let mut pending:Vec<Packet> = vec![];
+        let mut pending_len = HEADER;
+
+        for packet in packets {
+            let len = 4 + packet.encoded_len();
+            if !pending.is_empty() && pending_len + len > max_len {
+                packed.push(Self::packed(std::mem::take(&mut pending)));
+                pending_len = HEADER;
+            }
+            pending.push(packet);
+            pending_len += len;
+        }
+        if !pending.is_empty() { packed.push(Self::packed(pending)); }
+
+        packed
+    }
+
+    /// A single packet as is, several in an immediate bundle
+    fn packed(mut packets : Vec<Packet>) -> Packet {
+        match packets.pop() {
+            Some(packet) if packets.is_empty() => packet,
+            last => {
+                packets.extend(last);
+                Packet::Bundle(Self { time : TimeTag::IMMEDIATE, messages : packets })
+            },
+        }
+    }
 }
 
 impl Default for Bundle {
     fn default() -> Self { Self::new() }
 }
 
+/// generate typed argument getters for `Message`
+macro_rules! arg_getter_impl {
+    ($(($fn_name:ident, $variant:ident, $ty:ty, $flag:literal, |$v:ident| $conv:expr)),*) => {
+        impl Message {
+            $(
+            #[doc = concat!("Get a `", $flag, "` argument by index")]
+            ///
+            /// # Errors
+            /// fails if the argument does not exist or is a different type
+            pub fn $fn_name(&self, index : usize) -> Result<$ty, enums::Error> {
+                match self.get(index)? {
+                    Type::$variant($v) => Ok($conv),
+                    _ => Err(enums::Error::OSC(enums::OSCError::InvalidTypeConversion))
+                }
+            }
+            )*
+        }
+    }
+}
+
+arg_getter_impl! {
+    (get_int, Integer, i32, "i", |v| *v),
+    (get_long, LongInteger, i64, "h", |v| *v),
+    (get_float, Float, f32, "f", |v| *v),
+    (get_double, Double, f64, "d", |v| *v),
+    (get_str, String, &str, "s", |v| v.as_str()),
+    (get_blob, Blob, &[u8], "b", |v| v.as_slice()),
+    (get_bool, Boolean, bool, "T/F", |v| *v),
+    (get_char, Char, char, "c", |v| *v),
+    (get_color, Color, [u8;4], "r", |v| *v),
+    (get_time_tag, TimeTag, TimeTag, "t", |v| *v)
+}
+
 // MARK: Message impl
 impl Message {
     /// New message, relaxed addressing
@@ -113,6 +259,12 @@ impl Message {
         }
     }
 
+    /// Start a [`MessageBuilder`]
+    #[must_use]
+    pub fn builder(address: &str) -> MessageBuilder {
+        MessageBuilder { message : Self::new(address) }
+    }
+
     /// Get the first argument, with a sane default
     /// Note that type is determined by the type of the default
     pub fn first_default<T>(&self, default: T) -> T  where 
@@ -125,6 +277,28 @@ impl Message {
         }
     }
 
+    /// Get the first argument as `f32`, accepting any numeric type
+    /// (see [`Type::as_f32_lossy`])
+    #[must_use]
+    pub fn first_f32_lossy(&self, default: f32) -> f32 {
+        self.args.first().and_then(Type::as_f32_lossy).unwrap_or(default)
+    }
+
+    /// Get the first argument as `i32`, accepting any numeric type
+    /// (see [`Type::as_i32_lossy`])
+    #[must_use]
+    pub fn first_i32_lossy(&self, default: i32) -> i32 {
+        self.args.first().and_then(Type::as_i32_lossy).unwrap_or(default)
+    }
+
+    /// Get an argument by index
+    ///
+    /// # Errors
+    /// fails if the argument does not exist
+    pub fn get(&self, index : usize) -> Result<&Type, enums::Error> {
+        self.args.get(index).ok_or(enums::Error::OSC(enums::OSCError::MissingArgument))
+    }
+
     /// Boolean is message valid
     #[must_use]
     pub fn is_valid(&self) -> bool {
@@ -155,6 +329,113 @@ impl Message {
     }
 }
 
+// MARK: MessageBuilder
+/// Fluent builder for [`Message`]
+///
+/// ```
+/// use x32_osc_state::osc::Message;
+///
+/// let msg = Message::builder("/ch/01/mix/fader").float(0.75).build();
+///
+/// assert_eq!(msg.args.len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct MessageBuilder {
+    /// Message under construction
+    message : Message,
+}
+
+impl MessageBuilder {
+    /// Add any known type
+    #[must_use]
+    pub fn arg<T>(mut self, item : T) -> Self where
+        Type: std::convert::From<T>
+    {
+        self.message.args.push(Type::from(item));
+        self
+    }
+
+    /// Add a 32-bit integer
+    #[must_use]
+    pub fn int(self, v : i32) -> Self { self.arg(v) }
+
+    /// Add a 64-bit integer
+    #[must_use]
+    pub fn long(self, v : i64) -> Self { self.arg(v) }
+
+    /// Add a 32-bit float
+    #[must_use]
+    pub fn float(self, v : f32) -> Self { self.arg(v) }
+
+    /// Add a 64-bit float
+    #[must_use]
+    pub fn double(self, v : f64) -> Self { self.arg(v) }
+
+    /// Add a string
+    #[must_use]
+    pub fn string(self, v : &str) -> Self { self.arg(v) }
+
+    /// Add a boolean (T/F)
+    #[must_use]
+    pub fn bool(self, v : bool) -> Self { self.arg(v) }
+
+    /// Add a character
+    #[must_use]
+    pub fn char(self, v : char) -> Self { self.arg(v) }
+
+    /// Add a color
+    #[must_use]
+    pub fn color(self, v : [u8;4]) -> Self { self.arg(v) }
+
+    /// Add a time tag
+    #[must_use]
+    pub fn time(self, v : TimeTag) -> Self { self.arg(v) }
+
+    /// Add a blob
+    #[must_use]
+    pub fn blob(self, v : Vec<u8>) -> Self { self.arg(Type::Blob(v)) }
+
+    /// Add a null
+    #[must_use]
+    pub fn null(self) -> Self { self.arg(Type::Null()) }
+
+    /// Add a bang
+    #[must_use]
+    pub fn bang(self) -> Self { self.arg(Type::Bang()) }
+
+    /// Send an empty type list (`,`) if no arguments are added
+    #[must_use]
+    pub fn force_empty_args(mut self) -> Self {
+        self.message.force_empty_args = true;
+        self
+    }
+
+    /// Finish the message
+    #[must_use]
+    pub fn build(self) -> Message { self.message }
+}
+
+/// Build a [`Message`] from an address and a list of arguments
+///
+/// Each argument may be any type with a `From<T>` for [`Type`]
+///
+/// ```
+/// use x32_osc_state::{msg, osc::Type};
+///
+/// let msg = msg!("/meters", "/meters/0", 0_i32, 0_i32, 2_i32);
+///
+/// assert_eq!(msg.args[0], Type::String(String::from("/meters/0")));
+/// assert_eq!(msg.args.len(), 4);
+/// ```
+#[macro_export]
+macro_rules! msg {
+    ($address:expr $(, $arg:expr)* $(,)?) => {
+        $crate::osc::Message::builder($address)
+            $(.arg($arg))*
+            .build()
+    };
+}
+
 // MARK: Message->String
 impl fmt::Display for Message {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -175,18 +456,9 @@ impl TryFrom<Message> for Buffer {
     type Error = enums::Error;
 
     fn try_from(value: Message) -> Result<Self, Self::Error> {
-        if !value.is_valid() { return Err(enums::Error::Packet(enums::PacketError::InvalidMessage)); }
-
-        let mut osc_buffer = <Type as Into<Self>>::into(Type::String(value.address.clone()));//.into();
-
-        if value.force_empty_args && value.args.is_empty() {
-            osc_buffer.extend(&Self::from(vec![0x2c, 0x0, 0x0, 0x0]));
-        } else {
-            osc_buffer.extend(&<Type as Into<Self>>::into(value.type_list()));
-        }
-        osc_buffer.extend(&value.args.clone().into_iter().collect());
-
-        Ok(osc_buffer)
+        let mut writer = BufferWriter::with_capacity(value.encoded_len());
+        writer.write_message(&value)?;
+        Ok(writer.finish())
     }
 }
 
@@ -194,7 +466,17 @@ impl TryFrom<Message> for Buffer {
 impl TryFrom<Buffer> for Message {
     type Error = enums::Error;
 
-    fn try_from(mut data: Buffer) -> Result<Self, Self::Error> {
+    fn try_from(data: Buffer) -> Result<Self, Self::Error> {
+        Self::decode(data, &DecodeOptions::default())
+    }
+}
+
+impl Message {
+    /// Decode a message from a buffer using the supplied [`DecodeOptions`]
+    ///
+    /// # Errors
+    /// fails on invalid buffers, unknown types, or argument decoding failures
+    pub fn decode(mut data: Buffer, options : &DecodeOptions) -> Result<Self, enums::Error> {
         if !data.is_valid() {
             Err(enums::Error::Packet(enums::PacketError::NotFourByte))
         } else if let Ok(Type::String(osc_address)) = Type::try_from_buffer(data.next_string(), 's') {
@@ -203,6 +485,9 @@ impl TryFrom<Buffer> for Message {
 
             if let Ok(Type::TypeList(osc_types)) = Type::try_from_buffer(data.next_string(), ',') {
                 if osc_types.is_empty() { force_empty_args = true }
+                if osc_types.len() > options.max_elements {
+                    return Err(enums::Error::Packet(enums::PacketError::LimitExceeded))
+                }
 
                 let type_input_length= osc_types.len();
 
@@ -212,7 +497,7 @@ impl TryFrom<Buffer> for Message {
                     'T' | 'F' => Ok(Type::Boolean(type_flag == 'T')),
                     'N' => Ok(Type::Null()),
                     'I' => Ok(Type::Bang()),
-                    's' => Type::try_from_buffer(data.next_string(), 's'),
+                    's' => data.next_string().and_then(|v| options.text.decode(&v)).map(Type::String),
                     'b' => Type::try_from_buffer(data.next_block_with_size(), 'b'),
                     _ => Err(enums::Error::OSC(enums::OSCError::UnknownType))
                 }.ok()).collect();
@@ -238,20 +523,9 @@ impl TryFrom<Bundle> for Buffer {
     type Error = enums::Error;
 
     fn try_from(value: Bundle) -> Result<Self, Self::Error> {
-        let mut buffer = Self::from(enums::BUNDLE_TAG.to_vec());
-
-        buffer.extend(&Type::TimeTag(value.time).into());
-        
-        for item in value.messages.clone() {
-            let item_buffer = Self::try_from(item)?;
-            let item_length = item_buffer.len();
-
-            #[expect(clippy::cast_possible_truncation)]
-            #[expect(clippy::cast_possible_wrap)]
-            buffer.extend(&Type::Integer(item_length as i32).into());
-            buffer.extend(&item_buffer);
-        }
-        Ok(buffer)
+        let mut writer = BufferWriter::with_capacity(value.encoded_len());
+        writer.write_bundle(&value)?;
+        Ok(writer.finish())
     }
 }
 
@@ -271,20 +545,38 @@ impl fmt::Display for Bundle {
 impl TryFrom<Buffer> for Bundle {
     type Error = enums::Error;
 
-    fn try_from(mut data: Buffer) -> Result<Self, Self::Error> {
+    fn try_from(data: Buffer) -> Result<Self, Self::Error> {
+        Self::decode(data, &DecodeOptions::default())
+    }
+}
+
+impl Bundle {
+    /// Decode a bundle from a buffer using the supplied [`DecodeOptions`]
+    ///
+    /// # Errors
+    /// fails on invalid buffers or invalid contained messages
+    pub fn decode(mut data: Buffer, options : &DecodeOptions) -> Result<Self, enums::Error> {
+        let limit_exceeded = enums::Error::Packet(enums::PacketError::LimitExceeded);
+
         if !data.is_valid() {
             Err(enums::Error::Packet(enums::PacketError::NotFourByte))
+        } else if options.max_depth == 0 {
+            Err(limit_exceeded)
         } else if Ok(enums::BUNDLE_TAG.to_vec()) == data.next_string() {
             let time_tag = Type::try_from_buffer(data.next_bytes(8), 't')?;
             let time = time_tag.try_into()?;
 
+            let inner_options = DecodeOptions { max_depth : options.max_depth - 1, ..*options };
             let mut messages:Vec<Packet> = vec![];
 
             while ! data.is_empty() {
+                if messages.len() >= options.max_elements { return Err(limit_exceeded) }
+
                 match data.next_block() {
                     Ok(buffer) => {
-                        match buffer.try_into() {
+                        match Packet::decode(buffer, &inner_options) {
                             Ok(msg) => messages.push(msg),
+                            Err(e) if e == limit_exceeded => { return Err(e); }
                             Err(_) => { return Err(enums::Error::Packet(enums::PacketError::InvalidBuffer)); }
                         }
                     },
@@ -304,10 +596,9 @@ impl TryFrom<Packet> for Buffer {
     type Error = enums::Error;
 
     fn try_from(value: Packet) -> Result<Self, Self::Error> {
-        match value {
-            Packet::Message(v) => v.try_into(),
-            Packet::Bundle(v) => v.try_into(),
-        }
+        let mut writer = BufferWriter::with_capacity(value.encoded_len());
+        writer.write_packet(&value)?;
+        Ok(writer.finish())
     }
 }
 
@@ -326,18 +617,22 @@ impl TryFrom<Buffer> for Packet {
     type Error = enums::Error;
 
     fn try_from(data: Buffer) -> Result<Self, Self::Error> {
+        Self::decode(data, &DecodeOptions::default())
+    }
+}
+
+impl Packet {
+    /// Decode a message or bundle from a buffer using the supplied [`DecodeOptions`]
+    ///
+    /// # Errors
+    /// fails on invalid buffers or invalid messages
+    pub fn decode(data: Buffer, options : &DecodeOptions) -> Result<Self, enums::Error> {
         if !data.is_valid() {
             Err(enums::Error::Packet(enums::PacketError::NotFourByte))
         } else if data.is_bundle() {
-            match data.try_into() {
-                Ok(v) => Ok(Self::Bundle(v)),
-                Err(v) => Err(v)
-            }
+            Bundle::decode(data, options).map(Self::Bundle)
         } else {
-            match data.try_into() {
-                Ok(v) => Ok(Self::Message(v)),
-                Err(v) => Err(v)
-            }
+            Message::decode(data, options).map(Self::Message)
         }
     }
 }
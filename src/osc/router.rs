@@ -0,0 +1,191 @@
+use super::packet::{Message, Packet};
+use super::scheduler::Scheduler;
+use super::types::TimeTag;
+
+// MARK: Handler
+/// Message handler for the [`Router`]
+///
+/// Implemented for any `FnMut(&Message)` closure
+pub trait Handler {
+    /// Handle a message that matched the registered pattern
+    fn handle(&mut self, msg : &Message);
+}
+
+impl<F: FnMut(&Message)> Handler for F {
+    fn handle(&mut self, msg : &Message) { self(msg); }
+}
+
+/// Registered route
+struct Route {
+    /// OSC address pattern
+    pattern : String,
+    /// Handler to call on match
+    handler : Box<dyn Handler>,
+}
+
+// MARK: Router
+/// OSC message dispatcher
+///
+/// Handlers are registered against OSC address patterns (`?`, `*`,
+/// `[a-z]`, `[!0-9]`, and `{foo,bar}` are supported) and called for
+/// every matching message, including those inside (nested) bundles.
+#[derive(Default)]
+pub struct Router {
+    /// Registered routes, called in order of registration
+    routes : Vec<Route>,
+    /// Hold future dated bundles until they are due
+    honor_time_tags : bool,
+    /// Messages waiting on their time tag
+    pending : Scheduler,
+}
+
+impl std::fmt::Debug for Router {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Router")
+            .field("routes", &self.routes.iter().map(|r| r.pattern.as_str()).collect::<Vec<_>>())
+            .field("honor_time_tags", &self.honor_time_tags)
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+impl Router {
+    /// Make a new, empty router
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Register a handler against an address pattern
+    pub fn add<H: Handler + 'static>(&mut self, pattern : &str, handler : H) -> &mut Self {
+        self.routes.push(Route { pattern : pattern.to_owned(), handler : Box::new(handler) });
+        self
+    }
+
+    /// Hold bundles with a future time tag until [`Router::dispatch_pending`]
+    /// is called at or after that time.  Off by default (everything is
+    /// dispatched immediately)
+    pub fn honor_time_tags(&mut self, honor : bool) -> &mut Self {
+        self.honor_time_tags = honor;
+        self
+    }
+
+    /// Number of messages waiting on their time tag
+    #[must_use]
+    pub fn pending_len(&self) -> usize { self.pending.len() }
+
+    /// Dispatch a packet, returns the number of handler calls made
+    pub fn dispatch(&mut self, packet : &Packet) -> usize {
+        self.dispatch_at(packet, TimeTag::now())
+    }
+
+    /// Dispatch a packet as if the current time were `now`
+    pub fn dispatch_at(&mut self, packet : &Packet, now : TimeTag) -> usize {
+        match packet {
+            Packet::Message(msg) => self.dispatch_message(msg),
+            Packet::Bundle(bundle) => {
+                if self.honor_time_tags && bundle.time > now {
+                    self.pending.add(bundle.clone());
+                    0
+                } else {
+                    bundle.messages.iter().map(|item| self.dispatch_at(item, now)).sum()
+                }
+            }
+        }
+    }
+
+    /// Dispatch any held messages that are due at `now`
+    pub fn dispatch_pending(&mut self, now : TimeTag) -> usize {
+        self.pending.due(now)
+            .iter()
+            .map(|msg| self.dispatch_message(msg))
+            .sum()
+    }
+
+    /// Time tag of the next held message
+    #[must_use]
+    pub fn next_pending(&self) -> Option<TimeTag> { self.pending.next_due() }
+
+    /// Call each handler matching the message address
+    fn dispatch_message(&mut self, msg : &Message) -> usize {
+        let mut count = 0;
+        for route in &mut self.routes {
+            if address_matches(&route.pattern, &msg.address) {
+                route.handler.handle(msg);
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+// MARK: pattern matching
+/// Match an OSC address against an OSC address pattern
+///
+/// Leading slashes are optional on both sides (the X32 replies
+/// to `/node` with `node`)
+#[must_use]
+pub fn address_matches(pattern : &str, address : &str) -> bool {
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    let address = address.strip_prefix('/').unwrap_or(address);
+
+    let mut pattern_parts = pattern.split('/');
+    let mut address_parts = address.split('/');
+
+    loop {
+        match (pattern_parts.next(), address_parts.next()) {
+            (None, None) => return true,
+            (Some(p), Some(a)) => {
+                let p:Vec<char> = p.chars().collect();
+                let a:Vec<char> = a.chars().collect();
+                if !segment_matches(&p, &a) { return false }
+            },
+            _ => return false,
+        }
+    }
+}
+
+/// Match a single address segment
+fn segment_matches(pattern : &[char], text : &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len()).any(|i| segment_matches(&pattern[1..], &text[i..])),
+        Some('?') => !text.is_empty() && segment_matches(&pattern[1..], &text[1..]),
+        Some('[') => {
+            let Some(end) = pattern.iter().position(|c| *c == ']') else { return false };
+            let Some(ch) = text.first() else { return false };
+
+            class_matches(&pattern[1..end], *ch) && segment_matches(&pattern[end + 1..], &text[1..])
+        },
+        Some('{') => {
+            let Some(end) = pattern.iter().position(|c| *c == '}') else { return false };
+
+            pattern[1..end]
+                .split(|c| *c == ',')
+                .any(|alt| text.starts_with(alt) && segment_matches(&pattern[end + 1..], &text[alt.len()..]))
+        },
+        Some(c) => text.first() == Some(c) && segment_matches(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Match a character against a `[...]` class (ranges and `!` negation)
+#[expect(clippy::single_call_fn)]
+fn class_matches(class : &[char], ch : char) -> bool {
+    let (negate, class) = match class.split_first() {
+        Some(('!', rest)) => (true, rest),
+        _ => (false, class),
+    };
+
+    let mut found = false;
+    let mut i = 0;
+
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            found |= (class[i]..=class[i + 2]).contains(&ch);
+            i += 3;
+        } else {
+            found |= class[i] == ch;
+            i += 1;
+        }
+    }
+
+    found != negate
+}
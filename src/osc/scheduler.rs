@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use super::packet::{Bundle, Message, Packet};
+use super::types::TimeTag;
+
+// MARK: Scheduler
+/// Holds bundled messages until their time tag arrives
+///
+/// Messages are released by [`Scheduler::due`] in time tag order (and in
+/// the order they were added for equal tags).  Use [`Scheduler::next_due`]
+/// to decide how long to sleep before checking again.
+#[derive(Debug, Clone, Default)]
+pub struct Scheduler {
+    /// Waiting messages, keyed by time tag and arrival order
+    queue : BTreeMap<(TimeTag, u64), Message>,
+    /// Arrival counter
+    sequence : u64,
+}
+
+impl Scheduler {
+    /// Make a new, empty scheduler
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Add every message in a bundle (nested bundles use their own time tag)
+    pub fn add(&mut self, bundle : Bundle) {
+        self.add_packet(&Packet::Bundle(bundle));
+    }
+
+    /// Add a packet - bare messages are due immediately ([`TimeTag::IMMEDIATE`])
+    pub fn add_packet(&mut self, packet : &Packet) {
+        for (time, msg) in packet.messages_with_time() {
+            self.queue.insert((time.unwrap_or(TimeTag::IMMEDIATE), self.sequence), msg.clone());
+            self.sequence += 1;
+        }
+    }
+
+    /// Remove and return all messages due at or before `now`
+    pub fn due(&mut self, now : TimeTag) -> Vec<Message> {
+        let waiting = self.queue.split_off(&(now, u64::MAX));
+        std::mem::replace(&mut self.queue, waiting).into_values().collect()
+    }
+
+    /// Time tag of the next waiting message
+    #[must_use]
+    pub fn next_due(&self) -> Option<TimeTag> {
+        self.queue.keys().next().map(|(time, _)| *time)
+    }
+
+    /// Number of waiting messages
+    #[must_use]
+    pub fn len(&self) -> usize { self.queue.len() }
+
+    /// Is the scheduler empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.queue.is_empty() }
+
+    /// Drop all waiting messages
+    pub fn clear(&mut self) { self.queue.clear(); }
+}
@@ -1,11 +1,15 @@
-use std::{fmt, time::{Duration, SystemTime, UNIX_EPOCH}};
+use std::{fmt, fmt::Write, time::{Duration, SystemTime, UNIX_EPOCH}};
 
 use super::super::enums;
 use super::Buffer;
+use super::writer::BufferWriter;
+use super::options::TextDecoding;
 
 // MARK: OSCType
 /// OSC Basic Types
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Type {
     /// 4-byte padded string (s:0x73)
     String(String),
@@ -80,6 +84,12 @@ value_impl! {
     (TimeTag, TimeTag)
 }
 
+impl From<&str> for Type {
+    fn from(v: &str) -> Self {
+        Self::String(v.to_owned())
+    }
+}
+
 // MARK: Type->Buffer
 impl From<Type> for Buffer {
     fn from(value: Type) -> Self {
@@ -88,61 +98,21 @@ impl From<Type> for Buffer {
 }
 
 
-/// Pad a string buffer (`Vec<u8>`)
-fn padded_string_buffer(v: &String) -> Vec<u8> {
-    let mut buffer = v.as_bytes().to_vec();
-    let len_act = buffer.len();
-    let len_pad = 4 - (len_act % 4);
-    buffer.resize(len_act + len_pad, 0_u8);
-    buffer
-}
-
 /// Pad a string "string••\[8\]"
-fn padded_string(v: &String) -> String {
-    let buffer = padded_string_buffer(v);
+fn padded_string(v: &str) -> String {
+    let buffer = Vec::from(Type::from(v));
     let len_tot = buffer.len();
     let mut string = String::from_utf8(buffer).expect("invalid string").replace(char::from(0), "•");
-    string.push_str(&format!("[{len_tot}]"));
+    let _ = write!(string, "[{len_tot}]");
     string
 }
 
 // MARK: Types -> Vec<u8>
 impl From<Type> for Vec<u8> {
     fn from(v : Type) -> Self {
-        match v {
-            Type::Integer(v)     => v.to_be_bytes().to_vec(),
-            Type::LongInteger(v) => v.to_be_bytes().to_vec(),
-            Type::Float(v)       => v.to_be_bytes().to_vec(),
-            Type::Double(v)      => v.to_be_bytes().to_vec(),
-
-            Type::Color(v) => v.to_vec(),
-            Type::Char(v) => (v as u32).to_be_bytes().to_vec(),
-            Type::String(v) => padded_string_buffer(&v),
-            Type::TimeTag(v) => v.into(),
-            Type::TypeList(v) => {
-                if v.is_empty() {
-                    vec![]
-                } else {
-                    padded_string_buffer(&format!(",{}", v.into_iter().collect::<String>()))
-                }
-            },
-            Type::Blob(v) => {
-                let mut buffer = vec![];
-                #[expect(clippy::cast_possible_truncation)]
-                #[expect(clippy::cast_possible_wrap)]
-                let size = v.len() as i32;
-
-                buffer.extend(size.to_be_bytes().to_vec());
-                buffer.extend(v);
-
-                let len_act = buffer.len();
-                let len_tot = if len_act % 4 == 0 { len_act} else { len_act + (4 - (len_act % 4)) };
-                buffer.resize(len_tot, 0_u8);
-
-                buffer
-            },
-            _ => vec![],
-        }
+        let mut writer = BufferWriter::with_capacity(v.encoded_len());
+        writer.write_type(&v);
+        writer.finish().data
     }
 }
 
@@ -231,10 +201,7 @@ impl TryFrom<(&[u8], char)> for Type {
 
             ('i' | 'f' | 'h' | 'd' | 'c' | 'r' | 't', _) | (_, 0) => Err(enums::Error::Packet(enums::PacketError::Underrun)),
 
-            ('s', _,) => {
-                let v = std::str::from_utf8(arr).map_err(|_| enums::Error::OSC(enums::OSCError::ConvertFromString))?;
-                Ok(v.trim_end_matches(char::from(0)).to_owned().into())
-            },
+            ('s', _,) => Ok(TextDecoding::Utf8Strict.decode(arr)?.into()),
 
             (',', _) => {
                 let mut type_list:Vec<char> = vec![];
@@ -330,6 +297,36 @@ impl Type {
     {
         T::try_from(self).unwrap_or(default)
     }
+
+    /// get a numeric value as `f32`, converting from any numeric or
+    /// boolean type (firmware is inconsistent about `i` vs `f`)
+    #[must_use]
+    pub fn as_f32_lossy(&self) -> Option<f32> {
+        #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+        match self {
+            Self::Float(v) => Some(*v),
+            Self::Double(v) => Some(*v as f32),
+            Self::Integer(v) => Some(*v as f32),
+            Self::LongInteger(v) => Some(*v as f32),
+            Self::Boolean(v) => Some(if *v { 1.0 } else { 0.0 }),
+            _ => None,
+        }
+    }
+
+    /// get a numeric value as `i32`, converting from any numeric or
+    /// boolean type - floats are rounded, and out of range values saturate
+    #[must_use]
+    pub fn as_i32_lossy(&self) -> Option<i32> {
+        #[expect(clippy::cast_possible_truncation)]
+        match self {
+            Self::Integer(v) => Some(*v),
+            Self::LongInteger(v) => Some((*v).clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32),
+            Self::Float(v) => Some(v.round() as i32),
+            Self::Double(v) => Some(v.round() as i32),
+            Self::Boolean(v) => Some(i32::from(*v)),
+            _ => None,
+        }
+    }
 }
 
 
@@ -338,6 +335,8 @@ impl Type {
 // MARK: OSCTimeTag
 /// OSC Time tag structure
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TimeTag {
     /// seconds since epoch
     seconds: u32,
@@ -382,7 +381,6 @@ impl TimeTag {
     }
 
     /// get a future time tag (now + ms)
-    #[expect(clippy::single_call_fn)]
     #[inline]
     #[must_use]
     pub fn future(ms : u64) -> Self {
@@ -392,6 +390,58 @@ impl TimeTag {
         now.checked_add(adder).map_or_else(Self::default, |v| v.try_into().unwrap_or_default())
     }
 
+    /// The special "immediately" time tag (`0x00000000_00000001`)
+    pub const IMMEDIATE: Self = Self { seconds: 0, fractional: 1 };
+
+    /// is this the special "immediately" time tag
+    #[inline]
+    #[must_use]
+    pub const fn is_immediate(&self) -> bool {
+        self.seconds == 0 && self.fractional == 1
+    }
+
+    /// add a duration, `None` on overflow
+    #[must_use]
+    pub fn checked_add(self, duration : Duration) -> Option<Self> {
+        self.as_ticks().checked_add(Self::duration_to_ticks(duration)?).map(Self::from_ticks)
+    }
+
+    /// subtract a duration, `None` on underflow
+    #[must_use]
+    pub fn checked_sub(self, duration : Duration) -> Option<Self> {
+        self.as_ticks().checked_sub(Self::duration_to_ticks(duration)?).map(Self::from_ticks)
+    }
+
+    /// time elapsed from an earlier tag to this one, `None` if `earlier` is later
+    #[must_use]
+    pub fn duration_since(&self, earlier : &Self) -> Option<Duration> {
+        let ticks = self.as_ticks().checked_sub(earlier.as_ticks())?;
+
+        #[expect(clippy::cast_possible_truncation)]
+        let nanos = ((ticks & u64::from(u32::MAX)) * 1_000_000_000).div_ceil(1 << 32) as u32;
+
+        Some(Duration::new(ticks >> 32, nanos))
+    }
+
+    /// as a 32.32 fixed point number
+    pub(super) const fn as_ticks(self) -> u64 {
+        ((self.seconds as u64) << 32) | self.fractional as u64
+    }
+
+    /// from a 32.32 fixed point number
+    #[expect(clippy::cast_possible_truncation)]
+    const fn from_ticks(ticks : u64) -> Self {
+        Self { seconds: (ticks >> 32) as u32, fractional: ticks as u32 }
+    }
+
+    /// duration as 32.32 fixed point, `None` if it does not fit
+    fn duration_to_ticks(duration : Duration) -> Option<u64> {
+        let seconds = u64::from(u32::try_from(duration.as_secs()).ok()?);
+        let fractional = (u64::from(duration.subsec_nanos()) << 32) / 1_000_000_000;
+
+        Some((seconds << 32) | fractional)
+    }
+
     /// From RFC 5905
     const UNIX_OFFSET: u64 = 2_208_988_800;
     /// Number of bits in a `u32`
@@ -461,8 +511,9 @@ impl From<TimeTag> for SystemTime {
         #[expect(clippy::cast_possible_truncation)]
         #[expect(clippy::cast_sign_loss)]
         let duration_since_osc_epoch = Duration::new(u64::from(time.seconds), nano_secs.round() as u32);
-        let duration_since_unix_epoch =
-            duration_since_osc_epoch - Duration::new(TimeTag::UNIX_OFFSET, 0);
+        let duration_since_unix_epoch = duration_since_osc_epoch
+            .checked_sub(Duration::new(TimeTag::UNIX_OFFSET, 0))
+            .unwrap_or_default();
         UNIX_EPOCH + duration_since_unix_epoch
     }
 }
@@ -470,7 +521,7 @@ impl From<TimeTag> for SystemTime {
 #[cfg(test)]
 mod time_tag_test {
     use super::TimeTag;
-    use std::time::SystemTime;
+    use std::time::{Duration, SystemTime};
     
     #[test]
     fn time_future_test() {
@@ -488,4 +539,28 @@ mod time_tag_test {
 
         assert!(seconds > 4.0 && seconds < 6.0);
     }
+
+    #[test]
+    fn time_arithmetic_test() {
+        let start = TimeTag::from((100_u32, 0_u32));
+        let step = Duration::from_millis(1500);
+
+        let later = start.checked_add(step).expect("no overflow");
+        assert_eq!(later, TimeTag::from((101_u32, 1_u32 << 31)));
+        assert_eq!(later.duration_since(&start), Some(step));
+        assert_eq!(start.duration_since(&later), None);
+        assert_eq!(later.checked_sub(step), Some(start));
+
+        assert_eq!(start.checked_sub(Duration::from_secs(101)), None);
+        assert_eq!(TimeTag::from((u32::MAX, 0_u32)).checked_add(Duration::from_secs(1)), None);
+
+        assert!(TimeTag::IMMEDIATE.is_immediate());
+        assert!(!TimeTag::default().is_immediate());
+        assert!(TimeTag::IMMEDIATE < TimeTag::now());
+
+        let now = TimeTag::now();
+        let soon = now.checked_add(Duration::from_millis(250)).expect("no overflow");
+        let sys_delta = SystemTime::from(soon).duration_since(SystemTime::from(now)).expect("clock drift");
+        assert!(sys_delta.as_millis().abs_diff(250) <= 1);
+    }
 }
\ No newline at end of file
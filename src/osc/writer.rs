@@ -0,0 +1,209 @@
+//! Write cursor for encoding packets straight into a single allocation
+
+use super::super::enums;
+use super::packet::{Bundle, Message, Packet};
+use super::types::{TimeTag, Type};
+use super::Buffer;
+
+/// Padded length of a null terminated string
+const fn padded_len(len : usize) -> usize { (len / 4 + 1) * 4 }
+
+// MARK: BufferWriter
+/// Encoder that writes [`Type`], [`Message`], and [`Bundle`] data into one
+/// pre-sized buffer, rather than concatenating many small vectors
+///
+/// ```
+/// use x32_osc_state::osc::{BufferWriter, Message};
+///
+/// let msg = Message::builder("/ch/01/mix/on").int(1).build();
+/// let mut writer = BufferWriter::with_capacity(msg.encoded_len());
+///
+/// writer.write_message(&msg).expect("valid message");
+/// assert_eq!(writer.finish().len(), 24);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BufferWriter {
+    /// Encoded bytes so far
+    data : Vec<u8>,
+}
+
+impl BufferWriter {
+    /// New, empty writer
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// New writer with room for `capacity` bytes
+    #[must_use]
+    pub fn with_capacity(capacity : usize) -> Self {
+        Self { data : Vec::with_capacity(capacity) }
+    }
+
+    /// Bytes written so far
+    #[must_use]
+    pub fn len(&self) -> usize { self.data.len() }
+
+    /// Nothing written yet
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    /// Finish writing, returning the encoded [`Buffer`]
+    #[must_use]
+    pub fn finish(self) -> Buffer { Buffer::from(self.data) }
+
+    /// Write a null terminated, 4-byte padded string
+    fn write_str(&mut self, v : &str) {
+        let start = self.data.len();
+        self.data.extend_from_slice(v.as_bytes());
+        self.data.resize(start + padded_len(v.len()), 0_u8);
+    }
+
+    /// Write a time tag
+    fn write_time_tag(&mut self, v : TimeTag) {
+        self.data.extend_from_slice(&v.as_ticks().to_be_bytes());
+    }
+
+    /// Write a single [`Type`] (argument data only, no type flag)
+    pub fn write_type(&mut self, v : &Type) {
+        match v {
+            Type::Integer(v)     => self.data.extend_from_slice(&v.to_be_bytes()),
+            Type::LongInteger(v) => self.data.extend_from_slice(&v.to_be_bytes()),
+            Type::Float(v)       => self.data.extend_from_slice(&v.to_be_bytes()),
+            Type::Double(v)      => self.data.extend_from_slice(&v.to_be_bytes()),
+
+            Type::Color(v) => self.data.extend_from_slice(v),
+            Type::Char(v) => self.data.extend_from_slice(&u32::from(*v).to_be_bytes()),
+            Type::String(v) => self.write_str(v),
+            Type::TimeTag(v) => self.write_time_tag(*v),
+            Type::TypeList(v) if !v.is_empty() => {
+                let start = self.data.len();
+                self.data.push(b',');
+                for flag in v {
+                    self.data.extend_from_slice(flag.encode_utf8(&mut [0_u8; 4]).as_bytes());
+                }
+                self.data.resize(start + padded_len(self.data.len() - start), 0_u8);
+            },
+            Type::Blob(v) => {
+                #[expect(clippy::cast_possible_truncation)]
+                #[expect(clippy::cast_possible_wrap)]
+                let size = v.len() as i32;
+                let end = self.data.len() + 4 + v.len().next_multiple_of(4);
+
+                self.data.extend_from_slice(&size.to_be_bytes());
+                self.data.extend_from_slice(v);
+                self.data.resize(end, 0_u8);
+            },
+            _ => {},
+        }
+    }
+
+    /// Write a [`Message`]
+    ///
+    /// # Errors
+    /// fails on invalid messages, leaving the writer untouched
+    pub fn write_message(&mut self, v : &Message) -> Result<(), enums::Error> {
+        if !v.is_valid() { return Err(enums::Error::Packet(enums::PacketError::InvalidMessage)); }
+
+        self.write_str(&v.address);
+
+        if v.force_empty_args && v.args.is_empty() {
+            self.data.extend_from_slice(&[0x2c, 0x0, 0x0, 0x0]);
+        } else if !v.args.is_empty() {
+            let start = self.data.len();
+            self.data.push(b',');
+            self.data.extend(v.args.iter().filter_map(|arg| arg.as_type_char().ok()).map(|flag| flag as u8));
+            self.data.resize(start + padded_len(self.data.len() - start), 0_u8);
+        }
+
+        for arg in &v.args {
+            self.write_type(arg);
+        }
+        Ok(())
+    }
+
+    /// Write a [`Bundle`], including every (possibly nested) element
+    ///
+    /// # Errors
+    /// fails if any contained message is invalid, leaving the writer untouched
+    pub fn write_bundle(&mut self, v : &Bundle) -> Result<(), enums::Error> {
+        let bundle_start = self.data.len();
+        self.data.extend_from_slice(&enums::BUNDLE_TAG);
+        self.write_time_tag(v.time);
+
+        for item in &v.messages {
+            let start = self.data.len();
+            self.data.extend_from_slice(&[0_u8; 4]);
+
+            if let Err(e) = self.write_packet(item) {
+                self.data.truncate(bundle_start);
+                return Err(e);
+            }
+
+            #[expect(clippy::cast_possible_truncation)]
+            #[expect(clippy::cast_possible_wrap)]
+            let item_length = (self.data.len() - start - 4) as i32;
+            self.data[start..start + 4].copy_from_slice(&item_length.to_be_bytes());
+        }
+        Ok(())
+    }
+
+    /// Write a [`Packet`]
+    ///
+    /// # Errors
+    /// fails if any contained message is invalid, leaving the writer untouched
+    pub fn write_packet(&mut self, v : &Packet) -> Result<(), enums::Error> {
+        match v {
+            Packet::Message(v) => self.write_message(v),
+            Packet::Bundle(v) => self.write_bundle(v),
+        }
+    }
+}
+
+// MARK: encoded_len
+impl Type {
+    /// Length in bytes of the encoded argument data
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            Self::Integer(_) | Self::Float(_) | Self::Color(_) | Self::Char(_) => 4,
+            Self::LongInteger(_) | Self::Double(_) | Self::TimeTag(_) => 8,
+            Self::String(v) => padded_len(v.len()),
+            Self::TypeList(v) if v.is_empty() => 0,
+            Self::TypeList(v) => padded_len(1 + v.iter().map(|c| c.len_utf8()).sum::<usize>()),
+            Self::Blob(v) => 4 + v.len().next_multiple_of(4),
+            Self::Boolean(_) | Self::Null() | Self::Bang() | Self::Unknown() => 0,
+        }
+    }
+}
+
+impl Message {
+    /// Length in bytes of the encoded message
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        let type_len = if self.args.is_empty() {
+            if self.force_empty_args { 4 } else { 0 }
+        } else {
+            padded_len(1 + self.args.iter().filter(|v| v.as_type_char().is_ok()).count())
+        };
+
+        padded_len(self.address.len()) + type_len + self.args.iter().map(Type::encoded_len).sum::<usize>()
+    }
+}
+
+impl Bundle {
+    /// Length in bytes of the encoded bundle
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        enums::BUNDLE_TAG.len() + 8 + self.messages.iter().map(|v| 4 + v.encoded_len()).sum::<usize>()
+    }
+}
+
+impl Packet {
+    /// Length in bytes of the encoded packet
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            Self::Message(v) => v.encoded_len(),
+            Self::Bundle(v) => v.encoded_len(),
+        }
+    }
+}
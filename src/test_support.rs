@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::enums::{Fader, FaderIndex};
+use super::osc::{Buffer, Message};
+use super::X32Console;
+
+// MARK: XorShift
+/// Small deterministic PRNG (xorshift64*), so runs are reproducible from a seed
+#[derive(Debug, Clone)]
+struct XorShift(u64);
+
+impl XorShift {
+    /// next raw value
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// uniform-ish value in `0..max`
+    #[expect(clippy::cast_possible_truncation)]
+    fn below(&mut self, max : usize) -> usize {
+        (self.next_u64() % max.max(1) as u64) as usize
+    }
+
+    /// value in `0.0..1.0`
+    #[expect(clippy::cast_precision_loss)]
+    fn unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1_u64 << 24) as f32
+    }
+}
+
+// MARK: TrafficMix
+/// Relative weights of each kind of generated traffic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrafficMix {
+    /// node format fader, cue, and scene lines
+    pub node : u32,
+    /// standard OSC fader messages
+    pub standard : u32,
+    /// meter blobs
+    pub meters : u32,
+    /// malformed or truncated packets
+    pub malformed : u32,
+}
+
+impl Default for TrafficMix {
+    fn default() -> Self {
+        Self { node : 30, standard : 40, meters : 25, malformed : 5 }
+    }
+}
+
+// MARK: TrafficGenerator
+/// Randomized, reproducible console traffic for soak tests
+///
+/// Iterates `(elapsed, buffer)` pairs, where `elapsed` is the simulated
+/// show time the packet arrives at.  The generator tracks the level
+/// every fader *should* have, see [`TrafficGenerator::drift`].
+///
+/// ```
+/// use std::time::Duration;
+/// use x32_osc_state::X32Console;
+/// use x32_osc_state::test_support::TrafficGenerator;
+///
+/// let mut console = X32Console::new();
+/// let mut traffic = TrafficGenerator::new(42);
+///
+/// for (_, buffer) in traffic.by_ref().take_while(|(t, _)| *t < Duration::from_secs(60)) {
+///     console.process(buffer);
+/// }
+///
+/// assert!(traffic.drift(&console).is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrafficGenerator {
+    /// random source
+    rng : XorShift,
+    /// traffic weights
+    mix : TrafficMix,
+    /// simulated show time
+    elapsed : Duration,
+    /// longest gap between packets
+    max_gap : Duration,
+    /// expected fader levels
+    expected : BTreeMap<FaderIndex, f32>,
+}
+
+impl TrafficGenerator {
+    /// Make a new generator from a seed, using the default [`TrafficMix`]
+    #[must_use]
+    pub fn new(seed : u64) -> Self {
+        Self {
+            rng : XorShift(seed | 1),
+            mix : TrafficMix::default(),
+            elapsed : Duration::ZERO,
+            max_gap : Duration::from_millis(50),
+            expected : BTreeMap::new(),
+        }
+    }
+
+    /// Set the traffic weights
+    #[must_use]
+    pub const fn with_mix(mut self, mix : TrafficMix) -> Self {
+        self.mix = mix;
+        self
+    }
+
+    /// Set the longest gap between packets (default 50ms)
+    #[must_use]
+    pub const fn with_max_gap(mut self, gap : Duration) -> Self {
+        self.max_gap = gap;
+        self
+    }
+
+    /// Simulated show time so far
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration { self.elapsed }
+
+    /// Faders whose level in `console` differs from the generated traffic
+    #[must_use]
+    pub fn drift(&self, console : &X32Console) -> Vec<FaderIndex> {
+        self.expected.iter()
+            .filter(|(index, level)| console.fader(index).is_none_or(|f| (f.level().0 - **level).abs() > f32::EPSILON))
+            .map(|(index, _)| index.clone())
+            .collect()
+    }
+
+    /// Pick a random fader
+    fn fader(&mut self) -> FaderIndex {
+        let index = self.rng.below(8) + 1;
+        match self.rng.below(6) {
+            0 => FaderIndex::Aux(index),
+            1 => FaderIndex::Matrix(index.min(6)),
+            2 => FaderIndex::Main(index.min(2)),
+            3 => FaderIndex::Dca(index),
+            4 => FaderIndex::Bus(index * 2),
+            _ => FaderIndex::Channel(index * 4),
+        }
+    }
+
+    /// Node format line
+    fn node(&mut self) -> Message {
+        let fader = self.fader();
+        let address = fader.get_x32_address();
+
+        let line = match self.rng.below(4) {
+            0 => format!("/-show/showfile/cue/{:03} {}00 \"Cue {}\" 1 {} -1 0 1 0 0",
+                self.rng.below(100), self.rng.below(50), self.rng.below(1000), self.rng.below(100)),
+            1 => format!("/-show/showfile/scene/{:03} \"Scene {}\" \"\" %111111110 1",
+                self.rng.below(100), self.rng.below(1000)),
+            2 => format!("/{address}/config \"Strip {}\" 1 RD 1", self.rng.below(1000)),
+            _ => {
+                let level = Fader::level_to_string(self.rng.unit());
+                let level = level.trim_end_matches(" dB");
+                self.expected.insert(fader.clone(), Fader::level_from_string(level));
+
+                if matches!(fader, FaderIndex::Dca(_)) {
+                    format!("/{address} ON {level}")
+                } else {
+                    format!("/{address}/mix ON {level} ON +0 OFF -oo")
+                }
+            },
+        };
+
+        Message::builder("node").string(&line).build()
+    }
+
+    /// Standard OSC fader message
+    fn standard(&mut self) -> Message {
+        let fader = self.fader();
+        let address = fader.get_x32_address();
+        let mix = if matches!(fader, FaderIndex::Dca(_)) { "" } else { "/mix" };
+
+        match self.rng.below(3) {
+            0 => Message::builder(&format!("/{address}{mix}/on")).int(i32::from(self.rng.below(2) == 1)).build(),
+            1 => Message::builder(&format!("/{address}/config/name")).string(&format!("Name {}", self.rng.below(1000))).build(),
+            _ => {
+                let level = self.rng.unit();
+                self.expected.insert(fader, level);
+                Message::builder(&format!("/{address}{mix}/fader")).float(level).build()
+            },
+        }
+    }
+
+    /// Meter blob
+    fn meters(&mut self) -> Message {
+        let count = 1 + self.rng.below(70);
+        let mut blob = u32::try_from(count).unwrap_or_default().to_le_bytes().to_vec();
+
+        for _ in 0..count {
+            blob.extend(self.rng.unit().to_le_bytes());
+        }
+
+        Message::builder(&format!("/meters/{}", self.rng.below(16))).blob(blob).build()
+    }
+
+    /// Random bytes, or a truncated valid packet
+    fn malformed(&mut self) -> Buffer {
+        if self.rng.below(2) == 0 {
+            let length = self.rng.below(64);
+            #[expect(clippy::cast_possible_truncation)]
+            let data:Vec<u8> = (0..length).map(|_| self.rng.next_u64() as u8).collect();
+            Buffer::from(data)
+        } else {
+            let address = format!("/{}/mix/fader", self.fader().get_x32_address());
+            let mut data = Buffer::try_from(Message::builder(&address).float(self.rng.unit()).build())
+                .unwrap_or_default()
+                .as_vec();
+
+            // never 4-byte aligned, so never a valid (shorter) message
+            data.truncate(self.rng.below(data.len() / 4) * 4 + 1 + self.rng.below(3));
+            Buffer::from(data)
+        }
+    }
+}
+
+impl Iterator for TrafficGenerator {
+    type Item = (Duration, Buffer);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let gap_ms = u64::try_from(self.max_gap.as_millis()).unwrap_or(u64::MAX);
+        self.elapsed += Duration::from_millis(self.rng.next_u64() % gap_ms.max(1));
+
+        // summed as u64, so no mix of u32 weights can overflow
+        let [node, standard, meters, malformed] = [self.mix.node, self.mix.standard, self.mix.meters, self.mix.malformed].map(u64::from);
+        let pick = self.rng.next_u64() % (node + standard + meters + malformed).max(1);
+
+        let buffer = if pick < node {
+            Buffer::try_from(self.node()).unwrap_or_default()
+        } else if pick < node + standard {
+            Buffer::try_from(self.standard()).unwrap_or_default()
+        } else if pick < node + standard + meters {
+            Buffer::try_from(self.meters()).unwrap_or_default()
+        } else {
+            self.malformed()
+        };
+
+        Some((self.elapsed, buffer))
+    }
+}
@@ -0,0 +1,158 @@
+use super::enums::{Fader, FaderColor, FaderIndex, ResolvedCue};
+use super::{X32Console, X32ProcessResult};
+
+// MARK: ViewModel
+/// Change notification hook for view models
+///
+/// Feed every [`X32ProcessResult`] to [`ViewModel::apply`] - immediate-mode
+/// frameworks can use the return value to request a repaint, Elm-style
+/// frameworks can call it from their `update` function.
+pub trait ViewModel {
+    /// Apply a process result, returns true when the view changed
+    fn apply(&mut self, result : &X32ProcessResult) -> bool;
+}
+
+// MARK: StripView
+/// View model for a single channel strip
+#[derive(Debug, Clone, PartialEq)]
+pub struct StripView {
+    /// fader index
+    pub source : FaderIndex,
+    /// scribble strip label, or the default name
+    pub name : String,
+    /// fader level (0.0 - 1.0)
+    pub level : f32,
+    /// fader level, as dB text
+    pub level_label : String,
+    /// mute status (true is un-muted)
+    pub is_on : bool,
+    /// scribble strip color
+    pub color : FaderColor,
+}
+
+impl From<&Fader> for StripView {
+    fn from(fader : &Fader) -> Self {
+        let (level, level_label) = fader.level();
+
+        Self {
+            source : fader.source().clone(),
+            name : fader.name().to_owned(),
+            level, level_label,
+            is_on : fader.is_on().0,
+            color : fader.color(),
+        }
+    }
+}
+
+impl ViewModel for StripView {
+    fn apply(&mut self, result : &X32ProcessResult) -> bool {
+        match result {
+            X32ProcessResult::Fader((fader, _)) if fader.source() == &self.source => {
+                let updated = Self::from(fader);
+                let changed = updated != *self;
+                *self = updated;
+                changed
+            },
+            _ => false,
+        }
+    }
+}
+
+// MARK: CueRowView
+/// View model for a row in the cue list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CueRowView {
+    /// index in cue list
+    pub index : usize,
+    /// displayed cue number
+    pub cue_number : String,
+    /// cue name
+    pub name : String,
+    /// linked scene (`{index:02}:{name}` or `--`)
+    pub scene : String,
+    /// linked snippet (`{index:02}:{name}` or `--`)
+    pub snippet : String,
+    /// skipped when stepping through the list - show greyed out
+    pub skip : bool,
+}
+
+impl From<&ResolvedCue<'_>> for CueRowView {
+    fn from(cue : &ResolvedCue<'_>) -> Self {
+        Self {
+            index : cue.index,
+            cue_number : cue.cue.cue_number.clone(),
+            name : cue.cue.name.clone(),
+            scene : ResolvedCue::link_label(cue.cue.scene, cue.scene),
+            snippet : ResolvedCue::link_label(cue.cue.snippet, cue.snippet),
+            skip : cue.cue.skip,
+        }
+    }
+}
+
+// MARK: ConsoleView
+/// View model for a whole console - every strip, the cue list, and the active cue
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsoleView {
+    /// channel strips, in console order (channels, aux, bus, matrix, dca, main)
+    pub strips : Vec<StripView>,
+    /// cue list rows
+    pub cues : Vec<CueRowView>,
+    /// current cue index
+    pub current_cue : Option<usize>,
+    /// active cue, scene, or snippet (see [`X32Console::active_cue`])
+    pub active_cue : String,
+    /// a cue, scene, or snippet changed since the cue list rows were built -
+    /// call [`ConsoleView::sync_cues`]
+    pub cues_stale : bool,
+}
+
+impl ConsoleView {
+    /// Build a view of the current console state
+    #[must_use]
+    pub fn new(console : &X32Console) -> Self {
+        let strips = FaderIndex::all()
+            .filter_map(|index| console.fader(&index))
+            .map(StripView::from)
+            .collect();
+
+        let mut view = Self { strips, cues : vec![], current_cue : None, active_cue : String::new(), cues_stale : false };
+        view.sync_cues(console);
+        view
+    }
+
+    /// Rebuild the cue list rows - needed after a
+    /// [`X32ProcessResult::CueListChanged`], which only carries the index
+    /// (see [`ConsoleView::cues_stale`])
+    pub fn sync_cues(&mut self, console : &X32Console) {
+        self.cues = console.cues.keys()
+            .filter_map(|index| console.resolve_cue(*index))
+            .map(|cue| CueRowView::from(&cue))
+            .collect();
+        self.current_cue = console.current_cue;
+        self.active_cue = console.active_cue();
+        self.cues_stale = false;
+    }
+
+    /// Find a strip by fader index
+    #[must_use]
+    pub fn strip(&self, source : &FaderIndex) -> Option<&StripView> {
+        self.strips.iter().find(|strip| &strip.source == source)
+    }
+}
+
+impl ViewModel for ConsoleView {
+    fn apply(&mut self, result : &X32ProcessResult) -> bool {
+        match result {
+            X32ProcessResult::Fader(_) => self.strips.iter_mut().any(|strip| strip.apply(result)),
+            X32ProcessResult::CurrentCue(label) if *label != self.active_cue => {
+                label.clone_into(&mut self.active_cue);
+                true
+            },
+            X32ProcessResult::CueListChanged { .. } => {
+                self.cues_stale = true;
+                true
+            },
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+use crate::osc::Buffer;
+use crate::{X32Console, X32ProcessResult};
+
+/// File header, magic and version
+const HEADER : [u8; 8] = *b"X32CAP\x00\x01";
+/// Longest record accepted, on capture and replay - the largest UDP payload
+const MAX_RECORD : usize = 65_535;
+
+// MARK: Recorder
+/// Writes timestamped buffers to a capture, for replay with [`Replayer`]
+///
+/// A capture file is an 8 byte header (`X32CAP` and a 2 byte version)
+/// followed by one record per buffer - microseconds since the capture
+/// started (`u64`, little endian), the buffer length (`u32`, little
+/// endian), and the raw buffer.
+///
+/// ```
+/// use std::time::Duration;
+/// use x32_osc_state::{osc, X32Console};
+/// use x32_osc_state::enums::FaderIndex;
+/// use x32_osc_state::x32::capture::{Recorder, Replayer};
+///
+/// let mut recorder = Recorder::new(vec![]).expect("header written");
+/// let buffer:osc::Buffer = osc::Message::builder("/ch/01/mix/fader").float(0.5).build().try_into().expect("valid buffer");
+/// recorder.record_at(Duration::from_millis(20), &buffer).expect("record written");
+///
+/// let capture = recorder.into_inner();
+/// let mut console = X32Console::new();
+/// let replayer = Replayer::new(capture.as_slice()).expect("valid capture");
+/// replayer.replay(&mut console, false).expect("capture replayed");
+///
+/// assert_eq!(console.fader(&FaderIndex::Channel(1)).expect("valid fader").level().0, 0.5);
+/// ```
+#[derive(Debug)]
+pub struct Recorder<W : Write> {
+    /// capture destination
+    writer : W,
+    /// capture start
+    start : Instant,
+}
+
+impl<W : Write> Recorder<W> {
+    /// Start a capture, writing the header
+    ///
+    /// # Errors
+    /// fails on write errors
+    pub fn new(mut writer : W) -> io::Result<Self> {
+        writer.write_all(&HEADER)?;
+        Ok(Self { writer, start : Instant::now() })
+    }
+
+    /// Record a buffer received now
+    ///
+    /// # Errors
+    /// fails on write errors
+    pub fn record(&mut self, buffer : &Buffer) -> io::Result<()> {
+        self.record_at(self.start.elapsed(), buffer)
+    }
+
+    /// Record a buffer received `elapsed` after the capture started
+    ///
+    /// # Errors
+    /// fails on write errors, or buffers longer than a datagram (65535
+    /// bytes) that could not be replayed
+    pub fn record_at(&mut self, elapsed : Duration, buffer : &Buffer) -> io::Result<()> {
+        if buffer.len() > MAX_RECORD {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "buffer longer than a datagram"));
+        }
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        let length = u32::try_from(buffer.len()).map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+
+        self.writer.write_all(&micros.to_le_bytes())?;
+        self.writer.write_all(&length.to_le_bytes())?;
+        self.writer.write_all(buffer.as_slice())
+    }
+
+    /// Record a buffer, then process it
+    ///
+    /// # Errors
+    /// fails on write errors (the buffer is not processed)
+    pub fn process(&mut self, console : &mut X32Console, buffer : Buffer) -> io::Result<X32ProcessResult> {
+        self.record(&buffer)?;
+        Ok(console.process(buffer))
+    }
+
+    /// Flush and return the writer
+    ///
+    /// # Errors
+    /// fails on write errors
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Return the writer, without flushing
+    #[must_use]
+    pub fn into_inner(self) -> W { self.writer }
+}
+
+// MARK: Replayer
+/// Reads timestamped buffers from a capture
+///
+/// Iterates `(elapsed, buffer)` pairs, stopping at the end of the capture
+#[derive(Debug)]
+pub struct Replayer<R : Read> {
+    /// capture source
+    reader : R,
+}
+
+impl<R : Read> Replayer<R> {
+    /// Open a capture, checking the header
+    ///
+    /// # Errors
+    /// fails on read errors, or if this is not a capture
+    pub fn new(mut reader : R) -> io::Result<Self> {
+        let mut header = [0_u8; 8];
+        reader.read_exact(&mut header)?;
+
+        if header == HEADER {
+            Ok(Self { reader })
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "not an x32 capture"))
+        }
+    }
+
+    /// Feed every buffer into `console`, sleeping between them to keep the
+    /// original pacing if `paced`, returning the results that are not
+    /// [`X32ProcessResult::NoOperation`].  Buffers are processed with their
+    /// recorded receive time, relative to the start of the replay
+    ///
+    /// # Errors
+    /// fails on read errors
+    pub fn replay(self, console : &mut X32Console, paced : bool) -> io::Result<Vec<X32ProcessResult>> {
+        let start = Instant::now();
+        let mut results = vec![];
+
+        for record in self {
+            let (elapsed, buffer) = record?;
+            if paced {
+                std::thread::sleep(elapsed.saturating_sub(start.elapsed()));
+            }
+
+            let result = console.process_at(buffer, start + elapsed);
+            if result != X32ProcessResult::NoOperation { results.push(result); }
+        }
+        Ok(results)
+    }
+
+    /// Read one record, `None` at a clean end of file
+    ///
+    /// A truncated record is [`io::ErrorKind::UnexpectedEof`], and a
+    /// record longer than a UDP datagram is [`io::ErrorKind::InvalidData`]
+    fn read_record(&mut self) -> io::Result<Option<(Duration, Buffer)>> {
+        let mut micros = [0_u8; 8];
+        let mut filled = 0;
+        while filled < micros.len() {
+            match self.reader.read(micros.get_mut(filled..).unwrap_or_default()) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut length = [0_u8; 4];
+        self.reader.read_exact(&mut length)?;
+
+        let length = usize::try_from(u32::from_le_bytes(length)).unwrap_or(usize::MAX);
+        if length > MAX_RECORD {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "capture record longer than a datagram"));
+        }
+
+        let mut data = vec![0_u8; length];
+        self.reader.read_exact(&mut data)?;
+
+        Ok(Some((Duration::from_micros(u64::from_le_bytes(micros)), Buffer::from(data))))
+    }
+}
+
+impl<R : Read> Iterator for Replayer<R> {
+    type Item = io::Result<(Duration, Buffer)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
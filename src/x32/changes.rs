@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+use crate::enums::FaderIndex;
+use crate::x32::ConsoleMessage;
+use crate::X32ProcessResult;
+
+// MARK: ChangeKey
+/// Something in the tracked state that changed
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKey {
+    /// A fader parameter
+    Fader(FaderIndex),
+    /// A cue list entry
+    Cue(usize),
+    /// A scene list entry
+    Scene(usize),
+    /// A snippet list entry
+    Snippet(usize),
+    /// The current cue or show mode
+    CurrentCue,
+}
+
+impl ChangeKey {
+    /// Key for a cue list or current cue update
+    #[must_use]
+    pub fn from_message(update : &ConsoleMessage) -> Option<Self> {
+        match update {
+            ConsoleMessage::Cue(v) => Some(Self::Cue(v.index)),
+            ConsoleMessage::Scene(v) => Some(Self::Scene(v.index)),
+            ConsoleMessage::Snippet(v) => Some(Self::Snippet(v.index)),
+            ConsoleMessage::CurrentCue(_) | ConsoleMessage::Recall(..) | ConsoleMessage::ShowMode(_) => Some(Self::CurrentCue),
+            _ => None,
+        }
+    }
+
+    /// Key for a fader result - only when a parameter actually changed
+    #[must_use]
+    pub fn from_result(result : &X32ProcessResult) -> Option<Self> {
+        match result {
+            X32ProcessResult::Fader((fader, change)) if !change.is_empty() => Some(Self::Fader(fader.source().clone())),
+            _ => None,
+        }
+    }
+}
+
+// MARK: Changes
+/// Changes since a generation, see [`crate::X32Console::changes_since`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Changes {
+    /// current generation - pass it to the next call
+    pub generation : u64,
+    /// what changed, each listed once
+    pub keys : Vec<ChangeKey>,
+}
+
+// MARK: ChangeLog
+/// Dirty tracking - the generation each item last changed in
+///
+/// Every recorded change bumps the generation.  Only the latest generation
+/// per item is kept, so the log stays the size of the tracked state no
+/// matter how many pollers there are or how rarely they poll
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeLog {
+    /// current generation
+    generation : u64,
+    /// generation of the last change, by item
+    changed : BTreeMap<ChangeKey, u64>,
+}
+
+impl ChangeLog {
+    /// Record a change, returning the new generation
+    pub fn record(&mut self, key : ChangeKey) -> u64 {
+        self.generation += 1;
+        self.changed.insert(key, self.generation);
+        self.generation
+    }
+
+    /// Current generation
+    #[must_use]
+    pub fn generation(&self) -> u64 { self.generation }
+
+    /// Everything changed after generation `since`
+    #[must_use]
+    pub fn since(&self, since : u64) -> Changes {
+        Changes {
+            generation : self.generation,
+            keys : self.changed.iter()
+                .filter(|(_, generation)| **generation > since)
+                .map(|(key, _)| key.clone())
+                .collect(),
+        }
+    }
+
+    /// Every recorded item with the generation it last changed in, oldest
+    /// change first
+    #[must_use]
+    pub fn history(&self) -> Vec<(u64, ChangeKey)> {
+        let mut history:Vec<(u64, ChangeKey)> = self.changed.iter()
+            .map(|(key, generation)| (*generation, key.clone()))
+            .collect();
+        history.sort_unstable();
+        history
+    }
+
+    /// Forget recorded changes, keeping the generation counter (so
+    /// pollers never see it go backwards)
+    pub fn clear(&mut self) {
+        self.changed.clear();
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::VecDeque;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_core::Stream;
+use tokio::io::ReadBuf;
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::{osc, X32Console, X32ProcessResult};
+use super::{ConsoleRequest, KeepAlive, PacedSender};
+
+/// Largest datagram the console sends
+const MAX_DATAGRAM : usize = 8192;
+/// How often the send task wakes to check the keep-alive
+const TICK : Duration = Duration::from_secs(1);
+
+/// Is a socket error just the console being unreachable for now - an
+/// ICMP port unreachable on a connected UDP socket is reported on the
+/// next send or receive, and the console answers again once it is back
+fn is_transient(error : &io::Error) -> bool {
+    matches!(error.kind(), io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset)
+}
+
+// MARK: Client
+/// Async UDP client for a console
+///
+/// Owns the socket and an [`X32Console`].  A background task sends
+/// `/xremote` (see [`KeepAlive`]) and a full update every
+/// `refresh` interval, another feeds every received datagram into the
+/// console.  Results (other than [`X32ProcessResult::NoOperation`]) are
+/// available from [`Client::recv`], or by using the client as a
+/// [`Stream`].  An unreachable console does not stop the tasks - they
+/// keep trying until it answers.  The tasks stop when the client is
+/// dropped, or on any other socket error.
+///
+/// ```no_run
+/// # async fn run() -> std::io::Result<()> {
+/// use x32_osc_state::x32::Client;
+///
+/// let mut client = Client::connect("192.168.1.77:10023".parse().expect("address")).await?;
+/// while let Some(result) = client.recv().await {
+///     println!("{result:?} - {}", client.console().active_cue());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Client {
+    /// bound socket
+    socket : Arc<UdpSocket>,
+    /// tracked state
+    console : Arc<Mutex<X32Console>>,
+    /// processed results
+    results : mpsc::UnboundedReceiver<X32ProcessResult>,
+    /// send and receive tasks
+    tasks : [JoinHandle<()>; 2],
+}
+
+impl Client {
+    /// Connect to a console, with a full update every 5 minutes
+    ///
+    /// # Errors
+    /// fails if the local socket can't be bound
+    pub async fn connect(remote : SocketAddr) -> io::Result<Self> {
+        Box::pin(Self::connect_with(remote, X32Console::new(), Duration::from_secs(300))).await
+    }
+
+    /// Connect to a console with an existing state (e.g. restored from a
+    /// [`super::Snapshot`]), and a full update every `refresh`
+    ///
+    /// # Errors
+    /// fails if the local socket can't be bound
+    pub async fn connect_with(remote : SocketAddr, console : X32Console, refresh : Duration) -> io::Result<Self> {
+        let local:SocketAddr = if remote.is_ipv4() { ([0, 0, 0, 0], 0).into() } else { ([0_u16; 8], 0).into() };
+        let socket = UdpSocket::bind(local).await?;
+        socket.connect(remote).await?;
+        let socket = Arc::new(socket);
+        let console = Arc::new(Mutex::new(console));
+        let (sender, results) = mpsc::unbounded_channel();
+
+        let tasks = [
+            tokio::spawn(Self::send_loop(Arc::clone(&socket), Arc::clone(&console), refresh)),
+            tokio::spawn(Self::receive_loop(Arc::clone(&socket), Arc::clone(&console), sender)),
+        ];
+
+        Ok(Self { socket, console, results, tasks })
+    }
+
+    /// Next processed result, `None` once the receive task has stopped
+    pub async fn recv(&mut self) -> Option<X32ProcessResult> {
+        self.results.recv().await
+    }
+
+    /// Send a request to the console, rewritten by [`X32Console::address_map`]
+    ///
+    /// # Errors
+    /// fails on socket errors
+    pub async fn send(&self, request : ConsoleRequest) -> io::Result<()> {
+        let buffers = {
+            let mut console = self.console();
+            if let ConsoleRequest::BatchSubscribe(v) | ConsoleRequest::FormatSubscribe(v) = &request {
+                console.add_subscription(v);
+            }
+            console.request(request)
+        };
+        for item in buffers {
+            self.socket.send(item.as_slice()).await?;
+        }
+        Ok(())
+    }
+
+    /// Lock the tracked state
+    pub fn console(&self) -> MutexGuard<'_, X32Console> {
+        Self::lock(&self.console)
+    }
+
+    /// Local socket address
+    ///
+    /// # Errors
+    /// fails if the socket has no local address
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Lock a shared console, ignoring poisoning (the state is still usable)
+    fn lock(console : &Mutex<X32Console>) -> MutexGuard<'_, X32Console> {
+        console.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Keep-alive and periodic full update
+    #[expect(clippy::single_call_fn)]
+    async fn send_loop(socket : Arc<UdpSocket>, console : Arc<Mutex<X32Console>>, refresh : Duration) {
+        let mut keep_alive = KeepAlive::new().with_dialect(Self::lock(&console).dialect);
+        let mut pacing = PacedSender::new();
+        let mut next_refresh = Instant::now();
+
+        loop {
+            let now = Instant::now();
+            for item in keep_alive.due(now) {
+                match socket.send(item.as_slice()).await {
+                    Err(error) if !is_transient(&error) => return,
+                    _ => (),
+                }
+            }
+
+            if now >= next_refresh {
+                let buffers = Self::lock(&console).full_update();
+                match pacing.send_all(&socket, buffers).await {
+                    Ok(()) => next_refresh = now + refresh,
+                    // console unreachable - retry on the next tick
+                    Err(error) if is_transient(&error) => (),
+                    Err(_) => return,
+                }
+            }
+
+            tokio::time::sleep(TICK).await;
+        }
+    }
+
+    /// Feed datagrams received from the console into the state
+    #[expect(clippy::single_call_fn)]
+    async fn receive_loop(socket : Arc<UdpSocket>, console : Arc<Mutex<X32Console>>, sender : mpsc::UnboundedSender<X32ProcessResult>) {
+        let mut buf = vec![0_u8; MAX_DATAGRAM];
+
+        loop {
+            let len = match socket.recv(&mut buf).await {
+                Ok(len) => len,
+                Err(error) if is_transient(&error) => continue,
+                Err(_) => return,
+            };
+            let Some(data) = buf.get(..len) else { continue };
+            let results = Self::lock(&console).process_datagram(osc::Buffer::from(data.to_vec()));
+
+            for result in results {
+                if sender.send(result).is_err() { return }
+            }
+        }
+    }
+}
+
+impl Stream for Client {
+    type Item = X32ProcessResult;
+
+    fn poll_next(self : Pin<&mut Self>, cx : &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().results.poll_recv(cx)
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.tasks.iter().for_each(JoinHandle::abort);
+    }
+}
+
+// MARK: ResultStream
+/// A [`Stream`] of results from datagrams received on a socket, see
+/// [`X32Console::into_stream`]
+///
+/// Unlike [`Client`] nothing is sent and no task is spawned - datagrams
+/// are read and processed as the stream is polled, so it fits in a
+/// `select!` loop next to the application's own sends.  The stream ends
+/// on a socket error, other than the console being unreachable.
+///
+/// ```no_run
+/// # async fn run() -> std::io::Result<()> {
+/// use tokio::net::UdpSocket;
+/// use x32_osc_state::X32Console;
+///
+/// let socket = UdpSocket::bind("0.0.0.0:0").await?;
+/// socket.connect("192.168.1.77:10023").await?;
+///
+/// let mut results = X32Console::new().into_stream(socket);
+/// while let Some(result) = results.next().await {
+///     println!("{result:?} - {}", results.console().active_cue());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ResultStream {
+    /// receiving socket
+    socket : UdpSocket,
+    /// tracked state
+    console : X32Console,
+    /// results not yet returned (a bundle can produce several)
+    pending : VecDeque<X32ProcessResult>,
+    /// receive buffer
+    buf : Vec<u8>,
+}
+
+impl ResultStream {
+    /// Stream results from datagrams received on `socket`
+    #[must_use]
+    pub fn new(console : X32Console, socket : UdpSocket) -> Self {
+        Self { socket, console, pending : VecDeque::new(), buf : vec![0_u8; MAX_DATAGRAM] }
+    }
+
+    /// Next processed result, `None` after a socket error (other than the
+    /// console being unreachable)
+    pub async fn next(&mut self) -> Option<X32ProcessResult> {
+        std::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Tracked state
+    #[must_use]
+    pub const fn console(&self) -> &X32Console { &self.console }
+
+    /// Tracked state, mutable
+    pub const fn console_mut(&mut self) -> &mut X32Console { &mut self.console }
+
+    /// The socket, e.g. to send requests
+    #[must_use]
+    pub const fn socket(&self) -> &UdpSocket { &self.socket }
+
+    /// Stop streaming, returning the state and socket
+    #[must_use]
+    pub fn into_inner(self) -> (X32Console, UdpSocket) { (self.console, self.socket) }
+}
+
+impl Stream for ResultStream {
+    type Item = X32ProcessResult;
+
+    fn poll_next(self : Pin<&mut Self>, cx : &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(result) = this.pending.pop_front() { return Poll::Ready(Some(result)) }
+
+            let mut buf = ReadBuf::new(&mut this.buf);
+            match this.socket.poll_recv_from(cx, &mut buf) {
+                Poll::Ready(Ok(_)) => {
+                    let data = osc::Buffer::from(buf.filled().to_vec());
+                    this.pending.extend(this.console.process_datagram(data));
+                },
+                Poll::Ready(Err(error)) if is_transient(&error) => (),
+                Poll::Ready(Err(_)) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::enums::{Error, FaderIndex, FieldDiff, X32Error};
+use crate::osc::{Buffer, Message};
+use crate::X32Console;
+use super::PreampUpdate;
+
+// MARK: CopyScope
+/// Parameter groups copied by [`ConsoleCommand::copy_strip`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CopyScope {
+    /// Every parameter in [`crate::enums::Fader::fields`]
+    #[default]
+    All,
+    /// Fader level, on / off, and pan (`mix`)
+    Mix,
+    /// Scribble strip name and color (`config`)
+    Config,
+    /// Input trim (`preamp/trim`) - channels and aux inputs only
+    Trim,
+}
+
+impl CopyScope {
+    /// Does this scope include a [`crate::enums::Fader::fields`] name
+    #[must_use]
+    pub fn includes(&self, field : &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Mix => matches!(field, "level" | "on" | "pan"),
+            Self::Config => matches!(field, "label" | "color"),
+            Self::Trim => field == "trim",
+        }
+    }
+}
+
+// MARK: ConsoleCommand
+/// Set commands generated from tracked state
+///
+/// Build with [`ConsoleCommand::copy_strip`].  [`ConsoleCommand::diff`] is the
+/// dry run - what would change on the target - and [`ConsoleCommand::messages`]
+/// the set messages that apply it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConsoleCommand {
+    /// changes that would be applied
+    diff : Vec<FieldDiff>,
+    /// set messages
+    messages : Vec<Message>,
+}
+
+impl ConsoleCommand {
+    /// Copy the `scope` parameters of `from` onto `to`, using the values
+    /// tracked by `console`.  Only parameters that differ generate a message.
+    ///
+    /// # Errors
+    /// fails if either strip is not tracked
+    pub fn copy_strip(console : &X32Console, from : &FaderIndex, to : &FaderIndex, scope : CopyScope) -> Result<Self, Error> {
+        let (Some(source), Some(target)) = (console.fader(from), console.fader(to)) else {
+            return Err(Error::X32(X32Error::InvalidFader));
+        };
+
+        let address = to.get_x32_address();
+        let mix = |leaf : &str| match to {
+            FaderIndex::Dca(_) => format!("/{address}/{leaf}"),
+            _ => format!("/{address}/mix/{leaf}"),
+        };
+
+        let has_trim = matches!(to, FaderIndex::Channel(_) | FaderIndex::Aux(_));
+
+        let diff:Vec<FieldDiff> = FieldDiff::compare(&target.fields(), &source.fields())
+            .into_iter()
+            .filter(|v| scope.includes(v.field) && (v.field != "trim" || has_trim))
+            .collect();
+
+        let messages = diff.iter()
+            .filter_map(|v| match v.field {
+                "label" => Some(Message::builder(&format!("/{address}/config/name")).string(source.label()).build()),
+                "color" => Some(Message::builder(&format!("/{address}/config/color")).int(source.color().as_int()).build()),
+                "level" => Some(Message::builder(&mix("fader")).float(source.level().0).build()),
+                "on" => Some(Message::builder(&mix("on")).int(i32::from(source.is_on().0)).build()),
+                "pan" => Some(Message::builder(&mix("pan")).float(source.pan().0).build()),
+                "trim" => source.trim().map(|(v, _)| Message::builder(&format!("/{address}/preamp/trim")).float(PreampUpdate::trim_to_float(v)).build()),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self { diff, messages })
+    }
+
+    /// Changes that would be applied (`a` is the current value, `b` the new one)
+    #[must_use]
+    pub fn diff(&self) -> &[FieldDiff] { &self.diff }
+
+    /// Set messages
+    #[must_use]
+    pub fn messages(&self) -> &[Message] { &self.messages }
+
+    /// Nothing to change
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.messages.is_empty() }
+
+    /// Encoded set messages, ready to send
+    #[must_use]
+    pub fn to_buffers(&self) -> Vec<Buffer> {
+        self.messages.iter().filter_map(|v| Buffer::try_from(v.clone()).ok()).collect()
+    }
+}
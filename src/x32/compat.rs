@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+use crate::osc::{Message, Type};
+
+/// Summary of console messages that were not understood
+///
+/// Useful when connected to unexpected firmware - the prefix
+/// counts make for actionable bug reports, and [`CompatibilityReport::is_partial`]
+/// can drive a "partial support" notice
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompatibilityReport {
+    /// Messages that were understood
+    pub known : usize,
+    /// Messages that were not understood
+    pub unknown : usize,
+    /// Data that could not be decoded, or messages with bad arguments
+    pub malformed : usize,
+    /// Unknown messages, grouped by address prefix
+    pub by_prefix : BTreeMap<String, usize>,
+}
+
+impl CompatibilityReport {
+    /// Record an understood message
+    pub fn add_known(&mut self) {
+        self.known += 1;
+    }
+
+    /// Record a message that was not understood
+    pub fn add_unknown(&mut self, msg : &Message) {
+        self.unknown += 1;
+        *self.by_prefix.entry(Self::prefix(msg)).or_default() += 1;
+    }
+
+    /// Record data that could not be decoded or parsed
+    pub fn add_malformed(&mut self) {
+        self.malformed += 1;
+    }
+
+    /// Were any messages not understood
+    #[must_use]
+    pub fn is_partial(&self) -> bool { self.unknown > 0 }
+
+    /// Clear all counts
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Grouping prefix for a message - the first address part,
+    /// or `node` and the first part of the node address
+    #[must_use]
+    pub fn prefix(msg : &Message) -> String {
+        /// first address part, with leading slash
+        fn first_part(s : &str) -> String {
+            let s = s.strip_prefix('/').unwrap_or(s);
+            format!("/{}", s.split(['/', ' ']).next().unwrap_or(""))
+        }
+
+        match (msg.address.as_str(), msg.args.first()) {
+            ("node", Some(Type::String(v))) => format!("node {}", first_part(v)),
+            (address, _) => first_part(address),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::enums::{Error, FaderIndex};
+use crate::osc::{Buffer, Message, Packet, Type};
+use super::ConsoleMessage;
+
+// MARK: Dialect
+/// Console family spoken on the wire
+///
+/// The crate tracks state in X32 terms - X-Air (XR12 / XR16 / XR18)
+/// addresses are rewritten to their X32 equivalents on the way in, and
+/// back on the way out, so parsing is shared.  The X-Air has fewer
+/// strips (16 channels, 6 buses, 4 DCAs, no matrices or mono bus),
+/// calls the main bus `/lr`, does not zero pad bus numbers, and takes
+/// `/xremotenfb` as its keep-alive.
+///
+/// With the `wing` feature, [`Dialect::Wing`] is an experimental Behringer
+/// Wing mode - a different address tree, parsed by
+/// [`ConsoleMessage::try_from_wing`] and built by
+/// [`super::ConsoleRequest::encode_wing`].
+///
+/// ```
+/// use x32_osc_state::x32::Dialect;
+///
+/// assert_eq!(Dialect::XAir.incoming_address("/lr/mix/fader"), "/main/st/mix/fader");
+/// assert_eq!(Dialect::XAir.incoming_address("/bus/3/mix/on"), "/bus/03/mix/on");
+/// assert_eq!(Dialect::XAir.outgoing_address("/xremote"), "/xremotenfb");
+/// assert_eq!(Dialect::X32.outgoing_address("/main/st/mix/on"), "/main/st/mix/on");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dialect {
+    /// X32 and M32 family
+    #[default]
+    X32,
+    /// X-Air XR12, XR16 and XR18 (and the Midas MR series)
+    XAir,
+    /// Behringer Wing (experimental) - fader level, mute and name only
+    #[cfg(feature = "wing")]
+    Wing,
+}
+
+impl Dialect {
+    /// Faders the console has, in [`FaderIndex::all`] order
+    pub fn faders(self) -> Box<dyn Iterator<Item = FaderIndex>> {
+        match self {
+            #[cfg(feature = "wing")]
+            Self::Wing => Box::new(FaderIndex::all()),
+            Self::X32 => Box::new(FaderIndex::all()),
+            Self::XAir => Box::new((1..=16).map(FaderIndex::Channel)
+                .chain((1..=6).map(FaderIndex::Bus))
+                .chain((1..=4).map(FaderIndex::Dca))
+                .chain(std::iter::once(FaderIndex::Main(1)))),
+        }
+    }
+
+    /// Does the console have this fader
+    #[must_use]
+    pub fn has_fader(self, index : &FaderIndex) -> bool {
+        match self {
+            #[cfg(feature = "wing")]
+            Self::Wing => *index != FaderIndex::Unknown,
+            Self::X32 => *index != FaderIndex::Unknown,
+            Self::XAir => matches!(index,
+                FaderIndex::Channel(1..=16) | FaderIndex::Bus(1..=6) |
+                FaderIndex::Dca(1..=4) | FaderIndex::Main(1)
+            ),
+        }
+    }
+
+    /// Rewrite an address (or node path) received from the console to the X32 form
+    #[must_use]
+    pub fn incoming_address(self, address : &str) -> String {
+        let rewritten = if self == Self::XAir { xair_to_x32(address) } else { None };
+        rewritten.unwrap_or_else(|| address.to_owned())
+    }
+
+    /// Rewrite an X32 form address (or node path) for the console
+    #[must_use]
+    pub fn outgoing_address(self, address : &str) -> String {
+        let rewritten = if self == Self::XAir { x32_to_xair(address) } else { None };
+        rewritten.unwrap_or_else(|| address.to_owned())
+    }
+
+    /// Classify a received (and [`Dialect::apply_incoming`] rewritten) message
+    ///
+    /// # Errors
+    /// see [`ConsoleMessage`] - unknown messages are [`crate::enums::X32Error::UnimplementedPacket`]
+    pub fn parse(self, msg : &Message) -> Result<ConsoleMessage, Error> {
+        match self {
+            Self::X32 | Self::XAir => ConsoleMessage::try_from(msg),
+            #[cfg(feature = "wing")]
+            Self::Wing => ConsoleMessage::try_from_wing(msg),
+        }
+    }
+
+    /// Rewrite a received message in place, including the path of a
+    /// `node` reply
+    pub fn apply_incoming(self, msg : &mut Message) {
+        if self != Self::XAir { return }
+
+        msg.address = self.incoming_address(&msg.address);
+        if msg.address == "node" { self.rewrite_node_arg(msg, Self::incoming_address); }
+    }
+
+    /// Rewrite an outgoing message in place, including the path of a
+    /// `/node` request or `/` node set command
+    pub fn apply_outgoing(self, msg : &mut Message) {
+        if self != Self::XAir { return }
+
+        msg.address = self.outgoing_address(&msg.address);
+        if matches!(msg.address.as_str(), "/node" | "/") { self.rewrite_node_arg(msg, Self::outgoing_address); }
+    }
+
+    /// Rewrite the path at the start of a node string argument
+    fn rewrite_node_arg(self, msg : &mut Message, rewrite : fn(Self, &str) -> String) {
+        if let Some(Type::String(line)) = msg.args.first_mut() {
+            let (path, rest) = line.split_at(line.find(' ').unwrap_or(line.len()));
+            *line = format!("{}{rest}", rewrite(self, path));
+        }
+    }
+
+    /// Rewrite every message in an outgoing packet, including nested bundles
+    fn apply_outgoing_packet(self, packet : &mut Packet) {
+        match packet {
+            Packet::Message(msg) => self.apply_outgoing(msg),
+            Packet::Bundle(bundle) => bundle.messages.iter_mut().for_each(|v| self.apply_outgoing_packet(v)),
+        }
+    }
+
+    /// Rewrite generated command buffers (e.g. a [`super::ConsoleRequest`])
+    /// for the console.  Buffers that do not decode are passed through untouched
+    pub fn outgoing<T: IntoIterator<Item = Buffer>>(self, buffers : T) -> Vec<Buffer> {
+        buffers.into_iter()
+            .map(|buffer| {
+                if self != Self::XAir { return buffer }
+
+                let Ok(mut packet) = Packet::try_from(buffer.clone()) else { return buffer };
+                self.apply_outgoing_packet(&mut packet);
+                Buffer::try_from(packet).unwrap_or(buffer)
+            })
+            .collect()
+    }
+}
+
+/// Split a path into its leading slash (if any), bank, and the rest
+/// (with its leading slash)
+fn split_bank(path : &str) -> (&str, &str, &str) {
+    let (slash, path) = path.strip_prefix('/').map_or(("", path), |v| ("/", v));
+    let (bank, rest) = path.split_at(path.find('/').unwrap_or(path.len()));
+    (slash, bank, rest)
+}
+
+/// Split the rest of a path into its first part and the remainder
+fn split_index(rest : &str) -> (&str, &str) {
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    rest.split_at(rest.find('/').unwrap_or(rest.len()))
+}
+
+/// X-Air path to X32 path, `None` when they are the same
+#[expect(clippy::single_call_fn)]
+fn xair_to_x32(path : &str) -> Option<String> {
+    match split_bank(path) {
+        (slash, "lr", rest) => Some(format!("{slash}main/st{rest}")),
+        (slash, "bus", rest) => match split_index(rest) {
+            (index, tail) if index.len() == 1 => Some(format!("{slash}bus/0{index}{tail}")),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// X32 path to X-Air path, `None` when they are the same
+#[expect(clippy::single_call_fn)]
+fn x32_to_xair(path : &str) -> Option<String> {
+    match split_bank(path) {
+        ("/", "xremote", "") => Some(String::from("/xremotenfb")),
+        (slash, "main", rest) => match split_index(rest) {
+            ("st", tail) => Some(format!("{slash}lr{tail}")),
+            _ => None,
+        },
+        (slash, "bus", rest) => {
+            let (index, tail) = split_index(rest);
+            index.strip_prefix('0')
+                .filter(|v| v.len() == 1)
+                .map(|v| format!("{slash}bus/{v}{tail}"))
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,78 @@
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use crate::enums::Error;
+use crate::osc::{Buffer, Message};
+use super::DeviceInfo;
+
+/// Console OSC port
+const X32_PORT : u16 = 10023;
+
+// MARK: DiscoveredConsole
+/// A console that answered `/xinfo`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiscoveredConsole {
+    /// address the reply came from
+    pub addr : SocketAddr,
+    /// network name, e.g. `X32-02-4A-53`
+    pub name : String,
+    /// model, e.g. `X32`, `X32RACK`
+    pub model : String,
+    /// firmware version, e.g. `4.06`
+    pub firmware : String,
+}
+
+impl DiscoveredConsole {
+    /// Parse an `/xinfo` reply from `addr`
+    ///
+    /// # Errors
+    /// fails on short or unexpected replies
+    pub fn from_reply(addr : SocketAddr, msg : &Message) -> Result<Self, Error> {
+        let info = DeviceInfo::from_message(msg)?;
+        Ok(Self { addr, name : info.name, model : info.model, firmware : info.firmware })
+    }
+
+    /// Broadcast `/xinfo` to the local subnet and collect replies until `timeout`
+    ///
+    /// # Errors
+    /// fails if the socket can't be bound or the broadcast can't be sent
+    pub fn broadcast(timeout : Duration) -> io::Result<Vec<Self>> {
+        Self::scan(&[SocketAddr::from((Ipv4Addr::BROADCAST, X32_PORT))], timeout)
+    }
+
+    /// Send `/xinfo` to each of `targets` and collect replies until
+    /// `timeout`, one entry per replying address
+    ///
+    /// # Errors
+    /// fails if the socket can't be bound or a request can't be sent
+    pub fn scan(targets : &[SocketAddr], timeout : Duration) -> io::Result<Vec<Self>> {
+        let socket = UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))?;
+        socket.set_broadcast(true)?;
+
+        let request = Buffer::try_from(Message::new("/xinfo")).unwrap_or_default();
+        for target in targets {
+            socket.send_to(request.as_slice(), target)?;
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut found:Vec<Self> = vec![];
+        let mut buf = [0_u8; 512];
+
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()).filter(|v| !v.is_zero()) {
+            socket.set_read_timeout(Some(remaining))?;
+
+            let Ok((len, addr)) = socket.recv_from(&mut buf) else { break };
+            let Some(data) = buf.get(..len) else { continue };
+            let Ok(msg) = Message::try_from(Buffer::from(data.to_vec())) else { continue };
+
+            if let Ok(console) = Self::from_reply(addr, &msg) {
+                if !found.iter().any(|v| v.addr == console.addr) { found.push(console); }
+            }
+        }
+
+        found.sort();
+        Ok(found)
+    }
+}
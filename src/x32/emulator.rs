@@ -0,0 +1,324 @@
+use std::io;
+use std::net::UdpSocket;
+use std::time::{Duration, Instant};
+
+use crate::enums::{FaderIndex, ShowMode};
+use crate::osc::{Buffer, Message, Type};
+use crate::X32Console;
+use super::{ConsoleMessage, DeviceInfo, KeepAlive, MeterBank, NodeCommand};
+
+/// Meter update period for a time factor of 1
+const METER_STEP : Duration = Duration::from_millis(50);
+
+// MARK: MeterFeed
+/// An active meter subscription
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MeterFeed {
+    /// subscribed bank
+    bank : MeterBank,
+    /// time between blobs
+    period : Duration,
+    /// next blob due
+    next : Instant,
+    /// subscription lapses
+    expires : Instant,
+}
+
+// MARK: FakeConsole
+/// A console emulator for integration tests
+///
+/// Answers requests the way a desk does - `/node` queries with node
+/// replies, `/showdata` with the cue, scene and snippet lists, `/xinfo`
+/// and `/info` with its identity, and plain address queries with the
+/// value.  Set commands update the emulated desk, and `/meters`
+/// subscriptions produce blobs from [`FakeConsole::tick`].
+///
+/// The desk state is an [`X32Console`], so seed it by processing
+/// messages, or with [`FakeConsole::with_state`].
+///
+/// ```
+/// use std::time::Instant;
+/// use x32_osc_state::{x32, X32Console};
+/// use x32_osc_state::enums::FaderIndex;
+/// use x32_osc_state::x32::emulator::FakeConsole;
+///
+/// let mut desk = FakeConsole::new();
+/// let mut app = X32Console::new();
+/// let now = Instant::now();
+///
+/// for request in x32::ConsoleRequest::SetLabel(FaderIndex::Channel(1), String::from("Kick")) {
+///     desk.handle(request, now);
+/// }
+/// for request in x32::ConsoleRequest::full_update() {
+///     app.process_all(desk.handle(request, now));
+/// }
+///
+/// assert_eq!(app.fader(&FaderIndex::Channel(1)).expect("valid fader").label(), "Kick");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FakeConsole {
+    /// emulated desk state
+    state : X32Console,
+    /// reported identity
+    info : DeviceInfo,
+    /// value sent for every meter
+    meter_level : f32,
+    /// active meter subscriptions
+    meters : Vec<MeterFeed>,
+    /// `/xremote` lease
+    remote_until : Option<Instant>,
+}
+
+impl FakeConsole {
+    /// New emulator with a blank desk
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_state(X32Console::new())
+    }
+
+    /// New emulator with a prepared desk state
+    #[must_use]
+    pub fn with_state(state : X32Console) -> Self {
+        Self {
+            state,
+            info : DeviceInfo {
+                ip : Some(String::from("127.0.0.1")),
+                name : String::from("X32-EMULATOR"),
+                model : String::from("X32"),
+                firmware : String::from("4.06"),
+            },
+            meter_level : 0.5,
+            meters : vec![],
+            remote_until : None,
+        }
+    }
+
+    /// Set the value sent for every meter (default 0.5)
+    #[must_use]
+    pub const fn with_meter_level(mut self, level : f32) -> Self {
+        self.meter_level = level;
+        self
+    }
+
+    /// Emulated desk state
+    #[must_use]
+    pub const fn state(&self) -> &X32Console { &self.state }
+
+    /// Emulated desk state, mutable - e.g. to simulate a change made on the surface
+    pub fn state_mut(&mut self) -> &mut X32Console { &mut self.state }
+
+    /// Is an `/xremote` lease active at `now`
+    #[must_use]
+    pub fn is_remote_active(&self, now : Instant) -> bool {
+        self.remote_until.is_some_and(|v| now < v)
+    }
+
+    /// Handle a request received at `now`, returning the replies
+    pub fn handle(&mut self, buffer : Buffer, now : Instant) -> Vec<Buffer> {
+        let Ok(msg) = Message::try_from(buffer) else { return vec![] };
+
+        let replies = match msg.address.as_str() {
+            "/xremote" => {
+                self.remote_until = Some(now + KeepAlive::LEASE);
+                vec![]
+            },
+            "/xinfo" | "/info" => vec![self.info_reply(&msg.address)],
+            "/node" => Self::first_string(&msg).and_then(|path| self.node_reply(&path)).into_iter().collect(),
+            "/showdata" => self.show_data(),
+            "/meters" => {
+                self.subscribe(&msg, now);
+                vec![]
+            },
+            "/renew" => {
+                let bank = Self::first_string(&msg);
+                self.meters.iter_mut()
+                    .filter(|v| Some(v.bank.address()) == bank)
+                    .for_each(|v| v.expires = now + KeepAlive::LEASE);
+                vec![]
+            },
+            "/-action/gocue" | "/-action/goscene" | "/-action/gosnippet" => {
+                let current = Message::builder("/-show/prepos/current").int(msg.first_i32_lossy(-1)).build();
+                self.state.process(current.clone());
+                vec![current]
+            },
+            _ if msg.args.is_empty() => self.query_reply(&msg.address).into_iter().collect(),
+            _ => {
+                self.state.process(msg);
+                vec![]
+            },
+        };
+
+        replies.into_iter().filter_map(|v| Buffer::try_from(v).ok()).collect()
+    }
+
+    /// Meter blobs due at `now`, dropping lapsed subscriptions
+    pub fn tick(&mut self, now : Instant) -> Vec<Buffer> {
+        self.meters.retain(|v| now < v.expires);
+
+        let level = self.meter_level;
+        self.meters.iter_mut()
+            .filter(|v| now >= v.next)
+            .filter_map(|v| {
+                v.next = now + v.period;
+                Buffer::try_from(Self::meter_blob(&v.bank, level)).ok()
+            })
+            .collect()
+    }
+
+    /// Receive one request on `socket` (honoring its read timeout), reply
+    /// to the sender, then send it any due meter blobs
+    ///
+    /// # Errors
+    /// fails on socket errors, including a read timeout
+    pub fn serve_once(&mut self, socket : &UdpSocket) -> io::Result<()> {
+        let mut buf = [0_u8; 1024];
+        let (len, from) = socket.recv_from(&mut buf)?;
+
+        let now = Instant::now();
+        let data = buf.get(..len).unwrap_or_default().to_vec();
+
+        for reply in self.handle(Buffer::from(data), now) {
+            socket.send_to(reply.as_slice(), from)?;
+        }
+        for blob in self.tick(now) {
+            socket.send_to(blob.as_slice(), from)?;
+        }
+        Ok(())
+    }
+
+    /// First argument, if it is a string
+    fn first_string(msg : &Message) -> Option<String> {
+        match msg.args.first() {
+            Some(Type::String(v)) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    /// Wrap a node line the way the console does
+    fn node_message(cmd : &NodeCommand) -> Message {
+        Message::new_with_string("node", &format!("{cmd}\n"))
+    }
+
+    /// `/xinfo` or `/info` reply
+    fn info_reply(&self, address : &str) -> Message {
+        let first = if address == "/xinfo" { self.info.ip.clone().unwrap_or_default() } else { String::from("V2.07") };
+
+        Message::builder(address)
+            .string(&first)
+            .string(&self.info.name)
+            .string(&self.info.model)
+            .string(&self.info.firmware)
+            .build()
+    }
+
+    /// Reply to a `/node` query, e.g. `ch/01/mix`
+    fn node_reply(&self, path : &str) -> Option<Message> {
+        let address = format!("/{}", path.trim_start_matches('/'));
+
+        let cmd = match ConsoleMessage::split_address(&address) {
+            ("-prefs", "show_control", "", "") => NodeCommand::new(&address).raw(match self.state.show_mode {
+                ShowMode::Cues => "CUES",
+                ShowMode::Scenes => "SCENES",
+                ShowMode::Snippets => "SNIPPETS",
+            }),
+            ("-show", "prepos", "current", "") => NodeCommand::new(&address)
+                .int(self.state.current_cue.and_then(|v| i32::try_from(v).ok()).unwrap_or(-1)),
+            (bank, index, leaf, "") => {
+                let source = FaderIndex::from_parts(bank, index).ok()?;
+                let fader = self.state.fader(&source)?;
+
+                match (leaf, &source) {
+                    ("config", FaderIndex::Dca(_)) => NodeCommand::new(&address)
+                        .string(fader.label()).int(1).color(fader.color()),
+                    ("config", _) => NodeCommand::new(&address)
+                        .string(fader.label()).int(1).color(fader.color()).int(1),
+                    ("", FaderIndex::Dca(_)) => NodeCommand::new(&address)
+                        .on(fader.is_on().0).level(fader.level().0),
+                    #[expect(clippy::cast_possible_truncation)]
+                    ("mix", _) => NodeCommand::new(&address)
+                        .on(fader.is_on().0).level(fader.level().0)
+                        .on(true).raw(&format!("{:+}", ((fader.pan().0 - 0.5) * 200_f32).round() as i32))
+                        .on(false).raw("-oo"),
+                    _ => return None,
+                }
+            },
+            _ => return None,
+        };
+
+        Some(Self::node_message(&cmd))
+    }
+
+    /// Reply to a plain address query, e.g. `/ch/01/mix/fader`
+    fn query_reply(&self, address : &str) -> Option<Message> {
+        let (bank, index, first, second) = ConsoleMessage::split_address(address);
+        let fader = self.state.fader(&FaderIndex::from_parts(bank, index).ok()?)?;
+
+        let leaf = if bank == "dca" { (first, second) } else { (second, "") };
+        let reply = Message::builder(address);
+
+        Some(match (first, leaf) {
+            (_, ("fader", "")) => reply.float(fader.level().0),
+            (_, ("on", "")) => reply.int(i32::from(fader.is_on().0)),
+            ("config", ("name", "")) => reply.string(fader.label()),
+            ("config", ("color", "")) => reply.int(fader.color().as_int()),
+            _ => return None,
+        }.build())
+    }
+
+    /// Cue, scene, and snippet lists, as `/showdata` replies
+    fn show_data(&self) -> Vec<Message> {
+        let cues = self.state.cues.iter().map(|(i, cue)| {
+            NodeCommand::new(&format!("/-show/showfile/cue/{i:03}"))
+                .raw(&cue.cue_number.replace('.', ""))
+                .string(&cue.name)
+                .int(i32::from(!cue.skip))
+                .int(cue.scene.and_then(|v| i32::try_from(v).ok()).unwrap_or(-1))
+                .int(cue.snippet.and_then(|v| i32::try_from(v).ok()).unwrap_or(-1))
+                .raw("0 1 0 0")
+        });
+
+        let scenes = self.state.scenes.iter().map(|(i, name)| {
+            NodeCommand::new(&format!("/-show/showfile/scene/{i:03}"))
+                .string(name).string("").bitmask(0b1_1111_1110, 9).int(1)
+        });
+
+        let snippets = self.state.snippets.iter().map(|(i, name)| {
+            NodeCommand::new(&format!("/-show/showfile/snippet/{i:03}"))
+                .string(name).raw("1 1 0 32768 1")
+        });
+
+        cues.chain(scenes).chain(snippets).map(|v| Self::node_message(&v)).collect()
+    }
+
+    /// Start a meter subscription - `/meters ,si <bank> <time factor>`
+    fn subscribe(&mut self, msg : &Message, now : Instant) {
+        let Some(address) = Self::first_string(msg) else { return };
+        let Some(id) = address.strip_prefix("/meters/").and_then(|v| v.parse::<usize>().ok()) else { return };
+
+        let factor = match msg.args.get(1) {
+            Some(Type::Integer(v)) => u32::try_from(*v).unwrap_or(1).max(1),
+            _ => 1,
+        };
+
+        let bank = MeterBank::from_id(id);
+        self.meters.retain(|v| v.bank != bank);
+        self.meters.push(MeterFeed { bank, period : METER_STEP * factor, next : now, expires : now + KeepAlive::LEASE });
+    }
+
+    /// Meter blob for a bank - a little endian count, then the values
+    #[expect(clippy::single_call_fn)]
+    fn meter_blob(bank : &MeterBank, level : f32) -> Message {
+        let count = bank.value_count().unwrap_or(16);
+        let mut blob = u32::try_from(count).unwrap_or_default().to_le_bytes().to_vec();
+
+        for _ in 0..count {
+            blob.extend(level.to_le_bytes());
+        }
+
+        Message::builder(&bank.address()).blob(blob).build()
+    }
+}
+
+impl Default for FakeConsole {
+    fn default() -> Self { Self::new() }
+}
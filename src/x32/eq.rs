@@ -0,0 +1,174 @@
+use crate::enums::{Error, FaderIndex, X32Error};
+
+// MARK: EqType
+/// EQ band filter type
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EqType {
+    /// Low cut
+    LowCut,
+    /// Low shelf
+    LowShelf,
+    /// Parametric
+    #[default]
+    Parametric,
+    /// Vintage parametric
+    Vintage,
+    /// High shelf
+    HighShelf,
+    /// High cut
+    HighCut,
+}
+
+impl EqType {
+    /// Get type from index
+    #[must_use]
+    pub fn from_int(v : i32) -> Self {
+        match v {
+            0 => Self::LowCut,
+            1 => Self::LowShelf,
+            3 => Self::Vintage,
+            4 => Self::HighShelf,
+            5 => Self::HighCut,
+            _ => Self::Parametric,
+        }
+    }
+
+    /// Get type from a node string (`LCut`, `LShv`, `PEQ`, `VEQ`, `HShv`, `HCut`)
+    #[must_use]
+    pub fn from_const(v : &str) -> Self {
+        match v {
+            "LCut" => Self::LowCut,
+            "LShv" => Self::LowShelf,
+            "VEQ" => Self::Vintage,
+            "HShv" => Self::HighShelf,
+            "HCut" => Self::HighCut,
+            _ => Self::Parametric,
+        }
+    }
+}
+
+// MARK: EqUpdate
+/// EQ update processed
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+pub struct EqUpdate {
+    /// strip
+    pub source : FaderIndex,
+    /// EQ on / off
+    pub is_on : Option<bool>,
+    /// band (1-based), when updating a band
+    pub band : Option<usize>,
+    /// band filter type
+    pub kind : Option<EqType>,
+    /// band frequency, in Hz
+    pub frequency : Option<f32>,
+    /// band gain, in dB
+    pub gain : Option<f32>,
+    /// band quality
+    pub q : Option<f32>,
+}
+
+impl EqUpdate {
+    /// Update for a strip and (optional) band number from address parts
+    ///
+    /// # Errors
+    /// fails on strips without EQ, and out of range bands
+    pub fn from_parts(bank : &str, index : &str, number : Option<&str>) -> Result<Self, Error> {
+        let source = FaderIndex::from_parts(bank, index)?;
+        let count = Equalizer::band_count(&source).ok_or(Error::X32(X32Error::InvalidFader))?;
+
+        let selected = match number {
+            Some(v) => match v.parse::<usize>() {
+                Ok(v) if (1..=count).contains(&v) => Some(v),
+                _ => return Err(Error::X32(X32Error::InvalidFader)),
+            },
+            None => None,
+        };
+
+        Ok(Self { source, band : selected, ..Self::default() })
+    }
+
+    /// Frequency in Hz from a float (0.0 - 1.0, log scale 20Hz - 20kHz)
+    #[must_use]
+    pub fn frequency_from_float(v : f32) -> f32 { 20_f32 * 1000_f32.powf(v) }
+
+    /// Frequency in Hz from a node string (`124.7`, `1k97`, `10k02`)
+    #[must_use]
+    pub fn frequency_from_string(v : &str) -> Option<f32> {
+        match v.split_once('k') {
+            Some((whole, frac)) => format!("{whole}.{frac}").parse::<f32>().ok().map(|v| v * 1000_f32),
+            None => v.parse::<f32>().ok(),
+        }
+    }
+
+    /// Gain in dB from a float (0.0 - 1.0, -15dB - +15dB)
+    #[must_use]
+    pub fn gain_from_float(v : f32) -> f32 { v.mul_add(30_f32, -15_f32) }
+
+    /// Quality from a float (0.0 - 1.0, log scale 10 - 0.3)
+    #[must_use]
+    pub fn q_from_float(v : f32) -> f32 { 10_f32 * 0.03_f32.powf(v) }
+}
+
+// MARK: EqBand
+/// A single EQ band
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EqBand {
+    /// filter type
+    pub kind : EqType,
+    /// frequency, in Hz
+    pub frequency : f32,
+    /// gain, in dB
+    pub gain : f32,
+    /// quality
+    pub q : f32,
+}
+
+impl Default for EqBand {
+    fn default() -> Self {
+        Self { kind : EqType::Parametric, frequency : 1000_f32, gain : 0_f32, q : 2_f32 }
+    }
+}
+
+// MARK: Equalizer
+/// Tracked EQ for a strip - 4 bands for channels and aux ins, 6 for
+/// buses, matrices and mains
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equalizer {
+    /// EQ on / off
+    pub is_on : bool,
+    /// bands, in order
+    pub bands : Vec<EqBand>,
+}
+
+impl Equalizer {
+    /// New (flat) EQ for a strip, `None` for strips without EQ
+    #[must_use]
+    pub fn new(source : &FaderIndex) -> Option<Self> {
+        Self::band_count(source).map(|count| Self { is_on : false, bands : vec![EqBand::default(); count] })
+    }
+
+    /// Number of EQ bands on a strip, `None` for strips without EQ
+    #[must_use]
+    pub fn band_count(source : &FaderIndex) -> Option<usize> {
+        match source {
+            FaderIndex::Channel(_) | FaderIndex::Aux(_) => Some(4),
+            FaderIndex::Bus(_) | FaderIndex::Matrix(_) | FaderIndex::Main(_) => Some(6),
+            FaderIndex::Dca(_) | FaderIndex::Unknown => None,
+        }
+    }
+
+    /// Apply an update
+    pub fn update(&mut self, update : &EqUpdate) {
+        if let Some(v) = update.is_on { self.is_on = v; }
+
+        let Some(band) = update.band.and_then(|v| self.bands.get_mut(v.checked_sub(1)?)) else { return };
+
+        if let Some(v) = update.kind { band.kind = v; }
+        if let Some(v) = update.frequency { band.frequency = v; }
+        if let Some(v) = update.gain { band.gain = v; }
+        if let Some(v) = update.q { band.q = v; }
+    }
+}
@@ -1,6 +1,12 @@
-use crate::x32::updates::{CueUpdate, SnippetUpdate, SceneUpdate, FaderUpdate, FaderUpdateParse, FaderName, FaderIdx};
-use crate::enums::{Error, X32Error, ShowMode, NODE_STRING};
-use crate::osc::{Type, Buffer, Message};
+use std::borrow::Cow;
+
+use crate::x32::updates::{CueUpdate, SnippetUpdate, SceneUpdate, FaderUpdate, MeterBlob};
+use crate::enums::{Error, OSCError, X32Error, ShowMode, Fader, FaderColor, FaderIndex};
+use crate::x32::NodeTokens;
+use crate::x32::{SendUpdate, PreampUpdate, EqUpdate, EqType, SoloUpdate};
+use crate::x32::{RecorderKind, RecorderUpdate, TransportState, DeviceInfo};
+use crate::x32::{OutputPort, OutputSource, RoutingUpdate, UserControlUpdate, MonitorUpdate, SafesUpdate};
+use crate::osc::{Type, Buffer, Message, DecodeOptions};
 
 #[derive(Debug, PartialEq, PartialOrd)]
 /// Messages received from the X32 console
@@ -15,10 +21,128 @@ pub enum ConsoleMessage {
     Scene(SceneUpdate),
     /// Current cue index
     CurrentCue(i16),
+    /// A cue, scene, or snippet was fired from the console (or by another
+    /// client) - `/-action/gocue`, `/-action/goscene`, or `/-action/gosnippet`,
+    /// with the list it belongs to and its index
+    Recall(ShowMode, i16),
     /// Current control mode (Cues, Scenes or Snippets)
     ShowMode(ShowMode),
+    /// Bus (or matrix) send updates
+    Send(SendUpdate),
+    /// Head amp and preamp updates
+    Preamp(PreampUpdate),
+    /// EQ updates
+    Eq(EqUpdate),
+    /// Mute group on / off state, groups 1 - 6 (`None` is unchanged)
+    MuteGroups([Option<bool>; 6]),
+    /// Solo switches and the solo lamp
+    Solo(SoloUpdate),
+    /// Recorder transport and time counters
+    Recorder(RecorderUpdate),
+    /// Console identity (`/xinfo` or `/info` reply)
+    DeviceInfo(DeviceInfo),
+    /// Output source and routing block assignments
+    Routing(RoutingUpdate),
+    /// User assignable control bank updates
+    UserControl(UserControlUpdate),
+    /// Talkback and monitor section updates
+    Monitor(MonitorUpdate),
+    /// Scene safe bitmasks
+    Safes(SafesUpdate),
     /// Meters (see notes on [`crate::X32ProcessResult`])
-    Meters((usize, Vec<f32>))
+    Meters((usize, MeterBlob)),
+    /// `/batchsubscribe` or `/formatsubscribe` data, by alias
+    Subscription((String, MeterBlob))
+}
+
+/// Tracked state areas, for [`crate::X32Console::subsystem_age`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Subsystem {
+    /// Faders
+    Faders,
+    /// Cue, scene, and snippet lists
+    CueList,
+    /// Current cue and show mode
+    CurrentCue,
+    /// Bus and matrix sends
+    Sends,
+    /// Head amps and preamps
+    Preamps,
+    /// EQ
+    Eq,
+    /// Mute groups
+    MuteGroups,
+    /// Solo
+    Solo,
+    /// Recorders
+    Recorder,
+    /// Console identity
+    Device,
+    /// Output routing
+    Routing,
+    /// User assignable controls
+    UserControls,
+    /// Talkback and monitor
+    Monitor,
+    /// Scene safes
+    Safes,
+    /// Meters and subscriptions
+    Meters,
+}
+
+impl ConsoleMessage {
+    /// Tracked state area this message updates
+    #[must_use]
+    pub fn subsystem(&self) -> Subsystem {
+        match self {
+            Self::Fader(_) => Subsystem::Faders,
+            Self::Cue(_) | Self::Snippet(_) | Self::Scene(_) => Subsystem::CueList,
+            Self::CurrentCue(_) | Self::Recall(..) | Self::ShowMode(_) => Subsystem::CurrentCue,
+            Self::Send(_) => Subsystem::Sends,
+            Self::Preamp(_) => Subsystem::Preamps,
+            Self::Eq(_) => Subsystem::Eq,
+            Self::MuteGroups(_) => Subsystem::MuteGroups,
+            Self::Solo(_) => Subsystem::Solo,
+            Self::Recorder(_) => Subsystem::Recorder,
+            Self::DeviceInfo(_) => Subsystem::Device,
+            Self::Routing(_) => Subsystem::Routing,
+            Self::UserControl(_) => Subsystem::UserControls,
+            Self::Monitor(_) => Subsystem::Monitor,
+            Self::Safes(_) => Subsystem::Safes,
+            Self::Meters(_) | Self::Subscription(_) => Subsystem::Meters,
+        }
+    }
+}
+
+/// Data accepted by [`crate::X32Console::process`]
+pub trait ConsoleInput {
+    /// Convert into a message, decoding with the console's [`DecodeOptions`]
+    ///
+    /// # Errors
+    /// fails when the data is not a valid OSC message
+    fn into_message(self, options : &DecodeOptions) -> Result<Message, Error>;
+
+    /// Size on the wire in bytes, 0 when unknown
+    fn wire_len(&self) -> usize { 0 }
+}
+
+impl ConsoleInput for Buffer {
+    fn into_message(self, options : &DecodeOptions) -> Result<Message, Error> {
+        let result = Message::decode(self, options);
+
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result { tracing::debug!(error = %e, "osc decode failed"); }
+
+        result
+    }
+
+    fn wire_len(&self) -> usize { self.len() }
+}
+
+impl ConsoleInput for Message {
+    fn into_message(self, _options : &DecodeOptions) -> Result<Message, Error> {
+        Ok(self)
+    }
 }
 
 impl TryFrom<Buffer> for ConsoleMessage {
@@ -34,20 +158,49 @@ impl TryFrom<Message> for ConsoleMessage {
     type Error = Error;
 
     fn try_from(msg: Message) -> Result<Self, Self::Error> {
+        Self::try_from(&msg)
+    }
+}
+
+impl TryFrom<&Message> for ConsoleMessage {
+    type Error = Error;
+
+    fn try_from(msg: &Message) -> Result<Self, Self::Error> {
         match msg.address.as_str() {
-            "node" => {
-                let node_arg:String = msg.args
-                    .first()
-                    .unwrap_or_default()
-                    .clone()
-                    .try_into()?;
-                Self::try_from_node(node_arg.as_str())
+            "node" => match msg.args.first() {
+                Some(Type::String(node_arg)) => {
+                    let result = Self::try_from_node(node_arg);
+
+                    #[cfg(feature = "tracing")]
+                    tracing::trace!(node = node_arg.trim_end(), parsed = result.is_ok(), "node line");
+
+                    result
+                },
+                _ => Err(Error::OSC(OSCError::InvalidTypeConversion)),
             },
-            _ => Self::try_from_standard_osc(&msg)
+            _ => Self::try_from_standard_osc(msg)
         }
     }
 }
 
+/// Node line argument at `index`, or [`X32Error::MalformedPacket`] when
+/// the line was truncated before it
+fn node_arg<'a>(args : &'a [Cow<'_, str>], index : usize) -> Result<&'a str, Error> {
+    args.get(index).map(AsRef::as_ref).ok_or(Error::X32(X32Error::MalformedPacket))
+}
+
+/// Node line arguments, or [`X32Error::MalformedPacket`] when there are
+/// fewer than `count`
+fn node_args<'a, 'b>(args : &'b [Cow<'a, str>], count : usize) -> Result<&'b [Cow<'a, str>], Error> {
+    if args.len() < count { return Err(Error::X32(X32Error::MalformedPacket)) }
+    Ok(args)
+}
+
+/// Is an address part a send number (`01` - `16`)
+fn is_send_number(part : &str) -> bool {
+    !part.is_empty() && part.bytes().all(|v| v.is_ascii_digit())
+}
+
 impl ConsoleMessage {
     /// Split address on slashes, return as a tuple
     #[must_use]
@@ -63,156 +216,461 @@ impl ConsoleMessage {
         )
     }
 
-    /// Split an node message string argument into it's parts
+    /// Split an node message string argument into it's parts, with
+    /// escaped quotes in labels resolved
     #[must_use]
     pub fn split_node_msg(s : &str) -> (String, Vec<String>) {
-        let mut address = String::new();
-        let mut args:Vec<String> = vec![];
-
-        for (i, cap) in NODE_STRING.captures_iter(s).enumerate() {
-            if let Some(v) = cap.get(1) {
-                args.push(v.as_str().to_owned());
-            } else if let Some(v) = cap.get(0) {
-                if i == 0 {
-                    v.as_str().clone_into(&mut address);
-                } else {
-                    args.push(v.as_str().to_owned());
-                }
-            }
+        let (address, args) = Self::split_node(s);
+        (address.to_owned(), args.map(|v| NodeTokens::unescape(v).into_owned()).collect())
+    }
+
+    /// Split an node message string argument into it's address
+    /// and an argument iterator, without allocating
+    #[must_use]
+    pub fn split_node(s : &str) -> (&str, NodeTokens<'_>) {
+        let mut tokens = NodeTokens::new(s);
+        (tokens.next().unwrap_or(""), tokens)
+    }
+
+    /// List fired by an `/-action/go*` address part
+    fn recall_kind(action : &str) -> Option<ShowMode> {
+        match action {
+            "gocue" => Some(ShowMode::Cues),
+            "goscene" => Some(ShowMode::Scenes),
+            "gosnippet" => Some(ShowMode::Snippets),
+            _ => None,
         }
-        (address, args)
     }
 
     /// Match a standard OSC message from the console
-    #[expect(clippy::single_call_fn)]
+    #[expect(clippy::single_call_fn, clippy::too_many_lines)]
     fn try_from_standard_osc(msg : &Message) -> Result<Self, Error> {
         let parts = Self::split_address(&msg.address);
-        // let parts = (parts.0.as_str(), parts.1.as_str(), parts.2.as_str(), parts.3.as_str());
 
         match parts {
-            (_, _, "mix", "fader") | ("dca", _, "fader", "") => {
-                let fader_update = FaderUpdate::try_from(FaderUpdateParse::StdFader(
-                    FaderName(parts.0.to_owned()),
-                    FaderIdx(parts.1.to_owned()),
-                    msg.first_default(0_f32)
-                ))?;
-                
-                Ok(Self::Fader(fader_update))
+            (_, _, "mix", "fader") | ("dca", _, "fader", "") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                level : Some(msg.first_f32_lossy(0_f32)),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "mix", "on") | ("dca", _, "on", "") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                is_on : Some(msg.first_i32_lossy(0_i32) == 1),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "mix", send) if is_send_number(send) => {
+                let mut update = SendUpdate::from_parts(parts.0, parts.1, send)?;
+
+                match msg.address.rsplit('/').next() {
+                    Some("level") => update.level = Some(msg.first_f32_lossy(0_f32)),
+                    Some("on") => update.is_on = Some(msg.first_i32_lossy(0_i32) == 1),
+                    _ => return Err(Error::X32(X32Error::UnimplementedPacket)),
+                }
+                Ok(Self::Send(update))
             },
 
-            (_, _, "mix", "on") | ("dca", _, "on", "") => {
-                let fader_update = FaderUpdate::try_from(FaderUpdateParse::StdMute(
-                    FaderName(parts.0.to_owned()),
-                    FaderIdx(parts.1.to_owned()),
-                    msg.first_default(0_i32)
-                ))?;
+            ("headamp", _, "gain", "") => Ok(Self::Preamp(PreampUpdate {
+                gain : Some(PreampUpdate::gain_from_float(msg.first_f32_lossy(0_f32))),
+                ..PreampUpdate::from_headamp(parts.1)?
+            })),
+
+            ("headamp", _, "phantom", "") => Ok(Self::Preamp(PreampUpdate {
+                phantom : Some(msg.first_i32_lossy(0_i32) == 1),
+                ..PreampUpdate::from_headamp(parts.1)?
+            })),
+
+            (_, _, "preamp", "trim") => Ok(Self::Preamp(PreampUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                trim : Some(PreampUpdate::trim_from_float(msg.first_f32_lossy(0.5_f32))),
+                ..PreampUpdate::default()
+            })),
+
+            (_, _, "preamp", "invert") => Ok(Self::Preamp(PreampUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                invert : Some(msg.first_i32_lossy(0_i32) == 1),
+                ..PreampUpdate::default()
+            })),
+
+            (_, _, "eq", "on") => Ok(Self::Eq(EqUpdate {
+                is_on : Some(msg.first_i32_lossy(0_i32) == 1),
+                ..EqUpdate::from_parts(parts.0, parts.1, None)?
+            })),
+
+            (_, _, "eq", band) if is_send_number(band) => {
+                let mut update = EqUpdate::from_parts(parts.0, parts.1, Some(band))?;
 
-                Ok(Self::Fader(fader_update))
+                match msg.address.rsplit('/').next() {
+                    Some("type") => update.kind = Some(EqType::from_int(msg.first_i32_lossy(2_i32))),
+                    Some("f") => update.frequency = Some(EqUpdate::frequency_from_float(msg.first_f32_lossy(0.5_f32))),
+                    Some("g") => update.gain = Some(EqUpdate::gain_from_float(msg.first_f32_lossy(0.5_f32))),
+                    Some("q") => update.q = Some(EqUpdate::q_from_float(msg.first_f32_lossy(0.5_f32))),
+                    _ => return Err(Error::X32(X32Error::UnimplementedPacket)),
+                }
+                Ok(Self::Eq(update))
+            },
+
+            ("-stat", "solosw", index, "") => Ok(Self::Solo(SoloUpdate::Strip(
+                SoloUpdate::strip_from_switch(index)?,
+                msg.first_i32_lossy(0_i32) == 1,
+            ))),
+
+            ("-stat", recorder, "state", "") if matches!(recorder, "tape" | "urec") => {
+                let recorder = RecorderKind::from_part(recorder)?;
+                Ok(Self::Recorder(RecorderUpdate {
+                    recorder,
+                    state : Some(TransportState::from_int(recorder, msg.first_i32_lossy(0_i32))),
+                    ..RecorderUpdate::default()
+                }))
+            },
+
+            ("-stat", recorder, counter @ ("etime" | "rtime"), "") if matches!(recorder, "tape" | "urec") => {
+                let recorder = RecorderKind::from_part(recorder)?;
+                let time = RecorderUpdate::time_from_int(recorder, counter, msg.first_i32_lossy(0_i32));
+                Ok(Self::Recorder(RecorderUpdate {
+                    recorder,
+                    elapsed : (counter == "etime").then_some(time),
+                    remaining : (counter == "rtime").then_some(time),
+                    ..RecorderUpdate::default()
+                }))
+            },
+
+            ("outputs", bank, index, "src") => Ok(Self::Routing(RoutingUpdate::Output(
+                OutputPort::from_parts(bank, index)?,
+                OutputSource::from_int(msg.first_i32_lossy(0_i32))?,
+            ))),
+
+            ("config", "routing", group, block) if !block.is_empty() => {
+                let value = msg.first_i32_lossy(0_i32);
+                let label = match group {
+                    "OUT" => RoutingUpdate::out_block_label(value),
+                    _ => None,
+                }.unwrap_or_else(|| value.to_string());
+
+                Ok(Self::Routing(RoutingUpdate::Blocks(group.to_owned(), vec![(block.to_owned(), label)])))
             },
 
-            (_, _, "config", "name") => {
-                let fader_update = FaderUpdate::try_from(FaderUpdateParse::StdName(
-                    FaderName(parts.0.to_owned()),
-                    FaderIdx(parts.1.to_owned()),
-                    msg.first_default(String::new())
-                ))?;
+            ("config", "userctrl", bank, "color") => Ok(Self::UserControl(UserControlUpdate {
+                bank : UserControlUpdate::bank_from_part(bank)?,
+                color : Some(FaderColor::parse_int(msg.first_i32_lossy(0_i32))),
+                ..UserControlUpdate::default()
+            })),
 
-                Ok(Self::Fader(fader_update))
+            ("config", "userctrl", bank, kind @ ("enc" | "btn")) => {
+                let index = msg.address.rsplit('/').next()
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .ok_or(Error::X32(X32Error::UnimplementedPacket))?;
+                let control = vec![(index, msg.first_default(String::new()))];
+
+                Ok(Self::UserControl(UserControlUpdate {
+                    bank : UserControlUpdate::bank_from_part(bank)?,
+                    encoders : if kind == "enc" { control.clone() } else { vec![] },
+                    buttons : if kind == "btn" { control } else { vec![] },
+                    ..UserControlUpdate::default()
+                }))
             },
 
-            (_, _, "config", "color") => {
-                let fader_update = FaderUpdate::try_from(FaderUpdateParse::StdColor(
-                    FaderName(parts.0.to_owned()),
-                    FaderIdx(parts.1.to_owned()),
-                    msg.first_default(1_i32)
-                ))?;
+            ("config", "talk", "enable", "") => Ok(Self::Monitor(MonitorUpdate {
+                talk_enable : Some(msg.first_i32_lossy(0_i32) == 1),
+                ..MonitorUpdate::default()
+            })),
+
+            ("config", "talk", talk, leaf) => {
+                let mut update = MonitorUpdate { talk : Some(MonitorUpdate::talk_from_part(talk)?), ..MonitorUpdate::default() };
 
-                Ok(Self::Fader(fader_update))
+                match leaf {
+                    "level" => update.talk_level = Some(msg.first_f32_lossy(0_f32)),
+                    "latch" => update.latch = Some(msg.first_i32_lossy(0_i32) == 1),
+                    "destmap" => update.destinations = Some(MonitorUpdate::destinations_from_int(msg.first_i32_lossy(0_i32))),
+                    _ => return Err(Error::X32(X32Error::UnimplementedPacket)),
+                }
+                Ok(Self::Monitor(update))
             },
 
-            #[expect(clippy::cast_possible_truncation)]
-            ("-show", "prepos", "current", "") => 
-                Ok(Self::CurrentCue(msg.first_default(-1_i32) as i16)),
+            ("-stat", "talk", talk, "") => Ok(Self::Monitor(MonitorUpdate {
+                talk : Some(MonitorUpdate::talk_from_part(talk)?),
+                is_active : Some(msg.first_i32_lossy(0_i32) == 1),
+                ..MonitorUpdate::default()
+            })),
+
+            ("config", "solo", leaf @ ("level" | "dim" | "mono"), "") => Ok(Self::Monitor(MonitorUpdate {
+                level : (leaf == "level").then(|| msg.first_f32_lossy(0_f32)),
+                dim : (leaf == "dim").then(|| msg.first_i32_lossy(0_i32) == 1),
+                mono : (leaf == "mono").then(|| msg.first_i32_lossy(0_i32) == 1),
+                ..MonitorUpdate::default()
+            })),
+
+            ("-show", "showfile", "show", field) | ("config", "scene_safes", field, "") => {
+                let mut update = SafesUpdate::default();
+                let mask = u32::try_from(msg.first_i32_lossy(0_i32)).unwrap_or_default();
+
+                if !update.set(field, mask) { return Err(Error::X32(X32Error::UnimplementedPacket)) }
+                Ok(Self::Safes(update))
+            },
+
+            ("xinfo" | "info", "", "", "") => Ok(Self::DeviceInfo(DeviceInfo::from_message(msg)?)),
+
+            ("-stat", "solo", "", "") => Ok(Self::Solo(SoloUpdate::Active(msg.first_i32_lossy(0_i32) == 1))),
+
+            ("config", "mute", group, "") => {
+                let mut groups = [None; 6];
+                let slot = group.parse::<usize>().ok()
+                    .and_then(|v| groups.get_mut(v.checked_sub(1)?))
+                    .ok_or(Error::X32(X32Error::UnimplementedPacket))?;
+
+                *slot = Some(msg.first_i32_lossy(0_i32) == 1);
+                Ok(Self::MuteGroups(groups))
+            },
+
+            (_, _, "grp", "mute") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                mute_groups : u8::try_from(msg.first_i32_lossy(0_i32) & 0x3f).ok(),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "grp", "dca") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                dca_groups : u8::try_from(msg.first_i32_lossy(0_i32) & 0xff).ok(),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "mix", "pan") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                pan : Some(msg.first_f32_lossy(0.5_f32)),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "config", "name") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                label : Some(msg.first_default(String::new())),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "config", "color") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                color : Some(FaderColor::parse_int(msg.first_i32_lossy(1_i32))),
+                ..FaderUpdate::default()
+            })),
+
+            ("-show", "prepos", "current", "") => Ok(Self::CurrentCue(
+                i16::try_from(msg.first_default(-1_i32)).map_err(|_| Error::X32(X32Error::MalformedPacket))?
+            )),
 
             ("-prefs", "show_control", "", "") =>
                 Ok(Self::ShowMode(ShowMode::from_int(msg.first_default(-1_i32)))),
 
+            ("-action", action, "", "") => Ok(Self::Recall(
+                Self::recall_kind(action).ok_or(Error::X32(X32Error::UnimplementedPacket))?,
+                i16::try_from(msg.first_i32_lossy(-1_i32)).map_err(|_| Error::X32(X32Error::MalformedPacket))?
+            )),
+
             ("meters", _, "", "") => {
                 parts.1.parse::<usize>().map_or(Err(Error::X32(X32Error::UnimplementedPacket)), |t| {
                     if let Some(Type::Blob(v)) = msg.args.first() {
-                        let float_vec:Vec<f32> = v.chunks_exact(4)
-                            .map(|f| {
-                                f32::from_le_bytes([f[0], f[1], f[2], f[3]])
-                            }).collect();
-
-                        Ok(Self::Meters((t, float_vec)))
+                        Ok(Self::Meters((t, MeterBlob::from(v.clone()))))
                     } else {
                         Err(Error::X32(X32Error::UnimplementedPacket))
                     }
                 })
             },
 
-            _ => Err(Error::X32(X32Error::UnimplementedPacket))
+            _ => Err(Error::X32(X32Error::UnimplementedPacket)),
+        }
+    }
+
+    /// Subscription data, for a message sent to a subscription alias (see
+    /// [`crate::X32Console::add_subscription`]) - `None` unless it carries
+    /// a single blob
+    #[must_use]
+    pub fn subscription(msg : &Message) -> Option<Self> {
+        match msg.args.as_slice() {
+            [Type::Blob(v)] => Some(Self::Subscription((msg.address.clone(), MeterBlob::from(v.clone())))),
+            _ => None,
         }
     }
 
     
 
     /// Match a standard OSC message from the console
-    #[expect(clippy::single_call_fn)]
+    #[expect(clippy::single_call_fn, clippy::too_many_lines)]
     fn try_from_node(arg: &str) -> Result<Self, Error> {
-        let (address, args) = Self::split_node_msg(arg);
-
-        let arg_len = args.len();
+        let (address, args) = Self::split_node(arg);
+        let args:Vec<Cow<'_, str>> = args.map(NodeTokens::unescape).collect();
 
-        let parts = Self::split_address(&address);
-        // let parts = (parts.0.as_str(), parts.1.as_str(), parts.2.as_str(), parts.3.as_str());
+        let parts = Self::split_address(address);
 
         match parts {
-            (_, _, "mix", "") | ("dca", _, "", "") if arg_len >= 2 => {
-                let fader_update = FaderUpdate::try_from(FaderUpdateParse::NodeMix(
-                    FaderName(parts.0.to_owned()),
-                    FaderIdx(parts.1.to_owned()),
-                    args[0].clone(),
-                    args[1].clone()
-                ))?;
-                
-                Ok(Self::Fader(fader_update))
+            (_, _, "mix", "") | ("dca", _, "", "") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                is_on : Some(Fader::is_on_from_string(node_arg(&args, 0)?)),
+                level : Some(Fader::level_from_string(node_arg(&args, 1)?)),
+                level_db : Fader::db_from_string(node_arg(&args, 1)?),
+                pan : if parts.0 == "dca" { None } else { args.get(3).and_then(|v| Fader::pan_from_string(v)) },
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "mix", send) if is_send_number(send) => Ok(Self::Send(SendUpdate {
+                is_on : Some(Fader::is_on_from_string(node_arg(&args, 0)?)),
+                level : Some(Fader::level_from_string(node_arg(&args, 1)?)),
+                ..SendUpdate::from_parts(parts.0, parts.1, send)?
+            })),
+
+            ("headamp", _, "", "") => Ok(Self::Preamp(PreampUpdate {
+                gain : PreampUpdate::db_from_string(node_arg(&args, 0)?),
+                phantom : Some(Fader::is_on_from_string(node_arg(&args, 1)?)),
+                ..PreampUpdate::from_headamp(parts.1)?
+            })),
+
+            (_, _, "preamp", "") => Ok(Self::Preamp(PreampUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                trim : PreampUpdate::db_from_string(node_arg(&args, 0)?),
+                invert : Some(Fader::is_on_from_string(node_arg(&args, 1)?)),
+                ..PreampUpdate::default()
+            })),
+
+            (_, _, "eq", "") => Ok(Self::Eq(EqUpdate {
+                is_on : Some(Fader::is_on_from_string(node_arg(&args, 0)?)),
+                ..EqUpdate::from_parts(parts.0, parts.1, None)?
+            })),
+
+            (_, _, "eq", band) if is_send_number(band) => Ok(Self::Eq(EqUpdate {
+                kind : Some(EqType::from_const(node_arg(&args, 0)?)),
+                frequency : EqUpdate::frequency_from_string(node_arg(&args, 1)?),
+                gain : PreampUpdate::db_from_string(node_arg(&args, 2)?),
+                q : node_arg(&args, 3)?.parse::<f32>().ok(),
+                ..EqUpdate::from_parts(parts.0, parts.1, Some(band))?
+            })),
+
+            ("-stat", "solosw", index, "") => Ok(Self::Solo(SoloUpdate::Strip(
+                SoloUpdate::strip_from_switch(index)?,
+                Fader::is_on_from_string(node_arg(&args, 0)?),
+            ))),
+
+            ("-stat", recorder, "state", "") if matches!(recorder, "tape" | "urec") => Ok(Self::Recorder(RecorderUpdate {
+                recorder : RecorderKind::from_part(recorder)?,
+                state : Some(TransportState::from_const(node_arg(&args, 0)?)),
+                ..RecorderUpdate::default()
+            })),
+
+            ("config", "routing", group, "") => {
+                let blocks:Vec<(String, String)> = RoutingUpdate::block_names(group).into_iter()
+                    .zip(node_args(&args, 1)?.iter().map(ToString::to_string))
+                    .collect();
+
+                if blocks.is_empty() { return Err(Error::X32(X32Error::UnimplementedPacket)) }
+                Ok(Self::Routing(RoutingUpdate::Blocks(group.to_owned(), blocks)))
             },
 
-            (_, _, "config", "") if arg_len >= 1 => {
-                let fader_update = FaderUpdate::try_from(FaderUpdateParse::NodeConfig(
-                    FaderName(parts.0.to_owned()),
-                    FaderIdx(parts.1.to_owned()),
-                    args[0].clone(),
-                    args[2].clone(),
-                ))?;
+            ("config", "userctrl", bank, "") => Ok(Self::UserControl(UserControlUpdate {
+                bank : UserControlUpdate::bank_from_part(bank)?,
+                color : Some(FaderColor::parse_str(node_arg(&args, 0)?)),
+                ..UserControlUpdate::default()
+            })),
+
+            ("config", "userctrl", bank, "enc") => Ok(Self::UserControl(UserControlUpdate {
+                bank : UserControlUpdate::bank_from_part(bank)?,
+                encoders : node_args(&args, 1)?.iter().enumerate().map(|(i, v)| (i + 1, v.to_string())).collect(),
+                ..UserControlUpdate::default()
+            })),
+
+            ("config", "userctrl", bank, "btn") => Ok(Self::UserControl(UserControlUpdate {
+                bank : UserControlUpdate::bank_from_part(bank)?,
+                buttons : node_args(&args, 1)?.iter().enumerate().map(|(i, v)| (i + 5, v.to_string())).collect(),
+                ..UserControlUpdate::default()
+            })),
+
+            ("config", "talk", "", "") => Ok(Self::Monitor(MonitorUpdate {
+                talk_enable : Some(Fader::is_on_from_string(node_arg(&args, 0)?)),
+                ..MonitorUpdate::default()
+            })),
+
+            ("config", "talk", talk, "") => Ok(Self::Monitor(MonitorUpdate {
+                talk : Some(MonitorUpdate::talk_from_part(talk)?),
+                talk_level : Some(Fader::level_from_string(node_arg(&args, 0)?)),
+                latch : Some(Fader::is_on_from_string(node_arg(&args, 2)?)),
+                destinations : node_arg(&args, 3)?.strip_prefix('%').and_then(|v| u32::from_str_radix(v, 2).ok()),
+                ..MonitorUpdate::default()
+            })),
 
-                Ok(Self::Fader(fader_update))
+            ("config", "solo", "", "") => Ok(Self::Monitor(MonitorUpdate {
+                level : Some(Fader::level_from_string(node_arg(&args, 0)?)),
+                ..MonitorUpdate::default()
+            })),
+
+            ("-show", "showfile", "show", "") => {
+                let mut update = SafesUpdate::default();
+
+                for (field, value) in SafesUpdate::FIELDS.iter().zip(node_args(&args, 2)?.iter().skip(1)) {
+                    if let Some(mask) = SafesUpdate::mask_from_string(value) { update.set(field, mask); }
+                }
+                Ok(Self::Safes(update))
+            },
+
+            ("config", "scene_safes", "", "") => {
+                let mut update = SafesUpdate::default();
+
+                for (field, value) in SafesUpdate::FIELDS.iter().zip(node_args(&args, 1)?) {
+                    if let Some(mask) = SafesUpdate::mask_from_string(value) { update.set(field, mask); }
+                }
+                Ok(Self::Safes(update))
+            },
+
+            ("outputs", bank, index, "") => Ok(Self::Routing(RoutingUpdate::Output(
+                OutputPort::from_parts(bank, index)?,
+                OutputSource::from_int(node_arg(&args, 0)?.parse::<i32>().map_err(|_| Error::X32(X32Error::MalformedPacket))?)?,
+            ))),
+
+            ("-stat", "solo", "", "") => Ok(Self::Solo(SoloUpdate::Active(Fader::is_on_from_string(node_arg(&args, 0)?)))),
+
+            ("config", "mute", "", "") => {
+                let mut groups = [None; 6];
+                groups.iter_mut().zip(node_args(&args, 6)?).for_each(|(slot, v)| *slot = Some(Fader::is_on_from_string(v)));
+                Ok(Self::MuteGroups(groups))
             },
 
-            #[expect(clippy::cast_possible_truncation)]
-            ("-show", "prepos", "current", "") => Ok(Self::CurrentCue(args[0]
-                .parse::<i32>()
-                .unwrap_or(-1_i32) as i16
+            (_, _, "grp", "") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                dca_groups : Fader::groups_from_string(node_arg(&args, 0)?),
+                mute_groups : Fader::groups_from_string(node_arg(&args, 1)?).map(|v| v & 0x3f),
+                ..FaderUpdate::default()
+            })),
+
+            (_, _, "config", "") => Ok(Self::Fader(FaderUpdate {
+                source : FaderIndex::from_parts(parts.0, parts.1)?,
+                color : Some(FaderColor::parse_str(node_arg(&args, 2)?)),
+                label : Some(node_arg(&args, 0)?.to_owned()),
+                ..FaderUpdate::default()
+            })),
+
+            ("-show", "prepos", "current", "") => Ok(Self::CurrentCue(
+                i16::try_from(node_arg(&args, 0)?.parse::<i32>().unwrap_or(-1_i32))
+                    .map_err(|_| Error::X32(X32Error::MalformedPacket))?
             )),
 
             ("-prefs", "show_control", "", "") =>
-                Ok(Self::ShowMode(ShowMode::from_const(args[0].as_str()))),
+                Ok(Self::ShowMode(ShowMode::from_const(node_arg(&args, 0)?))),
+
+            ("-action", action, "", "") => Ok(Self::Recall(
+                Self::recall_kind(action).ok_or(Error::X32(X32Error::UnimplementedPacket))?,
+                i16::try_from(node_arg(&args, 0)?.parse::<i32>().map_err(|_| Error::X32(X32Error::MalformedPacket))?)
+                    .map_err(|_| Error::X32(X32Error::MalformedPacket))?
+            )),
 
             ("-show", "showfile", "cue", _) => {
-                let mut cue_number = args[0].clone();
+                let mut cue_number = node_arg(&args, 0)?.to_owned();
+                if cue_number.len() < 2 || !cue_number.is_ascii() { return Err(Error::X32(X32Error::MalformedPacket)) }
                 cue_number.insert(cue_number.len()-2, '.');
                 cue_number.insert(cue_number.len()-1, '.');
 
                 #[expect(clippy::cast_sign_loss)]
-                let scene = match args[3].parse::<i32>() {
+                let scene = match node_arg(&args, 3)?.parse::<i32>() {
                     Ok(d) if d >= 0 => Some(d as usize),
                     _ => None
                 };
 
                 #[expect(clippy::cast_sign_loss)]
-                let snippet = match args[4].parse::<i32>() {
+                let snippet = match node_arg(&args, 4)?.parse::<i32>() {
                     Ok(d) if d >= 0 => Some(d as usize),
                     _ => None,
                 };
@@ -220,18 +678,19 @@ impl ConsoleMessage {
                 Ok(Self::Cue(CueUpdate {
                     cue_number, scene, snippet,
                     index: parts.3.parse::<usize>().unwrap_or(0),
-                    name: args[1].clone(),
+                    name: node_arg(&args, 1)?.to_owned(),
+                    skip: node_arg(&args, 2)? == "0",
                 }))
             }
 
             ("-show", "showfile", "scene", _) => Ok(Self::Scene(SceneUpdate {
                 index: parts.3.parse::<usize>().unwrap_or(0),
-                name: args[0].clone(),
+                name: node_arg(&args, 0)?.to_owned(),
             })),
 
             ("-show", "showfile", "snippet", _) => Ok(Self::Snippet(SnippetUpdate {
                 index: parts.3.parse::<usize>().unwrap_or(0),
-                name: args[0].clone(),
+                name: node_arg(&args, 0)?.to_owned(),
             })),
 
             _ => Err(Error::X32(X32Error::UnimplementedPacket))
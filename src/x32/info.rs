@@ -0,0 +1,158 @@
+use crate::enums::{Error, X32Error};
+use crate::osc::Message;
+use super::{Dialect, MeterBank};
+
+// MARK: ConsoleModel
+/// Console model, from the model string of a [`DeviceInfo`]
+///
+/// The Midas M32 family speaks the X32 protocol and is tracked the same
+/// way - [`ConsoleModel::is_midas`] tells them apart
+///
+/// ```
+/// use x32_osc_state::x32::{ConsoleModel, Dialect};
+///
+/// assert_eq!(ConsoleModel::from_model("M32R"), ConsoleModel::M32Rack);
+/// assert_eq!(ConsoleModel::from_model("XR18").dialect(), Some(Dialect::XAir));
+/// assert!(ConsoleModel::from_model("M32C").is_midas());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConsoleModel {
+    /// X32 full size
+    X32,
+    /// X32 Compact
+    X32Compact,
+    /// X32 Producer
+    X32Producer,
+    /// X32 Rack
+    X32Rack,
+    /// X32 Core (no surface)
+    X32Core,
+    /// Midas M32 (and M32 Live)
+    M32,
+    /// Midas M32R
+    M32Rack,
+    /// Midas M32C (no surface)
+    M32Core,
+    /// X-Air XR12, XR16, XR18 and Midas MR12, MR18
+    XAir,
+    /// Not reported yet, or not recognized
+    #[default]
+    Unknown,
+}
+
+impl ConsoleModel {
+    /// Model from a reported model string, e.g. `X32RACK` or `M32R`
+    #[must_use]
+    pub fn from_model(model : &str) -> Self {
+        match model.trim().to_ascii_uppercase().as_str() {
+            "X32" => Self::X32,
+            "X32C" | "X32COMPACT" => Self::X32Compact,
+            "X32P" | "X32PRODUCER" => Self::X32Producer,
+            "X32R" | "X32RACK" => Self::X32Rack,
+            "X32CORE" => Self::X32Core,
+            "M32" | "M32L" | "M32LIVE" => Self::M32,
+            "M32R" | "M32RLIVE" => Self::M32Rack,
+            "M32C" => Self::M32Core,
+            "XR12" | "XR16" | "XR18" | "X18" | "MR12" | "MR18" => Self::XAir,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Is this a Midas console
+    #[must_use]
+    pub const fn is_midas(self) -> bool {
+        matches!(self, Self::M32 | Self::M32Rack | Self::M32Core)
+    }
+
+    /// Protocol dialect the model speaks, `None` when unknown
+    #[must_use]
+    pub const fn dialect(self) -> Option<Dialect> {
+        match self {
+            Self::Unknown => None,
+            Self::XAir => Some(Dialect::XAir),
+            _ => Some(Dialect::X32),
+        }
+    }
+
+    /// Does the model have a control surface
+    #[must_use]
+    pub const fn has_surface(self) -> bool {
+        !matches!(self, Self::X32Core | Self::M32Core)
+    }
+
+    /// Can the model send a meter bank - the X-Air banks are laid out
+    /// differently (only [`MeterBank::Other`] applies), and surface meters
+    /// need a surface.  Unknown models are assumed to send everything
+    #[must_use]
+    pub const fn has_meter_bank(self, bank : MeterBank) -> bool {
+        match (self, bank) {
+            (Self::Unknown, _) | (Self::XAir, MeterBank::Other(_)) => true,
+            (Self::XAir, _) => false,
+            (_, MeterBank::Surface) => self.has_surface(),
+            _ => true,
+        }
+    }
+}
+
+// MARK: DeviceInfo
+/// Console identity, from `/xinfo` and `/info` replies
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceInfo {
+    /// console IP address (`/xinfo` only)
+    pub ip : Option<String>,
+    /// network name, e.g. `X32-02-4A-53`
+    pub name : String,
+    /// model, e.g. `X32`, `X32RACK`
+    pub model : String,
+    /// firmware version, e.g. `4.06`
+    pub firmware : String,
+}
+
+impl DeviceInfo {
+    /// Parse an `/xinfo` (`,ssss ip name model firmware`) or
+    /// `/info` (`,ssss server name model firmware`) reply
+    ///
+    /// # Errors
+    /// fails on short replies
+    pub fn from_message(msg : &Message) -> Result<Self, Error> {
+        let args:Vec<String> = msg.args.iter().filter_map(|v| v.clone().try_into().ok()).collect();
+
+        let [first, name, model, firmware, ..] = args.as_slice() else {
+            return Err(Error::X32(X32Error::MalformedPacket));
+        };
+
+        Ok(Self {
+            ip : (msg.address == "/xinfo").then(|| first.clone()),
+            name : name.clone(),
+            model : model.clone(),
+            firmware : firmware.clone(),
+        })
+    }
+
+    /// Firmware version as (major, minor), `None` if it does not parse
+    #[must_use]
+    pub fn firmware_version(&self) -> Option<(u32, u32)> {
+        let (major, minor) = self.firmware.trim_start_matches(['V', 'v']).split_once('.')?;
+        Some((major.parse().ok()?, minor.parse().ok()?))
+    }
+
+    /// Is the firmware at least `major.minor`
+    #[must_use]
+    pub fn firmware_at_least(&self, major : u32, minor : u32) -> bool {
+        self.firmware_version().is_some_and(|v| v >= (major, minor))
+    }
+
+    /// Console model, from [`DeviceInfo::model`]
+    #[must_use]
+    pub fn console_model(&self) -> ConsoleModel {
+        ConsoleModel::from_model(&self.model)
+    }
+
+    /// Merge a newer reply, keeping the IP address if the reply lacks one
+    pub fn update(&mut self, info : Self) {
+        let ip = info.ip.or_else(|| self.ip.take());
+        *self = Self { ip, ..info };
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::{Duration, Instant};
+
+use crate::osc::Buffer;
+use super::{ConsoleRequest, Dialect};
+
+// MARK: KeepAlive
+/// `/xremote` and subscription renewal cadence
+///
+/// The console only pushes changes to clients that sent `/xremote` in the
+/// last 10 seconds, and meter and batch subscriptions lapse on the same
+/// timer.  Poll [`KeepAlive::due`] with the current time from the send
+/// loop, and send whatever it returns.
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use x32_osc_state::x32::{ConsoleRequest, KeepAlive, MeterBank};
+///
+/// let mut keep = KeepAlive::new();
+/// keep.renew(ConsoleRequest::RenewMeters(MeterBank::Overview));
+///
+/// let start = Instant::now();
+/// assert_eq!(keep.due(start).len(), 2);
+/// assert!(keep.due(start + Duration::from_secs(1)).is_empty());
+/// assert_eq!(keep.due(start + keep.interval()).len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeepAlive {
+    /// time between sends
+    interval : Duration,
+    /// buffers sent with each `/xremote`
+    renewals : Vec<Buffer>,
+    /// last send
+    last_sent : Option<Instant>,
+    /// console family, for the keep-alive address
+    dialect : Dialect,
+}
+
+impl KeepAlive {
+    /// How long the console honors an `/xremote` or subscription
+    pub const LEASE : Duration = Duration::from_secs(10);
+    /// Longest allowed interval, leaving a second of slack on the lease
+    const MAX_INTERVAL : Duration = Duration::from_secs(9);
+    /// Shortest allowed interval, so a zero interval can't flood the console
+    const MIN_INTERVAL : Duration = Duration::from_millis(500);
+
+    /// New keep-alive, sending every 5 seconds
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { interval : Duration::from_secs(5), renewals : vec![], last_sent : None, dialect : Dialect::X32 }
+    }
+
+    /// Send the keep-alive for `dialect` (`/xremotenfb` for the X-Air)
+    #[must_use]
+    pub const fn with_dialect(mut self, dialect : Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Send every `interval` instead, clamped between half a second and
+    /// just under [`KeepAlive::LEASE`]
+    #[must_use]
+    pub fn with_interval(mut self, interval : Duration) -> Self {
+        self.interval = interval.clamp(Self::MIN_INTERVAL, Self::MAX_INTERVAL);
+        self
+    }
+
+    /// Time between sends
+    #[must_use]
+    pub const fn interval(&self) -> Duration { self.interval }
+
+    /// Also send `request` with every `/xremote` - e.g. a
+    /// [`ConsoleRequest::RenewMeters`] or [`ConsoleRequest::Renew`]
+    pub fn renew(&mut self, request : ConsoleRequest) {
+        self.renewals.extend(request.encode_for(self.dialect));
+    }
+
+    /// Buffers due to be sent at `now` - `/xremote` and the renewals once
+    /// every interval, nothing in between
+    pub fn due(&mut self, now : Instant) -> Vec<Buffer> {
+        if self.next_due().is_some_and(|v| now < v) { return vec![] }
+
+        self.last_sent = Some(now);
+
+        let mut buffers = ConsoleRequest::KeepAlive().encode_for(self.dialect);
+        buffers.extend(self.renewals.iter().cloned());
+        buffers
+    }
+
+    /// When the next send is due, `None` if nothing has been sent yet
+    #[must_use]
+    pub fn next_due(&self) -> Option<Instant> {
+        self.last_sent.map(|v| v + self.interval)
+    }
+
+    /// When the remote lease expires, `None` if nothing has been sent yet
+    #[must_use]
+    pub fn expires_at(&self) -> Option<Instant> {
+        self.last_sent.map(|v| v + Self::LEASE)
+    }
+
+    /// Whether the remote lease has lapsed (or was never taken) at `now`
+    #[must_use]
+    pub fn is_expired(&self, now : Instant) -> bool {
+        self.expires_at().is_none_or(|v| now >= v)
+    }
+
+    /// Forget the last send, so the next [`KeepAlive::due`] sends immediately
+    pub fn reset(&mut self) {
+        self.last_sent = None;
+    }
+}
+
+impl Default for KeepAlive {
+    fn default() -> Self { Self::new() }
+}
@@ -0,0 +1,89 @@
+use std::time::{Duration, Instant};
+
+// MARK: ConnectionState
+/// Console link health
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionState {
+    /// Receiving data
+    Connected,
+    /// Nothing received for a while - the console may just be quiet
+    Degraded,
+    /// Nothing received for long enough that the tracked state is suspect
+    #[default]
+    Lost,
+}
+
+// MARK: LinkMonitor
+/// Tracks time since the last packet from the console
+///
+/// [`crate::X32Console::process`] records every packet, poll
+/// [`crate::X32Console::check_link`] from a timer to notice the console
+/// going quiet.  A quiet console sends nothing, so keep a meter
+/// subscription running (see [`super::KeepAlive`]) for a steady heartbeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkMonitor {
+    /// silence before the link is [`ConnectionState::Degraded`]
+    degraded_after : Duration,
+    /// silence before the link is [`ConnectionState::Lost`]
+    lost_after : Duration,
+    /// last packet received
+    last_received : Option<Instant>,
+    /// last reported state
+    state : ConnectionState,
+}
+
+impl LinkMonitor {
+    /// New monitor - degraded after 5 seconds of silence, lost after 15
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_thresholds(Duration::from_secs(5), Duration::from_secs(15))
+    }
+
+    /// New monitor with custom silence thresholds
+    #[must_use]
+    pub const fn with_thresholds(degraded_after : Duration, lost_after : Duration) -> Self {
+        Self { degraded_after, lost_after, last_received : None, state : ConnectionState::Lost }
+    }
+
+    /// Record a packet at `now`, returning the new state on a transition
+    pub fn received(&mut self, now : Instant) -> Option<ConnectionState> {
+        self.last_received = Some(now);
+        self.transition(ConnectionState::Connected)
+    }
+
+    /// Check for silence at `now`, returning the new state on a transition
+    pub fn poll(&mut self, now : Instant) -> Option<ConnectionState> {
+        let next = match self.silence(now) {
+            Some(v) if v < self.degraded_after => ConnectionState::Connected,
+            Some(v) if v < self.lost_after => ConnectionState::Degraded,
+            _ => ConnectionState::Lost,
+        };
+        self.transition(next)
+    }
+
+    /// Last reported state
+    #[must_use]
+    pub const fn state(&self) -> ConnectionState { self.state }
+
+    /// Time since the last packet at `now`, `None` if nothing was received
+    #[must_use]
+    pub fn silence(&self, now : Instant) -> Option<Duration> {
+        self.last_received.map(|v| now.saturating_duration_since(v))
+    }
+
+    /// Forget the link, back to [`ConnectionState::Lost`]
+    pub fn reset(&mut self) {
+        self.last_received = None;
+        self.state = ConnectionState::Lost;
+    }
+
+    /// Move to `next`, returning it if it differs
+    fn transition(&mut self, next : ConnectionState) -> Option<ConnectionState> {
+        (next != self.state).then(|| { self.state = next; next })
+    }
+}
+
+impl Default for LinkMonitor {
+    fn default() -> Self { Self::new() }
+}
@@ -0,0 +1,203 @@
+use crate::enums::{Error, PacketError};
+use super::updates::MeterBlob;
+
+// MARK: MeterBank
+/// Known `/meters/<id>` banks
+///
+/// Only banks 0, 1, 2, 3, and 5 have a typed layout.  The rest - the RTA
+/// (4), and the per-strip and effects banks (6 and up) - are
+/// [`MeterBank::Other`], and decode as [`Meters::Raw`] values in the order
+/// the console sends them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MeterBank {
+    /// `/meters/0` - meters page: channels, aux & fx returns, buses, matrices
+    Overview,
+    /// `/meters/1` - channels, with gate and dynamics gain reduction
+    Channels,
+    /// `/meters/2` - buses, matrices and mains, with dynamics gain reduction
+    Outputs,
+    /// `/meters/3` - aux sends, aux returns and fx returns
+    Aux,
+    /// `/meters/5` - console surface: channel bank, group bank, mains
+    Surface,
+    /// Any other bank, by id (decoded as [`Meters::Raw`])
+    Other(usize),
+}
+
+impl MeterBank {
+    /// Bank from the numeric id used in the address
+    #[must_use]
+    pub fn from_id(id : usize) -> Self {
+        match id {
+            0 => Self::Overview,
+            1 => Self::Channels,
+            2 => Self::Outputs,
+            3 => Self::Aux,
+            5 => Self::Surface,
+            v => Self::Other(v),
+        }
+    }
+
+    /// Numeric id used in the address
+    #[must_use]
+    pub fn id(&self) -> usize {
+        match self {
+            Self::Overview => 0,
+            Self::Channels => 1,
+            Self::Outputs => 2,
+            Self::Aux => 3,
+            Self::Surface => 5,
+            Self::Other(v) => *v,
+        }
+    }
+
+    /// Bank address, `/meters/<id>`
+    #[must_use]
+    pub fn address(&self) -> String {
+        format!("/meters/{}", self.id())
+    }
+
+    /// Number of values the bank sends (excluding the leading count), if known
+    #[must_use]
+    pub fn value_count(&self) -> Option<usize> {
+        match self {
+            Self::Overview => Some(70),
+            Self::Channels => Some(96),
+            Self::Outputs => Some(49),
+            Self::Aux => Some(22),
+            Self::Surface => Some(27),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+// MARK: Meters
+/// Typed meter values, decoded from a [`MeterBlob`]
+///
+/// Levels are linear, `0.0` - `1.0` (full scale), gain reduction values
+/// likewise.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Meters {
+    /// `/meters/0`
+    Overview {
+        /// input channels 1-32
+        channels : [f32;32],
+        /// aux returns 1-8
+        aux_returns : [f32;8],
+        /// stereo fx returns 1L-4R
+        fx_returns : [f32;8],
+        /// mix buses 1-16
+        buses : [f32;16],
+        /// matrices 1-6
+        matrices : [f32;6],
+    },
+    /// `/meters/1`
+    Channels {
+        /// input channels 1-32
+        pre : [f32;32],
+        /// gate gain reduction, channels 1-32
+        gate_gr : [f32;32],
+        /// dynamics gain reduction, channels 1-32
+        dyn_gr : [f32;32],
+    },
+    /// `/meters/2`
+    Outputs {
+        /// mix buses 1-16
+        buses : [f32;16],
+        /// matrices 1-6
+        matrices : [f32;6],
+        /// main left and right
+        main : [f32;2],
+        /// mono / center
+        mono : f32,
+        /// dynamics gain reduction, mix buses 1-16
+        bus_gr : [f32;16],
+        /// dynamics gain reduction, matrices 1-6
+        matrix_gr : [f32;6],
+        /// dynamics gain reduction, main stereo
+        main_gr : f32,
+        /// dynamics gain reduction, mono / center
+        mono_gr : f32,
+    },
+    /// `/meters/3`
+    Aux {
+        /// aux sends 1-6
+        sends : [f32;6],
+        /// aux returns 1-8
+        returns : [f32;8],
+        /// stereo fx returns 1L-4R
+        fx_returns : [f32;8],
+    },
+    /// `/meters/5`
+    Surface {
+        /// selected channel bank, 16 strips
+        channels : [f32;16],
+        /// selected group bank, 8 strips
+        groups : [f32;8],
+        /// main left and right
+        main : [f32;2],
+        /// mono / center
+        mono : f32,
+    },
+    /// Any other bank, undecoded values
+    Raw(MeterBank, Vec<f32>),
+}
+
+/// Next `N` values from a length checked iterator
+fn take<const N: usize>(values : &mut impl Iterator<Item = f32>) -> [f32;N] {
+    std::array::from_fn(|_| values.next().unwrap_or_default())
+}
+
+impl Meters {
+    /// Decode a meter blob for a bank.  The first blob value is the count
+    /// sent by the console, and is skipped
+    ///
+    /// # Errors
+    /// fails if the blob is shorter than the bank layout
+    pub fn decode(bank : MeterBank, blob : &MeterBlob) -> Result<Self, Error> {
+        let available = blob.len().saturating_sub(1);
+
+        if bank.value_count().is_some_and(|needed| available < needed) {
+            return Err(Error::Packet(PacketError::Underrun));
+        }
+
+        let values = &mut blob.iter().skip(1);
+
+        Ok(match bank {
+            MeterBank::Overview => Self::Overview {
+                channels : take(values),
+                aux_returns : take(values),
+                fx_returns : take(values),
+                buses : take(values),
+                matrices : take(values),
+            },
+            MeterBank::Channels => Self::Channels {
+                pre : take(values),
+                gate_gr : take(values),
+                dyn_gr : take(values),
+            },
+            MeterBank::Outputs => Self::Outputs {
+                buses : take(values),
+                matrices : take(values),
+                main : take(values),
+                mono : values.next().unwrap_or_default(),
+                bus_gr : take(values),
+                matrix_gr : take(values),
+                main_gr : values.next().unwrap_or_default(),
+                mono_gr : values.next().unwrap_or_default(),
+            },
+            MeterBank::Aux => Self::Aux {
+                sends : take(values),
+                returns : take(values),
+                fx_returns : take(values),
+            },
+            MeterBank::Surface => Self::Surface {
+                channels : take(values),
+                groups : take(values),
+                main : take(values),
+                mono : values.next().unwrap_or_default(),
+            },
+            MeterBank::Other(_) => Self::Raw(bank, values.collect()),
+        })
+    }
+}
@@ -4,6 +4,110 @@ mod to_console;
 mod from_console;
 /// Update packets for state
 pub mod updates;
+/// Unknown message reporting
+mod compat;
+/// Node format command builder
+mod node;
+/// Console state snapshots
+mod snapshot;
+/// Bus send tracking
+mod sends;
+/// Head amp and preamp tracking
+mod preamp;
+/// Channel and bus EQ tracking
+mod eq;
+/// Solo tracking
+mod solo;
+/// USB and card recorder tracking
+mod recorder;
+/// Console identity
+mod info;
+/// Output routing tracking
+mod routing;
+/// User assignable controls
+mod userctrl;
+/// Talkback and monitor section
+mod monitor;
+/// Scene safes
+mod safes;
+/// Change observers
+mod observe;
+/// Generation counted change tracking
+mod changes;
+/// Keep-alive cadence
+mod keepalive;
+/// Outgoing rate limit
+mod pacing;
+/// Console discovery
+mod discover;
+/// Connection health
+mod link;
+/// Async UDP client
+#[cfg(feature = "tokio")]
+mod client;
+/// OSC relay for several clients
+#[cfg(feature = "tokio")]
+mod proxy;
+/// Console emulator for integration tests
+#[cfg(feature = "test-support")]
+pub mod emulator;
+/// Packet capture and replay
+pub mod capture;
+/// Show file parsing
+mod showfile;
+/// Snippet file parsing
+mod snippet;
+/// Traffic counters
+mod stats;
+/// Set command generation
+mod command;
+/// Typed meter banks
+mod meters;
+/// Address remapping for OSC middleware
+mod remap;
+/// X32 and X-Air dialects
+mod dialect;
+/// Behringer Wing dialect
+#[cfg(feature = "wing")]
+mod wing;
+/// Warm start from a snapshot
+mod warm;
+/// Snapshot on shutdown
+#[cfg(feature = "serde")]
+mod shutdown;
 
-pub use to_console::ConsoleRequest;
-pub use from_console::ConsoleMessage;
+pub use to_console::{ConsoleRequest, FaderLevel, Subscription};
+pub use from_console::{ConsoleMessage, ConsoleInput, Subsystem};
+pub use compat::CompatibilityReport;
+pub use stats::Stats;
+pub use node::{NodeCommand, NodeTokens};
+pub use snapshot::{Snapshot, FaderState};
+pub use remap::{AddressMap, PrefixRule};
+pub use dialect::Dialect;
+pub use meters::{Meters, MeterBank};
+pub use command::{ConsoleCommand, CopyScope};
+pub use warm::WarmStart;
+pub use sends::{SendUpdate, BusSend, SendMatrix};
+pub use preamp::{PreampUpdate, Preamp};
+pub use eq::{EqUpdate, EqType, EqBand, Equalizer};
+pub use solo::{SoloUpdate, SoloState};
+pub use recorder::{RecorderKind, TransportState, RecorderUpdate, Recorder};
+pub use info::{DeviceInfo, ConsoleModel};
+pub use discover::DiscoveredConsole;
+pub use routing::{OutputPort, OutputSource, RoutingUpdate, Routing};
+pub use userctrl::{UserControlUpdate, UserAssignment, UserBank};
+pub use monitor::{MonitorUpdate, Talkback, MonitorState};
+pub use safes::{SafesUpdate, Safes};
+pub use observe::{EventFilter, Observers};
+pub use changes::{ChangeKey, Changes, ChangeLog};
+pub use keepalive::KeepAlive;
+pub use pacing::{Paced, PacedSender};
+pub use showfile::ShowFile;
+pub use snippet::SnippetFile;
+pub use link::{ConnectionState, LinkMonitor};
+#[cfg(feature = "tokio")]
+pub use client::{Client, ResultStream};
+#[cfg(feature = "tokio")]
+pub use proxy::Proxy;
+#[cfg(feature = "serde")]
+pub use shutdown::ShutdownGuard;
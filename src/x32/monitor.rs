@@ -0,0 +1,108 @@
+use crate::enums::{Error, X32Error};
+
+// MARK: MonitorUpdate
+/// Talkback and monitor section update processed
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct MonitorUpdate {
+    /// talkback channel, 0 - 1 (`A` or `B`), for talkback updates
+    pub talk : Option<usize>,
+    /// talkback enabled
+    pub talk_enable : Option<bool>,
+    /// talkback button engaged
+    pub is_active : Option<bool>,
+    /// talkback level, fader scale
+    pub talk_level : Option<f32>,
+    /// talkback button latches
+    pub latch : Option<bool>,
+    /// talkback destinations (bit 0 is bus 1)
+    pub destinations : Option<u32>,
+    /// monitor level, fader scale
+    pub level : Option<f32>,
+    /// monitor dim
+    pub dim : Option<bool>,
+    /// monitor mono
+    pub mono : Option<bool>,
+}
+
+impl MonitorUpdate {
+    /// Talkback index from an address part (`A` or `B`)
+    ///
+    /// # Errors
+    /// fails on unknown talkback channels
+    pub fn talk_from_part(v : &str) -> Result<usize, Error> {
+        match v {
+            "A" => Ok(0),
+            "B" => Ok(1),
+            _ => Err(Error::X32(X32Error::UnimplementedPacket)),
+        }
+    }
+
+    /// Destination bitmask from an integer
+    #[must_use]
+    pub fn destinations_from_int(v : i32) -> u32 {
+        u32::try_from(v).unwrap_or_default()
+    }
+}
+
+// MARK: Talkback
+/// Tracked talkback channel
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Talkback {
+    /// button engaged
+    pub is_active : bool,
+    /// level, fader scale
+    pub level : f32,
+    /// button latches
+    pub latch : bool,
+    /// destinations (bit 0 is bus 1)
+    pub destinations : u32,
+}
+
+impl Talkback {
+    /// Does this talkback feed a mix bus (1 - 16)
+    #[must_use]
+    pub fn talks_to_bus(&self, bus : usize) -> bool {
+        bus.checked_sub(1).is_some_and(|v| v < 16 && self.destinations & (1 << v) != 0)
+    }
+}
+
+// MARK: MonitorState
+/// Tracked talkback and monitor section
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonitorState {
+    /// talkback enabled
+    pub talk_enable : bool,
+    /// talkback A and B
+    pub talkback : [Talkback; 2],
+    /// monitor level, fader scale
+    pub level : f32,
+    /// monitor dim
+    pub dim : bool,
+    /// monitor mono
+    pub mono : bool,
+}
+
+impl MonitorState {
+    /// Apply an update
+    pub fn update(&mut self, update : &MonitorUpdate) {
+        if let Some(v) = update.talk_enable { self.talk_enable = v; }
+        if let Some(v) = update.level { self.level = v; }
+        if let Some(v) = update.dim { self.dim = v; }
+        if let Some(v) = update.mono { self.mono = v; }
+
+        let Some(talk) = update.talk.and_then(|v| self.talkback.get_mut(v)) else { return };
+
+        if let Some(v) = update.is_active { talk.is_active = v; }
+        if let Some(v) = update.talk_level { talk.level = v; }
+        if let Some(v) = update.latch { talk.latch = v; }
+        if let Some(v) = update.destinations { talk.destinations = v; }
+    }
+
+    /// Is either talkback engaged
+    #[must_use]
+    pub fn is_talking(&self) -> bool {
+        self.talkback.iter().any(|v| v.is_active)
+    }
+}
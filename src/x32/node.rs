@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+use std::fmt;
+use crate::enums::{Error, Fader, FaderColor};
+use crate::osc::{Buffer, Message};
+
+// MARK: NodeCommand
+/// Builder for node format set commands
+///
+/// This is the inverse of the `node` parsing in [`super::ConsoleMessage`] - the
+/// formatted line is sent as the single string argument of a `/` message.
+///
+/// ```
+/// use x32_osc_state::x32::NodeCommand;
+///
+/// let cmd = NodeCommand::new("/ch/01/config").string("Lead Vox").int(1).raw("RD");
+///
+/// assert_eq!(cmd.to_string(), "/ch/01/config \"Lead Vox\" 1 RD");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeCommand {
+    /// node address
+    address : String,
+    /// formatted arguments
+    args : Vec<String>,
+}
+
+impl NodeCommand {
+    /// Start a new command for a node address (e.g. `/ch/01/mix`)
+    #[must_use]
+    pub fn new(address : &str) -> Self {
+        Self { address : address.to_owned(), args : vec![] }
+    }
+
+    /// Parse a node line, keeping quoted arguments quoted
+    ///
+    /// ```
+    /// use x32_osc_state::x32::NodeCommand;
+    ///
+    /// let cmd = NodeCommand::parse("/ch/01/config \"Lead Vox\" 1 RD").expect("valid line");
+    ///
+    /// assert_eq!(cmd.args(), ["\"Lead Vox\"", "1", "RD"]);
+    /// ```
+    #[must_use]
+    pub fn parse(line : &str) -> Option<Self> {
+        let mut tokens = NodeTokens::new(line);
+        let mut cmd = Self::new(tokens.next()?);
+
+        for token in tokens {
+            let start = token.as_ptr().addr() - line.as_ptr().addr();
+            let is_quoted = line.get(..start).is_some_and(|v| v.ends_with('"'));
+            cmd = if is_quoted { cmd.string(&NodeTokens::unescape(token)) } else { cmd.raw(token) };
+        }
+        Some(cmd)
+    }
+
+    /// Add an argument exactly as given
+    #[must_use]
+    pub fn raw(mut self, v : &str) -> Self {
+        self.args.push(v.to_owned());
+        self
+    }
+
+    /// Add a quoted string argument, escaping `"` and `\\` (see
+    /// [`NodeTokens::unescape`])
+    ///
+    /// ```
+    /// use x32_osc_state::x32::NodeCommand;
+    ///
+    /// let cmd = NodeCommand::new("/ch/02/config").string(r#"12" Snare"#);
+    ///
+    /// assert_eq!(cmd.to_string(), r#"/ch/02/config "12\" Snare""#);
+    /// ```
+    #[must_use]
+    pub fn string(self, v : &str) -> Self {
+        let mut quoted = String::with_capacity(v.len() + 2);
+        quoted.push('"');
+        for c in v.chars() {
+            if matches!(c, '"' | '\\') { quoted.push('\\'); }
+            quoted.push(c);
+        }
+        quoted.push('"');
+        self.raw(&quoted)
+    }
+
+    /// Add an `ON` / `OFF` argument
+    #[must_use]
+    pub fn on(self, v : bool) -> Self {
+        self.raw(if v { "ON" } else { "OFF" })
+    }
+
+    /// Add a fader level argument, from a fader position (0.0 - 1.0)
+    #[must_use]
+    pub fn level(self, v : f32) -> Self {
+        let level = Fader::level_to_string(v);
+        self.raw(level.strip_suffix(" dB").unwrap_or(&level))
+    }
+
+    /// Add an integer argument
+    #[must_use]
+    pub fn int(self, v : i32) -> Self {
+        self.raw(&v.to_string())
+    }
+
+    /// Add a `%` bitmask argument, `bits` wide (lowest bit last)
+    #[must_use]
+    pub fn bitmask(self, v : u32, bits : usize) -> Self {
+        self.raw(&format!("%{v:0bits$b}"))
+    }
+
+    /// Add a scribble strip color argument
+    #[must_use]
+    pub fn color(self, v : FaderColor) -> Self {
+        self.raw(v.as_const())
+    }
+
+    /// Node address
+    #[must_use]
+    pub fn address(&self) -> &str { &self.address }
+
+    /// Formatted arguments
+    #[must_use]
+    pub fn args(&self) -> &[String] { &self.args }
+}
+
+impl fmt::Display for NodeCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)?;
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+        Ok(())
+    }
+}
+
+// MARK: NodeCommand->Message
+impl From<NodeCommand> for Message {
+    fn from(v: NodeCommand) -> Self {
+        Self::new_with_string("/", &v.to_string())
+    }
+}
+
+// MARK: NodeCommand->Buffer
+impl TryFrom<NodeCommand> for Buffer {
+    type Error = Error;
+
+    fn try_from(v: NodeCommand) -> Result<Self, Self::Error> {
+        Message::from(v).try_into()
+    }
+}
+
+// MARK: NodeTokens
+/// Non-allocating tokenizer for node format lines
+///
+/// Splits on whitespace - double quoted items are returned without
+/// their quotes, and may contain spaces, `%`, and non-ASCII text.  A `\"`
+/// inside quotes does not end the item; tokens are returned as written,
+/// use [`NodeTokens::unescape`] for the label text.  An unterminated quote
+/// runs to the end of the line.
+///
+/// ```
+/// use x32_osc_state::x32::NodeTokens;
+///
+/// let tokens:Vec<&str> = NodeTokens::new("/ch/01/config \"Lead Vox\" 1 RD").collect();
+///
+/// assert_eq!(tokens, vec!["/ch/01/config", "Lead Vox", "1", "RD"]);
+///
+/// let tokens:Vec<&str> = NodeTokens::new(r#"/ch/02/config "12\" Snare" 1"#).collect();
+///
+/// assert_eq!(tokens, vec!["/ch/02/config", r#"12\" Snare"#, "1"]);
+/// assert_eq!(NodeTokens::unescape(tokens[1]), r#"12" Snare"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NodeTokens<'a> {
+    /// remaining input
+    rest : &'a str,
+}
+
+impl<'a> NodeTokens<'a> {
+    /// Tokenize a node line
+    #[must_use]
+    pub const fn new(line : &'a str) -> Self { Self { rest : line } }
+
+    /// Resolve `\"` and `\\` escapes in a token, borrowing when there are none
+    #[must_use]
+    pub fn unescape(token : &str) -> Cow<'_, str> {
+        if !token.contains('\\') { return Cow::Borrowed(token) }
+
+        let mut output = String::with_capacity(token.len());
+        let mut chars = token.chars();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.clone().next()) {
+                ('\\', Some(next @ ('"' | '\\'))) => {
+                    output.push(next);
+                    chars.next();
+                },
+                _ => output.push(c),
+            }
+        }
+        Cow::Owned(output)
+    }
+
+    /// Byte offset of the closing quote in `quoted`, skipping escaped quotes
+    #[expect(clippy::single_call_fn)]
+    fn closing_quote(quoted : &str) -> Option<usize> {
+        let mut escaped = false;
+
+        quoted.char_indices().find_map(|(i, c)| {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => return Some(i),
+                _ => (),
+            }
+            None
+        })
+    }
+}
+
+impl<'a> Iterator for NodeTokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rest = self.rest.trim_start();
+
+        if let Some(quoted) = self.rest.strip_prefix('"') {
+            let Some(end) = Self::closing_quote(quoted) else {
+                // unterminated quote, keep the rest of the line
+                self.rest = "";
+                return Some(quoted.trim_end());
+            };
+            self.rest = &quoted[end + 1..];
+            return Some(&quoted[..end]);
+        }
+
+        if self.rest.is_empty() { return None }
+
+        let end = self.rest
+            .find(|c : char| c.is_whitespace() || c == '"')
+            .unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+
+        self.rest = rest;
+        Some(token)
+    }
+}
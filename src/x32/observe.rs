@@ -0,0 +1,108 @@
+use std::sync::mpsc;
+
+use crate::enums::{Error, FaderChange, FaderIndex};
+use crate::X32ProcessResult;
+
+// MARK: EventFilter
+/// Results an observer is interested in, see [`crate::X32Console::subscribe`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EventFilter {
+    /// Every result except [`X32ProcessResult::NoOperation`]
+    All,
+    /// Any parameter change on a strip
+    Fader(FaderIndex),
+    /// Changes to some parameters of a strip, e.g. channel 5 level
+    FaderParameter(FaderIndex, FaderChange),
+    /// Current cue, scene, or snippet changes
+    CurrentCue,
+    /// Cue, scene, or snippet list edits
+    CueList,
+    /// Solo state changes
+    Solo,
+    /// Meter and subscription data
+    Meters,
+    /// Connection health changes
+    Connection,
+    /// Messages the crate does not understand
+    Unhandled,
+}
+
+impl EventFilter {
+    /// Does a result pass this filter
+    #[must_use]
+    pub fn matches(&self, result : &X32ProcessResult) -> bool {
+        match (self, result) {
+            (_, X32ProcessResult::NoOperation) => false,
+            (Self::All, _) |
+            (Self::CurrentCue, X32ProcessResult::CurrentCue(_)) |
+            (Self::CueList, X32ProcessResult::CueListChanged { .. }) |
+            (Self::Solo, X32ProcessResult::Solo(_)) |
+            (Self::Connection, X32ProcessResult::Connection(_)) |
+            (Self::Unhandled, X32ProcessResult::Unhandled { .. }) |
+            (Self::Meters, X32ProcessResult::Meters(_) | X32ProcessResult::Subscription(_)) => true,
+            (Self::Fader(source), X32ProcessResult::Fader((fader, change))) =>
+                fader.source() == source && !change.is_empty(),
+            (Self::FaderParameter(source, wanted), X32ProcessResult::Fader((fader, change))) =>
+                fader.source() == source && change.bits() & wanted.bits() != 0,
+            _ => false,
+        }
+    }
+}
+
+// MARK: Observers
+/// Registered observers - each receives matching results on its channel
+///
+/// Observers whose receiver has been dropped are removed on the next
+/// notification.  Cloning a console clones the senders, so both copies
+/// notify the same receivers
+#[derive(Debug, Clone, Default)]
+pub struct Observers {
+    /// filters and their channels
+    observers : Vec<(EventFilter, mpsc::Sender<X32ProcessResult>)>,
+    /// error channels
+    errors : Vec<mpsc::Sender<Error>>,
+}
+
+impl Observers {
+    /// Register an observer, returning the receiving end
+    pub fn subscribe(&mut self, filter : EventFilter) -> mpsc::Receiver<X32ProcessResult> {
+        let (sender, receiver) = mpsc::channel();
+        self.observers.push((filter, sender));
+        receiver
+    }
+
+    /// Send a result to every interested observer
+    pub fn notify(&mut self, result : &X32ProcessResult) {
+        if matches!(result, X32ProcessResult::NoOperation) { return }
+
+        self.observers.retain(|(filter, sender)| {
+            !filter.matches(result) || sender.send(result.clone()).is_ok()
+        });
+    }
+
+    /// Register an error observer, returning the receiving end
+    pub fn subscribe_errors(&mut self) -> mpsc::Receiver<Error> {
+        let (sender, receiver) = mpsc::channel();
+        self.errors.push(sender);
+        receiver
+    }
+
+    /// Send an error to every error observer
+    pub fn notify_error(&mut self, error : Error) {
+        self.errors.retain(|sender| sender.send(error).is_ok());
+    }
+
+    /// Number of registered observers, including error observers
+    #[must_use]
+    pub fn len(&self) -> usize { self.observers.len() + self.errors.len() }
+
+    /// No observers registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.observers.is_empty() && self.errors.is_empty() }
+
+    /// Remove every observer
+    pub fn clear(&mut self) {
+        self.observers.clear();
+        self.errors.clear();
+    }
+}
@@ -0,0 +1,133 @@
+use std::time::{Duration, Instant};
+
+use crate::osc::Buffer;
+
+// MARK: PacedSender
+/// Outgoing rate limit - a minimum gap between packets, with a burst budget
+///
+/// The console drops packets sent faster than it can handle them, which
+/// matters for the ~150 buffers of a full update.  Up to `burst` packets
+/// go out back to back, after that one packet is allowed every `gap` - the
+/// budget refills at the same rate while nothing is sent.
+///
+/// Ask [`PacedSender::delay`] before each send, or wrap the buffers with
+/// [`PacedSender::pace`] and sleep for the paired duration before sending
+/// each one.
+///
+/// ```
+/// use std::time::Duration;
+/// use x32_osc_state::x32::{ConsoleRequest, PacedSender};
+///
+/// let paced:Vec<_> = PacedSender::new()
+///     .with_burst(2)
+///     .pace(ConsoleRequest::full_update())
+///     .map(|(_, wait)| wait)
+///     .take(4)
+///     .collect();
+///
+/// assert_eq!(paced, [Duration::ZERO, Duration::ZERO, Duration::from_millis(50), Duration::from_millis(50)]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PacedSender {
+    /// time between packets once the burst is spent
+    gap : Duration,
+    /// packets allowed back to back
+    burst : u32,
+    /// when the next packet could go out if the burst budget were empty
+    next_slot : Option<Instant>,
+}
+
+impl PacedSender {
+    /// New sender, one packet every 50ms with no burst
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { gap : Duration::from_millis(50), burst : 1, next_slot : None }
+    }
+
+    /// Wait `gap` between packets instead
+    #[must_use]
+    pub const fn with_gap(mut self, gap : Duration) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Allow `burst` packets back to back (at least 1)
+    #[must_use]
+    pub fn with_burst(mut self, burst : u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Time between packets once the burst is spent
+    #[must_use]
+    pub const fn gap(&self) -> Duration { self.gap }
+
+    /// Packets allowed back to back
+    #[must_use]
+    pub const fn burst(&self) -> u32 { self.burst }
+
+    /// How long to wait before sending a packet at `now` - the packet is
+    /// counted as sent once the wait is over
+    pub fn delay(&mut self, now : Instant) -> Duration {
+        let slack = self.gap.saturating_mul(self.burst - 1);
+        let next_slot = self.next_slot.unwrap_or(now);
+        let allowed = next_slot.checked_sub(slack).unwrap_or(now);
+        let wait = allowed.saturating_duration_since(now);
+
+        self.next_slot = Some(next_slot.max(now + wait) + self.gap);
+        wait
+    }
+
+    /// Forget past sends, restoring the full burst budget
+    pub fn reset(&mut self) {
+        self.next_slot = None;
+    }
+
+    /// Pair each buffer with the time to wait before sending it, after
+    /// the previous one - the waits assume each send is immediate
+    pub fn pace<T: IntoIterator<Item = Buffer>>(self, buffers : T) -> Paced<T::IntoIter> {
+        Paced { buffers : buffers.into_iter(), sender : self, clock : Instant::now() }
+    }
+
+    /// Send `buffers` on a connected socket, sleeping as needed
+    ///
+    /// # Errors
+    /// fails on socket errors
+    #[cfg(feature = "tokio")]
+    pub async fn send_all<T: IntoIterator<Item = Buffer>>(&mut self, socket : &tokio::net::UdpSocket, buffers : T) -> std::io::Result<()> {
+        for item in buffers {
+            tokio::time::sleep(self.delay(Instant::now())).await;
+            socket.send(item.as_slice()).await?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for PacedSender {
+    fn default() -> Self { Self::new() }
+}
+
+// MARK: Paced
+/// Buffers paired with the wait before sending each, see [`PacedSender::pace`]
+#[derive(Debug, Clone)]
+pub struct Paced<I> {
+    /// buffers to send
+    buffers : I,
+    /// rate limit
+    sender : PacedSender,
+    /// simulated send time
+    clock : Instant,
+}
+
+impl<I: Iterator<Item = Buffer>> Iterator for Paced<I> {
+    type Item = (Buffer, Duration);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buffer = self.buffers.next()?;
+        let wait = self.sender.delay(self.clock);
+        self.clock += wait;
+        Some((buffer, wait))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.buffers.size_hint() }
+}
@@ -0,0 +1,76 @@
+use crate::enums::{Error, FaderIndex, X32Error};
+
+// MARK: PreampUpdate
+/// Head amp / preamp update processed
+///
+/// Head amps are mapped to channels using the default local patch
+/// (`/headamp/000` - `/headamp/031` are channels 1 - 32)
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+pub struct PreampUpdate {
+    /// channel
+    pub source : FaderIndex,
+    /// head amp gain, in dB (-12 - +60)
+    pub gain : Option<f32>,
+    /// digital trim, in dB (-18 - +18)
+    pub trim : Option<f32>,
+    /// +48V phantom power
+    pub phantom : Option<bool>,
+    /// polarity inverted
+    pub invert : Option<bool>,
+}
+
+impl PreampUpdate {
+    /// Channel for a head amp index (`000` - `031`)
+    ///
+    /// # Errors
+    /// fails on head amps outside the local inputs
+    pub fn from_headamp(index : &str) -> Result<Self, Error> {
+        let index = index.parse::<usize>().map_err(|_| Error::X32(X32Error::InvalidFader))?;
+        if index >= 32 { return Err(Error::X32(X32Error::InvalidFader)) }
+
+        Ok(Self { source : FaderIndex::Channel(index + 1), ..Self::default() })
+    }
+
+    /// Head amp gain in dB, from a float (0.0 - 1.0)
+    #[must_use]
+    pub fn gain_from_float(v : f32) -> f32 { v.mul_add(72_f32, -12_f32) }
+
+    /// Trim in dB, from a float (0.0 - 1.0)
+    #[must_use]
+    pub fn trim_from_float(v : f32) -> f32 { v.mul_add(36_f32, -18_f32) }
+
+    /// Trim as a float (0.0 - 1.0), from dB
+    #[must_use]
+    pub fn trim_to_float(v : f32) -> f32 { ((v + 18_f32) / 36_f32).clamp(0_f32, 1_f32) }
+
+    /// dB value from a node string (`+20.0`)
+    #[must_use]
+    pub fn db_from_string(v : &str) -> Option<f32> {
+        v.parse::<f32>().ok()
+    }
+}
+
+// MARK: Preamp
+/// Tracked preamp state for a channel
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preamp {
+    /// head amp gain, in dB
+    pub gain : f32,
+    /// digital trim, in dB
+    pub trim : f32,
+    /// +48V phantom power
+    pub phantom : bool,
+    /// polarity inverted
+    pub invert : bool,
+}
+
+impl Preamp {
+    /// Apply an update
+    pub fn update(&mut self, update : &PreampUpdate) {
+        if let Some(v) = update.gain { self.gain = v; }
+        if let Some(v) = update.trim { self.trim = v; }
+        if let Some(v) = update.phantom { self.phantom = v; }
+        if let Some(v) = update.invert { self.invert = v; }
+    }
+}
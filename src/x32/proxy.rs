@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use tokio::net::UdpSocket;
+use tokio::task::JoinHandle;
+
+use crate::{osc, X32Console};
+use super::KeepAlive;
+
+/// Largest datagram the console (or a client) sends
+const MAX_DATAGRAM : usize = 8192;
+/// How often the keep-alive task wakes
+const TICK : Duration = Duration::from_secs(1);
+
+/// Downstream clients, by address, with the time they were last heard from
+type Clients = Arc<Mutex<HashMap<SocketAddr, Instant>>>;
+
+// MARK: Proxy
+/// OSC relay between one console and any number of downstream clients
+///
+/// The console only keeps a handful of `/xremote` leases, so the proxy
+/// holds the single lease itself (see [`KeepAlive`]) and:
+///
+/// - forwards every datagram from the console to each client heard from
+///   within the last [`KeepAlive::LEASE`] - replies to queries go to every
+///   client, as the console can't say who asked
+/// - forwards client datagrams to the console, except `/xremote` and
+///   `/xremotenfb`, which only renew the client
+/// - feeds console datagrams into an [`X32Console`], see [`Proxy::console`]
+///
+/// Clients talk to [`Proxy::local_addr`] exactly as they would to the
+/// console.  The tasks stop when the proxy is dropped.
+///
+/// ```no_run
+/// # async fn run() -> std::io::Result<()> {
+/// use x32_osc_state::x32::Proxy;
+///
+/// let proxy = Proxy::bind(
+///     "0.0.0.0:10023".parse().expect("address"),
+///     "192.168.1.77:10023".parse().expect("address"),
+/// ).await?;
+/// println!("relaying for {:?}", proxy.clients());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Proxy {
+    /// socket clients connect to
+    downstream : Arc<UdpSocket>,
+    /// known clients
+    clients : Clients,
+    /// tracked state
+    console : Arc<Mutex<X32Console>>,
+    /// keep-alive, console, and client tasks
+    tasks : [JoinHandle<()>; 3],
+}
+
+impl Proxy {
+    /// Listen for clients on `listen` and relay to the console at `remote`
+    ///
+    /// # Errors
+    /// fails if either socket can't be bound
+    pub async fn bind(listen : SocketAddr, remote : SocketAddr) -> io::Result<Self> {
+        Box::pin(Self::bind_with(listen, remote, X32Console::new())).await
+    }
+
+    /// Listen for clients on `listen` and relay to the console at `remote`,
+    /// tracking state in `console` (its [`X32Console::dialect`] picks the
+    /// keep-alive)
+    ///
+    /// # Errors
+    /// fails if either socket can't be bound
+    pub async fn bind_with(listen : SocketAddr, remote : SocketAddr, console : X32Console) -> io::Result<Self> {
+        let local:SocketAddr = if remote.is_ipv4() { ([0, 0, 0, 0], 0).into() } else { ([0_u16; 8], 0).into() };
+        let upstream = UdpSocket::bind(local).await?;
+        upstream.connect(remote).await?;
+        let upstream = Arc::new(upstream);
+        let downstream = Arc::new(UdpSocket::bind(listen).await?);
+        let keep_alive = KeepAlive::new().with_dialect(console.dialect);
+        let console = Arc::new(Mutex::new(console));
+        let clients = Clients::default();
+
+        let tasks = [
+            tokio::spawn(Self::keep_alive_loop(Arc::clone(&upstream), keep_alive)),
+            tokio::spawn(Self::console_loop(Arc::clone(&upstream), Arc::clone(&downstream), Arc::clone(&clients), Arc::clone(&console))),
+            tokio::spawn(Self::client_loop(upstream, Arc::clone(&downstream), Arc::clone(&clients))),
+        ];
+
+        Ok(Self { downstream, clients, console, tasks })
+    }
+
+    /// Clients heard from within the last [`KeepAlive::LEASE`]
+    #[must_use]
+    pub fn clients(&self) -> Vec<SocketAddr> {
+        Self::live_clients(&self.clients, Instant::now())
+    }
+
+    /// Lock the tracked state
+    pub fn console(&self) -> MutexGuard<'_, X32Console> {
+        Self::lock(&self.console)
+    }
+
+    /// Address clients send to
+    ///
+    /// # Errors
+    /// fails if the socket has no local address
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.downstream.local_addr()
+    }
+
+    /// Lock a shared value, ignoring poisoning (the value is still usable)
+    fn lock<T>(value : &Mutex<T>) -> MutexGuard<'_, T> {
+        value.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Forget expired clients, returning the rest
+    fn live_clients(clients : &Mutex<HashMap<SocketAddr, Instant>>, now : Instant) -> Vec<SocketAddr> {
+        let mut clients = Self::lock(clients);
+        clients.retain(|_, seen| now.duration_since(*seen) < KeepAlive::LEASE);
+        clients.keys().copied().collect()
+    }
+
+    /// Is a client datagram a keep-alive the proxy answers for
+    #[expect(clippy::single_call_fn)]
+    fn is_keep_alive(data : &[u8]) -> bool {
+        osc::Message::try_from(osc::Buffer::from(data.to_vec()))
+            .is_ok_and(|v| matches!(v.address.as_str(), "/xremote" | "/xremotenfb"))
+    }
+
+    /// Hold the console lease
+    #[expect(clippy::single_call_fn)]
+    async fn keep_alive_loop(upstream : Arc<UdpSocket>, mut keep_alive : KeepAlive) {
+        loop {
+            for item in keep_alive.due(Instant::now()) {
+                let _ = upstream.send(item.as_slice()).await;
+            }
+            tokio::time::sleep(TICK).await;
+        }
+    }
+
+    /// Fan console datagrams out to the clients, and into the state
+    #[expect(clippy::single_call_fn)]
+    async fn console_loop(upstream : Arc<UdpSocket>, downstream : Arc<UdpSocket>, clients : Clients, console : Arc<Mutex<X32Console>>) {
+        let mut buf = vec![0_u8; MAX_DATAGRAM];
+
+        loop {
+            // an unreachable console is reported as a receive error - keep
+            // listening, it will answer the next keep-alive when it is back
+            let Ok(len) = upstream.recv(&mut buf).await else { continue };
+            let Some(data) = buf.get(..len) else { continue };
+
+            for client in Self::live_clients(&clients, Instant::now()) {
+                let _ = downstream.send_to(data, client).await;
+            }
+            let _ = Self::lock(&console).process_datagram(osc::Buffer::from(data.to_vec()));
+        }
+    }
+
+    /// Register clients and forward their requests to the console
+    #[expect(clippy::single_call_fn)]
+    async fn client_loop(upstream : Arc<UdpSocket>, downstream : Arc<UdpSocket>, clients : Clients) {
+        let mut buf = vec![0_u8; MAX_DATAGRAM];
+
+        loop {
+            let Ok((len, from)) = downstream.recv_from(&mut buf).await else { continue };
+            let Some(data) = buf.get(..len) else { continue };
+
+            Self::lock(&clients).insert(from, Instant::now());
+            if Self::is_keep_alive(data) { continue }
+            let _ = upstream.send(data).await;
+        }
+    }
+}
+
+impl Drop for Proxy {
+    fn drop(&mut self) {
+        self.tasks.iter().for_each(JoinHandle::abort);
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use crate::enums::{Error, X32Error};
+use crate::osc::Message;
+
+// MARK: RecorderKind
+/// Recorder on the console
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecorderKind {
+    /// Front panel USB stick recorder (`/-stat/tape`)
+    #[default]
+    Tape,
+    /// X-USB / X-Live expansion card recorder (`/-stat/urec`)
+    Card,
+}
+
+impl RecorderKind {
+    /// Get recorder from an address part (`tape` or `urec`)
+    ///
+    /// # Errors
+    /// fails on unknown recorders
+    pub fn from_part(v : &str) -> Result<Self, Error> {
+        match v {
+            "tape" => Ok(Self::Tape),
+            "urec" => Ok(Self::Card),
+            _ => Err(Error::X32(X32Error::UnimplementedPacket)),
+        }
+    }
+
+    /// Address part
+    #[must_use]
+    pub fn part(&self) -> &'static str {
+        match self {
+            Self::Tape => "tape",
+            Self::Card => "urec",
+        }
+    }
+}
+
+// MARK: TransportState
+/// Recorder transport state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransportState {
+    /// Stopped
+    #[default]
+    Stop,
+    /// Playback paused
+    PlayPause,
+    /// Playing
+    Play,
+    /// Recording paused
+    RecordPause,
+    /// Recording
+    Record,
+    /// Fast forward (tape only)
+    FastForward,
+    /// Rewind (tape only)
+    Rewind,
+}
+
+impl TransportState {
+    /// Get state from a recorder's state index
+    ///
+    /// The tape recorder reports `STOP PPAUSE PLAY RPAUSE RECORD FF REW`,
+    /// the card recorder `STOP PAUSE PLAY REC`
+    #[must_use]
+    pub fn from_int(kind : RecorderKind, v : i32) -> Self {
+        match (kind, v) {
+            (RecorderKind::Tape | RecorderKind::Card, 1) => Self::PlayPause,
+            (_, 2) => Self::Play,
+            (RecorderKind::Tape, 3) => Self::RecordPause,
+            (RecorderKind::Tape, 4) | (RecorderKind::Card, 3) => Self::Record,
+            (RecorderKind::Tape, 5) => Self::FastForward,
+            (RecorderKind::Tape, 6) => Self::Rewind,
+            _ => Self::Stop,
+        }
+    }
+
+    /// Get state from a node string
+    #[must_use]
+    pub fn from_const(v : &str) -> Self {
+        match v {
+            "PPAUSE" | "PAUSE" => Self::PlayPause,
+            "PLAY" => Self::Play,
+            "RPAUSE" => Self::RecordPause,
+            "RECORD" | "REC" => Self::Record,
+            "FF" => Self::FastForward,
+            "REW" => Self::Rewind,
+            _ => Self::Stop,
+        }
+    }
+
+    /// State index for a recorder (see [`TransportState::from_int`]).  The
+    /// card recorder has a single pause, and no fast forward or rewind -
+    /// `None` for those
+    #[must_use]
+    pub const fn as_int(&self, kind : RecorderKind) -> Option<i32> {
+        match (kind, self) {
+            (_, Self::Stop) => Some(0),
+            (RecorderKind::Card, Self::PlayPause | Self::RecordPause) |
+            (RecorderKind::Tape, Self::PlayPause) => Some(1),
+            (_, Self::Play) => Some(2),
+            (RecorderKind::Card, Self::Record) | (RecorderKind::Tape, Self::RecordPause) => Some(3),
+            (RecorderKind::Tape, Self::Record) => Some(4),
+            (RecorderKind::Tape, Self::FastForward) => Some(5),
+            (RecorderKind::Tape, Self::Rewind) => Some(6),
+            (RecorderKind::Card, Self::FastForward | Self::Rewind) => None,
+        }
+    }
+
+    /// Set message for a recorder, `None` if it lacks the state
+    #[must_use]
+    pub fn to_message(&self, kind : RecorderKind) -> Option<Message> {
+        self.as_int(kind).map(|v| Message::builder(&format!("/-stat/{}/state", kind.part())).int(v).build())
+    }
+}
+
+// MARK: RecorderUpdate
+/// Recorder update processed
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd)]
+pub struct RecorderUpdate {
+    /// recorder
+    pub recorder : RecorderKind,
+    /// transport state
+    pub state : Option<TransportState>,
+    /// elapsed time
+    pub elapsed : Option<Duration>,
+    /// remaining time
+    pub remaining : Option<Duration>,
+}
+
+impl RecorderUpdate {
+    /// Time counter from an integer - the card recorder reports elapsed
+    /// time in milliseconds, everything else is in seconds
+    #[must_use]
+    pub fn time_from_int(kind : RecorderKind, counter : &str, v : i32) -> Duration {
+        let v = u64::try_from(v).unwrap_or_default();
+        match (kind, counter) {
+            (RecorderKind::Card, "etime") => Duration::from_millis(v),
+            _ => Duration::from_secs(v),
+        }
+    }
+}
+
+// MARK: Recorder
+/// Tracked recorder state
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recorder {
+    /// transport state
+    pub state : TransportState,
+    /// elapsed time
+    pub elapsed : Duration,
+    /// remaining time (space on the media while recording)
+    pub remaining : Duration,
+}
+
+impl Recorder {
+    /// Apply an update
+    pub fn update(&mut self, update : &RecorderUpdate) {
+        if let Some(v) = update.state { self.state = v; }
+        if let Some(v) = update.elapsed { self.elapsed = v; }
+        if let Some(v) = update.remaining { self.remaining = v; }
+    }
+
+    /// Is the recorder recording
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.state == TransportState::Record
+    }
+}
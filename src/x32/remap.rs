@@ -0,0 +1,111 @@
+use crate::osc::{Buffer, Message, Packet};
+
+/// A single prefix rewrite - addresses starting with `wire` on the network
+/// side correspond to addresses starting with `console` on the console side
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixRule {
+    /// Prefix as seen on the wire (after any middleware)
+    pub wire : String,
+    /// Prefix as understood by the console (and this crate)
+    pub console : String,
+}
+
+/// Address remapping table, for consoles behind OSC processors that
+/// prefix or rewrite addresses
+///
+/// Rules are checked in the order they were added and the first matching
+/// prefix wins, so add more specific prefixes first.  Incoming messages
+/// are rewritten `wire` -> `console` before classification, outgoing
+/// requests `console` -> `wire`.
+///
+/// ```
+/// use x32_osc_state::x32::AddressMap;
+///
+/// let mut map = AddressMap::new();
+/// map.add_prefix("/stage/node", "node").strip_prefix("/stage");
+///
+/// assert_eq!(map.incoming_address("/stage/ch/01/mix/on"), "/ch/01/mix/on");
+/// assert_eq!(map.incoming_address("/stage/node"), "node");
+/// assert_eq!(map.outgoing_address("/xremote"), "/stage/xremote");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddressMap {
+    /// Rewrite rules, in priority order
+    rules : Vec<PrefixRule>,
+}
+
+impl AddressMap {
+    /// New, empty (pass through) map
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Add a prefix replacement rule
+    pub fn add_prefix(&mut self, wire : &str, console : &str) -> &mut Self {
+        self.rules.push(PrefixRule { wire : wire.to_owned(), console : console.to_owned() });
+        self
+    }
+
+    /// Add a rule that strips `wire` from incoming addresses (and adds it to outgoing ones)
+    pub fn strip_prefix(&mut self, wire : &str) -> &mut Self {
+        self.add_prefix(wire, "")
+    }
+
+    /// Remove every rule
+    pub fn clear(&mut self) { self.rules.clear(); }
+
+    /// Map has no rules
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.rules.is_empty() }
+
+    /// Current rules, in priority order
+    #[must_use]
+    pub fn rules(&self) -> &[PrefixRule] { &self.rules }
+
+    /// Rewrite an address received from the wire
+    #[must_use]
+    pub fn incoming_address(&self, address : &str) -> String {
+        self.rules.iter()
+            .find_map(|rule| address.strip_prefix(rule.wire.as_str()).map(|rest| format!("{}{rest}", rule.console)))
+            .unwrap_or_else(|| address.to_owned())
+    }
+
+    /// Rewrite an address about to be sent to the wire
+    #[must_use]
+    pub fn outgoing_address(&self, address : &str) -> String {
+        self.rules.iter()
+            .find_map(|rule| address.strip_prefix(rule.console.as_str()).map(|rest| format!("{}{rest}", rule.wire)))
+            .unwrap_or_else(|| address.to_owned())
+    }
+
+    /// Rewrite a received message in place
+    pub fn apply_incoming(&self, msg : &mut Message) {
+        if !self.is_empty() { msg.address = self.incoming_address(&msg.address); }
+    }
+
+    /// Rewrite an outgoing message in place
+    pub fn apply_outgoing(&self, msg : &mut Message) {
+        if !self.is_empty() { msg.address = self.outgoing_address(&msg.address); }
+    }
+
+    /// Rewrite every message in an outgoing packet, including nested bundles
+    fn apply_outgoing_packet(&self, packet : &mut Packet) {
+        match packet {
+            Packet::Message(msg) => self.apply_outgoing(msg),
+            Packet::Bundle(bundle) => bundle.messages.iter_mut().for_each(|v| self.apply_outgoing_packet(v)),
+        }
+    }
+
+    /// Rewrite generated command buffers (e.g. a [`super::ConsoleRequest`])
+    /// for the wire.  Buffers that do not decode are passed through untouched
+    pub fn outgoing<T: IntoIterator<Item = Buffer>>(&self, buffers : T) -> Vec<Buffer> {
+        buffers.into_iter()
+            .map(|buffer| {
+                if self.is_empty() { return buffer }
+
+                let Ok(mut packet) = Packet::try_from(buffer.clone()) else { return buffer };
+                self.apply_outgoing_packet(&mut packet);
+                Buffer::try_from(packet).unwrap_or(buffer)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+
+use crate::enums::{Error, X32Error};
+
+// MARK: OutputPort
+/// Console output patch points (`/outputs/<bank>/NN`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutputPort {
+    /// Main outputs, 1-16 (the XLR outs, with default routing)
+    Main(usize),
+    /// Aux outputs, 1-6
+    Aux(usize),
+    /// P16 ultranet outputs, 1-16
+    P16(usize),
+    /// AES/EBU outputs, 1-2
+    Aes(usize),
+    /// Record outputs, 1-2
+    Rec(usize),
+}
+
+impl OutputPort {
+    /// Port from address parts (e.g. `main`, `07`)
+    ///
+    /// # Errors
+    /// fails on unknown banks, and out of range indexes
+    pub fn from_parts(bank : &str, index : &str) -> Result<Self, Error> {
+        let index = index.parse::<usize>().map_err(|_| Error::X32(X32Error::InvalidFader))?;
+
+        match bank {
+            _ if index == 0 => Err(Error::X32(X32Error::InvalidFader)),
+            "main" if index <= 16 => Ok(Self::Main(index)),
+            "aux" if index <= 6 => Ok(Self::Aux(index)),
+            "p16" if index <= 16 => Ok(Self::P16(index)),
+            "aes" if index <= 2 => Ok(Self::Aes(index)),
+            "rec" if index <= 2 => Ok(Self::Rec(index)),
+            _ => Err(Error::X32(X32Error::InvalidFader)),
+        }
+    }
+}
+
+// MARK: OutputSource
+/// Signal assigned to an output (`/outputs/<bank>/NN/src`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutputSource {
+    /// Nothing assigned
+    #[default]
+    Off,
+    /// Main left
+    MainLeft,
+    /// Main right
+    MainRight,
+    /// Mono / center
+    MainMono,
+    /// Mix bus, 1-16
+    Bus(usize),
+    /// Matrix, 1-6
+    Matrix(usize),
+    /// Channel direct out, 1-32
+    Channel(usize),
+    /// Aux in direct out, 1-8
+    Aux(usize),
+    /// FX return direct out, 1-8
+    FxReturn(usize),
+    /// Monitor left
+    MonitorLeft,
+    /// Monitor right
+    MonitorRight,
+    /// Talkback
+    Talkback,
+}
+
+impl OutputSource {
+    /// Get source from the console's index (0 - 76)
+    ///
+    /// # Errors
+    /// fails on unknown sources
+    pub fn from_int(v : i32) -> Result<Self, Error> {
+        let index = usize::try_from(v).map_err(|_| Error::X32(X32Error::MalformedPacket))?;
+
+        match index {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::MainLeft),
+            2 => Ok(Self::MainRight),
+            3 => Ok(Self::MainMono),
+            4..=19 => Ok(Self::Bus(index - 3)),
+            20..=25 => Ok(Self::Matrix(index - 19)),
+            26..=57 => Ok(Self::Channel(index - 25)),
+            58..=65 => Ok(Self::Aux(index - 57)),
+            66..=73 => Ok(Self::FxReturn(index - 65)),
+            74 => Ok(Self::MonitorLeft),
+            75 => Ok(Self::MonitorRight),
+            76 => Ok(Self::Talkback),
+            _ => Err(Error::X32(X32Error::MalformedPacket)),
+        }
+    }
+}
+
+// MARK: RoutingUpdate
+/// Output routing update processed
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub enum RoutingUpdate {
+    /// Output source assignment
+    Output(OutputPort, OutputSource),
+    /// Routing block assignments (`/config/routing/<group>/<block>`) for a
+    /// group, as (block, value) - e.g. `OUT`, `[("1-4", "OUT1-4")]`
+    Blocks(String, Vec<(String, String)>),
+}
+
+impl RoutingUpdate {
+    /// Block names of a routing group, in node message order
+    #[must_use]
+    pub fn block_names(group : &str) -> Vec<String> {
+        let (size, count, aux) = match group {
+            "IN" | "PLAY" => (8, 4, true),
+            "AES50A" | "AES50B" => (8, 6, false),
+            "CARD" => (8, 4, false),
+            "OUT" => (4, 4, false),
+            _ => (0, 0, false),
+        };
+
+        let mut names:Vec<String> = (0..count).map(|v| format!("{}-{}", v * size + 1, v * size + size)).collect();
+        if aux { names.push(String::from("AUX")); }
+        names
+    }
+
+    /// Label for an `OUT` routing block index - blocks of 4 from the local
+    /// inputs, AES50 A and B, the card, the outputs, and P16
+    #[must_use]
+    pub fn out_block_label(v : i32) -> Option<String> {
+        let groups = [("AN", 8), ("A", 12), ("B", 12), ("CARD", 8), ("OUT", 4), ("P16", 4)];
+        let mut index = usize::try_from(v).ok()?;
+
+        for (name, count) in groups {
+            if index < count {
+                return Some(format!("{name}{}-{}", index * 4 + 1, index * 4 + 4));
+            }
+            index -= count;
+        }
+
+        match index {
+            0 => Some(String::from("AUX/CR")),
+            1 => Some(String::from("AUX/TB")),
+            _ => None,
+        }
+    }
+}
+
+// MARK: Routing
+/// Tracked output routing
+///
+/// Only assignments the console has reported are stored
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Routing {
+    /// output source assignments
+    outputs : BTreeMap<OutputPort, OutputSource>,
+    /// routing blocks, by `group/block` (e.g. `OUT/1-4`)
+    blocks : BTreeMap<String, String>,
+}
+
+impl Routing {
+    /// Apply an update
+    pub fn update(&mut self, update : RoutingUpdate) {
+        match update {
+            RoutingUpdate::Output(port, source) => { self.outputs.insert(port, source); },
+            RoutingUpdate::Blocks(group, blocks) => {
+                for (block, value) in blocks {
+                    self.blocks.insert(format!("{group}/{block}"), value);
+                }
+            },
+        }
+    }
+
+    /// Rebuild from output assignments and `group/block` routing blocks
+    /// (as returned by [`Routing::outputs`] and [`Routing::blocks`])
+    #[must_use]
+    pub fn from_parts(outputs : &[(OutputPort, OutputSource)], blocks : &[(String, String)]) -> Self {
+        Self {
+            outputs : outputs.iter().copied().collect(),
+            blocks : blocks.iter().cloned().collect(),
+        }
+    }
+
+    /// Every output assignment
+    pub fn outputs(&self) -> impl Iterator<Item = (&OutputPort, &OutputSource)> {
+        self.outputs.iter()
+    }
+
+    /// Every routing block, by `group/block`
+    pub fn blocks(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.blocks.iter()
+    }
+
+    /// Source assigned to an output
+    #[must_use]
+    pub fn output(&self, port : &OutputPort) -> Option<OutputSource> {
+        self.outputs.get(port).copied()
+    }
+
+    /// Routing block assignment, e.g. `("OUT", "1-4")`
+    #[must_use]
+    pub fn block(&self, group : &str, block : &str) -> Option<&str> {
+        self.blocks.get(&format!("{group}/{block}")).map(String::as_str)
+    }
+
+    /// The output patch point feeding a local XLR output (1 - 16), following
+    /// the `OUT` routing blocks (default routing when not tracked).  `None`
+    /// when the block carries an input or a P16 / aux block straight through
+    #[must_use]
+    pub fn xlr_output(&self, index : usize) -> Option<OutputPort> {
+        if !(1..=16).contains(&index) { return None }
+
+        let start = (index - 1) / 4 * 4 + 1;
+        let offset = (index - 1) % 4;
+        let label = self.block("OUT", &format!("{start}-{}", start + 3))
+            .map_or_else(|| format!("OUT{start}-{}", start + 3), str::to_owned);
+
+        let first = label.strip_prefix("OUT")?.split('-').next()?.parse::<usize>().ok()?;
+        Some(OutputPort::Main(first + offset))
+    }
+
+    /// Signal reaching a local XLR output (1 - 16), see [`Routing::xlr_output`]
+    #[must_use]
+    pub fn xlr_source(&self, index : usize) -> Option<OutputSource> {
+        self.xlr_output(index).and_then(|port| self.output(&port))
+    }
+
+    /// Forget all routing
+    pub fn clear(&mut self) {
+        self.outputs.clear();
+        self.blocks.clear();
+    }
+}
@@ -0,0 +1,130 @@
+use crate::enums::FaderIndex;
+
+// MARK: SafesUpdate
+/// Scene safe update processed - each field is a bitmask, set bits are
+/// protected from scene recalls
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd)]
+pub struct SafesUpdate {
+    /// input parameter groups
+    pub inputs : Option<u32>,
+    /// mix send parameter groups
+    pub mix_sends : Option<u32>,
+    /// mix bus parameter groups
+    pub mix_buses : Option<u32>,
+    /// console setup groups
+    pub console : Option<u32>,
+    /// channels 1 - 16
+    pub chan16 : Option<u32>,
+    /// channels 17 - 32
+    pub chan32 : Option<u32>,
+    /// aux ins 1 - 8, fx returns 1 - 8
+    pub returns : Option<u32>,
+    /// buses 1 - 16
+    pub buses : Option<u32>,
+    /// main, mono, matrix 1 - 6, dca 1 - 8
+    pub lr_mtx_dca : Option<u32>,
+    /// effects 1 - 8
+    pub effects : Option<u32>,
+}
+
+impl SafesUpdate {
+    /// Field names, in `/-show/showfile/show` order
+    pub const FIELDS : [&'static str; 10] = [
+        "inputs", "mxsends", "mxbuses", "console", "chan16",
+        "chan32", "return", "buses", "lrmtxdca", "effects",
+    ];
+
+    /// Bitmask from a node value (`%0101` or decimal)
+    #[must_use]
+    pub fn mask_from_string(v : &str) -> Option<u32> {
+        v.strip_prefix('%').map_or_else(|| v.parse::<u32>().ok(), |v| u32::from_str_radix(v, 2).ok())
+    }
+
+    /// Set a field by name (see [`SafesUpdate::FIELDS`]), returns false on unknown fields
+    pub fn set(&mut self, field : &str, mask : u32) -> bool {
+        let slot = match field {
+            "inputs" => &mut self.inputs,
+            "mxsends" => &mut self.mix_sends,
+            "mxbuses" => &mut self.mix_buses,
+            "console" => &mut self.console,
+            "chan16" => &mut self.chan16,
+            "chan32" => &mut self.chan32,
+            "return" => &mut self.returns,
+            "buses" => &mut self.buses,
+            "lrmtxdca" => &mut self.lr_mtx_dca,
+            "effects" => &mut self.effects,
+            _ => return false,
+        };
+        *slot = Some(mask);
+        true
+    }
+}
+
+// MARK: Safes
+/// Tracked scene safes - what a scene recall will not touch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Safes {
+    /// input parameter groups (console bit order)
+    pub inputs : u32,
+    /// mix send parameter groups (console bit order)
+    pub mix_sends : u32,
+    /// mix bus parameter groups (console bit order)
+    pub mix_buses : u32,
+    /// console setup groups (console bit order)
+    pub console : u32,
+    /// channels 1 - 32 (bit 0 is channel 1)
+    pub channels : u32,
+    /// aux ins 1 - 8 (bits 0 - 7), fx returns 1 - 8 (bits 8 - 15)
+    pub returns : u32,
+    /// buses 1 - 16
+    pub buses : u32,
+    /// main (bit 0), mono (bit 1), matrix 1 - 6 (bits 2 - 7), dca 1 - 8 (bits 8 - 15)
+    pub lr_mtx_dca : u32,
+    /// effects 1 - 8
+    pub effects : u32,
+}
+
+impl Safes {
+    /// Apply an update
+    pub fn update(&mut self, update : &SafesUpdate) {
+        if let Some(v) = update.inputs { self.inputs = v; }
+        if let Some(v) = update.mix_sends { self.mix_sends = v; }
+        if let Some(v) = update.mix_buses { self.mix_buses = v; }
+        if let Some(v) = update.console { self.console = v; }
+        if let Some(v) = update.chan16 { self.channels = (self.channels & 0xffff_0000) | (v & 0xffff); }
+        if let Some(v) = update.chan32 { self.channels = (self.channels & 0xffff) | ((v & 0xffff) << 16); }
+        if let Some(v) = update.returns { self.returns = v; }
+        if let Some(v) = update.buses { self.buses = v; }
+        if let Some(v) = update.lr_mtx_dca { self.lr_mtx_dca = v; }
+        if let Some(v) = update.effects { self.effects = v; }
+    }
+
+    /// Is a strip protected from scene recalls
+    #[must_use]
+    pub fn is_strip_safe(&self, source : &FaderIndex) -> bool {
+        let (mask, bit) = match source {
+            FaderIndex::Channel(v) => (self.channels, *v),
+            FaderIndex::Aux(v) => (self.returns, *v),
+            FaderIndex::Bus(v) => (self.buses, *v),
+            FaderIndex::Main(v) => (self.lr_mtx_dca, *v),
+            FaderIndex::Matrix(v) => (self.lr_mtx_dca, *v + 2),
+            FaderIndex::Dca(v) => (self.lr_mtx_dca, *v + 8),
+            FaderIndex::Unknown => return false,
+        };
+
+        bit.checked_sub(1).is_some_and(|v| v < 32 && mask & (1 << v) != 0)
+    }
+
+    /// Strips protected from scene recalls
+    #[must_use]
+    pub fn safe_strips(&self) -> Vec<FaderIndex> {
+        FaderIndex::all().filter(|v| self.is_strip_safe(v)).collect()
+    }
+
+    /// Is anything protected
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use crate::enums::{Error, FaderIndex, X32Error};
+
+// MARK: SendUpdate
+/// Send level update processed
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+pub struct SendUpdate {
+    /// sending strip (channel, aux in, or bus)
+    pub source : FaderIndex,
+    /// receiving strip (bus, or matrix for bus sends)
+    pub target : FaderIndex,
+    /// send level, as number
+    pub level : Option<f32>,
+    /// send on status, as bool
+    pub is_on : Option<bool>,
+}
+
+impl SendUpdate {
+    /// Source and target from an address bank, index, and send number
+    /// (e.g. `ch`, `05`, `03` for `/ch/05/mix/03`)
+    ///
+    /// # Errors
+    /// fails on strips without sends, and out of range indexes
+    pub fn from_parts(bank : &str, index : &str, send : &str) -> Result<Self, Error> {
+        let source = FaderIndex::from_parts(bank, index)?;
+        let target = match source {
+            FaderIndex::Channel(_) | FaderIndex::Aux(_) => FaderIndex::from_parts("bus", send)?,
+            FaderIndex::Bus(_) => FaderIndex::from_parts("mtx", send)?,
+            _ => return Err(Error::X32(X32Error::InvalidFader)),
+        };
+
+        Ok(Self { source, target, ..Self::default() })
+    }
+}
+
+// MARK: BusSend
+/// A single tracked send
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BusSend {
+    /// send level (0.0 - 1.0, same scale as a fader)
+    pub level : f32,
+    /// send is on
+    pub is_on : bool,
+}
+
+impl Default for BusSend {
+    fn default() -> Self { Self { level : 0_f32, is_on : true } }
+}
+
+// MARK: SendMatrix
+/// Send levels, by source and target strip
+///
+/// Only sends the console has reported are stored
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SendMatrix {
+    /// tracked sends
+    sends : BTreeMap<(FaderIndex, FaderIndex), BusSend>,
+}
+
+impl SendMatrix {
+    /// Get a send
+    #[must_use]
+    pub fn get(&self, source : &FaderIndex, target : &FaderIndex) -> Option<BusSend> {
+        self.sends.get(&(source.clone(), target.clone())).copied()
+    }
+
+    /// Every tracked send, by (source, target)
+    pub fn iter(&self) -> impl Iterator<Item = (&(FaderIndex, FaderIndex), &BusSend)> {
+        self.sends.iter()
+    }
+
+    /// Every tracked send from a strip, by target
+    pub fn from_strip<'a>(&'a self, source : &'a FaderIndex) -> impl Iterator<Item = (&'a FaderIndex, &'a BusSend)> {
+        self.sends.iter()
+            .filter(move |((s, _), _)| s == source)
+            .map(|((_, t), v)| (t, v))
+    }
+
+    /// Apply an update, returning the new send
+    pub fn update(&mut self, update : SendUpdate) -> BusSend {
+        let send = self.sends.entry((update.source, update.target)).or_default();
+
+        if let Some(level) = update.level { send.level = level; }
+        if let Some(is_on) = update.is_on { send.is_on = is_on; }
+
+        *send
+    }
+
+    /// Number of tracked sends
+    #[must_use]
+    pub fn len(&self) -> usize { self.sends.len() }
+
+    /// No sends tracked
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.sends.is_empty() }
+
+    /// Forget every send
+    pub fn clear(&mut self) { self.sends.clear(); }
+}
@@ -0,0 +1,96 @@
+use crate::enums::{Error, X32Error};
+use crate::osc::Message;
+use crate::X32Console;
+use super::{ConsoleMessage, NodeTokens};
+
+/// Node address prefix for show file lines
+const SHOW_PREFIX : &str = "/-show/showfile/";
+
+// MARK: ShowFile
+/// A `.shw` show file, as saved to USB by the console
+///
+/// The file is a `#version#` header line followed by node format lines
+/// relative to `/-show/showfile/` - the `show` line (name and safes), and
+/// the `cue/`, `scene/`, and `snippet/` lists.  Other lines are ignored.
+///
+/// ```
+/// use x32_osc_state::X32Console;
+/// use x32_osc_state::x32::ShowFile;
+///
+/// let show = ShowFile::parse("#2.7#\ncue/000 100 \"Intro\" 0 1 -1 0 1 0 0\nscene/001 \"Opening\" \"\" %000000000 1\n").expect("valid show");
+///
+/// let mut console = X32Console::new();
+/// show.apply_to(&mut console);
+///
+/// assert_eq!(show.version(), "2.7");
+/// assert_eq!(console.cue_list_size(), (1, 1, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowFile {
+    /// file format version, from the header
+    version : String,
+    /// show name, from the `show` line
+    name : String,
+    /// understood lines, as full node lines
+    lines : Vec<String>,
+}
+
+impl ShowFile {
+    /// Parse the contents of a `.shw` file
+    ///
+    /// # Errors
+    /// - [`X32Error::InvalidFile`] if the header is missing
+    /// - [`X32Error::MalformedPacket`] if a show, cue, scene, or snippet line is incomplete
+    pub fn parse(text : &str) -> Result<Self, Error> {
+        let mut text_lines = text.lines().map(str::trim).filter(|v| !v.is_empty());
+
+        let version = text_lines.next()
+            .and_then(|v| v.strip_prefix('#'))
+            .and_then(|v| v.split_once('#'))
+            .map(|(v, _)| v.to_owned())
+            .ok_or(Error::X32(X32Error::InvalidFile))?;
+
+        let mut name = String::new();
+        let mut lines = vec![];
+
+        for line in text_lines {
+            let key = line.split(['/', ' ']).next().unwrap_or_default();
+            if !matches!(key, "show" | "cue" | "scene" | "snippet") { continue }
+
+            let full = format!("{SHOW_PREFIX}{line}");
+            ConsoleMessage::try_from(&Message::new_with_string("node", &full))
+                .map_err(|_| Error::X32(X32Error::MalformedPacket))?;
+
+            if key == "show" {
+                name = NodeTokens::unescape(NodeTokens::new(line).nth(1).unwrap_or_default()).into_owned();
+            }
+            lines.push(full);
+        }
+
+        Ok(Self { version, name, lines })
+    }
+
+    /// File format version, e.g. `2.7`
+    #[must_use]
+    pub fn version(&self) -> &str { &self.version }
+
+    /// Show name, empty if the file has no `show` line
+    #[must_use]
+    pub fn name(&self) -> &str { &self.name }
+
+    /// Understood lines, as full node lines (e.g. `/-show/showfile/cue/000 ...`)
+    #[must_use]
+    pub fn lines(&self) -> &[String] { &self.lines }
+
+    /// Replace the cue, scene, and snippet lists (and show safes) of
+    /// `console` with the ones in this file
+    pub fn apply_to(&self, console : &mut X32Console) {
+        console.clear_cues();
+
+        for line in &self.lines {
+            if let Ok(update) = ConsoleMessage::try_from(&Message::new_with_string("node", line)) {
+                console.update(update);
+            }
+        }
+    }
+}
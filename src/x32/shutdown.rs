@@ -0,0 +1,110 @@
+use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
+
+use crate::X32Console;
+use super::ChangeKey;
+
+/// Audit log entry - an item and the generation it last changed in
+#[derive(serde::Serialize)]
+struct AuditEntry<'a> {
+    /// change generation
+    generation : u64,
+    /// what changed
+    change : &'a ChangeKey,
+}
+
+/// Audit log line, `{"audit":[...]}`
+#[derive(serde::Serialize)]
+struct AuditLog<'a> {
+    /// changes, oldest first
+    audit : Vec<AuditEntry<'a>>,
+}
+
+// MARK: ShutdownGuard
+/// Writes a JSON [`super::Snapshot`] of the console when dropped
+///
+/// The guard derefs to the wrapped [`X32Console`], so it can be used in
+/// its place.  Dropping it - at the end of `main`, or while unwinding from
+/// a panic - writes one line of JSON to the writer, so the last known
+/// state is available for a post-mortem.  With [`ShutdownGuard::with_audit`]
+/// a second line lists what changed, in order (see [`super::ChangeLog`]).
+///
+/// ```
+/// use x32_osc_state::X32Console;
+/// use x32_osc_state::x32::ShutdownGuard;
+///
+/// let mut out = vec![];
+/// {
+///     let mut console = ShutdownGuard::new(X32Console::new(), &mut out);
+///     console.current_cue = Some(3);
+/// }
+/// assert!(String::from_utf8_lossy(&out).contains("\"current_cue\":3"));
+/// ```
+#[derive(Debug)]
+pub struct ShutdownGuard<W : Write> {
+    /// guarded console
+    console : X32Console,
+    /// snapshot destination, `None` once disarmed
+    writer : Option<W>,
+    /// also write the audit log
+    audit : bool,
+}
+
+impl<W : Write> ShutdownGuard<W> {
+    /// Guard a console, writing to `writer` on drop
+    pub const fn new(console : X32Console, writer : W) -> Self {
+        Self { console, writer : Some(writer), audit : false }
+    }
+
+    /// Also write the audit log - every changed fader and cue list entry,
+    /// oldest change first, with the generation it last changed in
+    #[must_use]
+    pub const fn with_audit(mut self) -> Self {
+        self.audit = true;
+        self
+    }
+
+    /// Write a snapshot now (the guard stays armed)
+    ///
+    /// # Errors
+    /// fails on serialization or write errors
+    pub fn flush(&mut self) -> io::Result<()> {
+        let Some(writer) = self.writer.as_mut() else { return Ok(()) };
+        let json = self.console.snapshot().to_json()?;
+
+        writeln!(writer, "{json}")?;
+
+        if self.audit {
+            let history = self.console.changes.history();
+            let log = AuditLog {
+                audit : history.iter().map(|(generation, change)| AuditEntry { generation : *generation, change }).collect(),
+            };
+            writeln!(writer, "{}", serde_json::to_string(&log)?)?;
+        }
+        writer.flush()
+    }
+
+    /// Disarm the guard and return the console, without writing a snapshot
+    #[must_use]
+    pub fn into_inner(mut self) -> X32Console {
+        self.writer = None;
+        std::mem::take(&mut self.console)
+    }
+}
+
+impl<W : Write> Deref for ShutdownGuard<W> {
+    type Target = X32Console;
+
+    fn deref(&self) -> &Self::Target { &self.console }
+}
+
+impl<W : Write> DerefMut for ShutdownGuard<W> {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.console }
+}
+
+impl<W : Write> Drop for ShutdownGuard<W> {
+    fn drop(&mut self) {
+        // nothing useful can be done with an error while shutting down
+        let _ = self.flush();
+    }
+}
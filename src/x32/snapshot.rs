@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+use crate::enums::{Fader, FaderColor, FaderIndex, ShowCue, ShowMode};
+use crate::x32::updates::FaderUpdate;
+use crate::x32::{BusSend, DeviceInfo, Equalizer, MonitorState, OutputPort, OutputSource, Preamp, Safes, SendUpdate, UserBank};
+use crate::X32Console;
+
+// MARK: FaderState
+/// Fader state, as stored in a [`Snapshot`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
+pub struct FaderState {
+    /// fader index
+    pub source : FaderIndex,
+    /// scribble strip label (empty when unset)
+    pub label : String,
+    /// fader level (0.0 - 1.0)
+    pub level : f32,
+    /// mute status (true is un-muted)
+    pub is_on : bool,
+    /// scribble strip color
+    pub color : FaderColor,
+    /// pan (0.0 left - 0.5 center - 1.0 right)
+    pub pan : f32,
+    /// mute group membership (bit 0 is group 1)
+    pub mute_groups : u8,
+    /// DCA membership (bit 0 is DCA 1)
+    pub dca_groups : u8,
+    /// input trim in dB, `None` when not reported
+    pub trim : Option<f32>,
+}
+
+impl Default for FaderState {
+    fn default() -> Self {
+        Self::from(&Fader::new(FaderIndex::Unknown))
+    }
+}
+
+impl From<&Fader> for FaderState {
+    fn from(fader : &Fader) -> Self {
+        Self {
+            source : fader.source().clone(),
+            label : fader.label().to_owned(),
+            level : fader.level().0,
+            is_on : fader.is_on().0,
+            color : fader.color(),
+            pan : fader.pan().0,
+            mute_groups : fader.mute_groups(),
+            dca_groups : fader.dca_groups(),
+            trim : fader.trim().map(|v| v.0),
+        }
+    }
+}
+
+// MARK: Snapshot
+/// Point in time copy of the tracked console state
+///
+/// Snapshots carry a schema [`Snapshot::version`].  Missing fields load
+/// with their defaults, older versions are migrated on load, and newer
+/// versions are rejected (see [`Snapshot::from_json`])
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Snapshot {
+    /// schema version
+    pub version : u32,
+    /// every fader
+    pub faders : Vec<FaderState>,
+    /// cue list, by index
+    pub cues : BTreeMap<usize, ShowCue>,
+    /// scene list, by index
+    pub scenes : BTreeMap<usize, String>,
+    /// snippet list, by index
+    pub snippets : BTreeMap<usize, String>,
+    /// show control mode
+    pub show_mode : ShowMode,
+    /// current cue, scene, or snippet
+    pub current_cue : Option<usize>,
+    /// mute group on / off state
+    pub mute_groups : [bool; 6],
+    /// preamps, by channel
+    pub preamps : Vec<(FaderIndex, Preamp)>,
+    /// EQs, by strip
+    pub eqs : Vec<(FaderIndex, Equalizer)>,
+    /// sends, as (source, target, send)
+    pub sends : Vec<(FaderIndex, FaderIndex, BusSend)>,
+    /// output source assignments
+    pub outputs : Vec<(OutputPort, OutputSource)>,
+    /// routing blocks, as (`group/block`, value)
+    pub routing_blocks : Vec<(String, String)>,
+    /// user assignable control banks
+    pub user_controls : [UserBank; 3],
+    /// talkback and monitor section
+    pub monitor : MonitorState,
+    /// scene safes
+    pub safes : Safes,
+    /// console identity
+    pub device : Option<DeviceInfo>,
+}
+
+impl From<&X32Console> for Snapshot {
+    fn from(console : &X32Console) -> Self {
+        Self {
+            version : Self::VERSION,
+            faders : FaderIndex::all()
+                .filter_map(|index| console.fader(&index))
+                .map(FaderState::from)
+                .collect(),
+            cues : console.cues.clone(),
+            scenes : console.scenes.clone(),
+            snippets : console.snippets.clone(),
+            show_mode : console.show_mode,
+            current_cue : console.current_cue,
+            mute_groups : console.mute_groups,
+            preamps : console.preamps.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            eqs : console.eqs.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+            sends : console.sends.iter().map(|((s, t), v)| (s.clone(), t.clone(), *v)).collect(),
+            outputs : console.routing.outputs().map(|(k, v)| (*k, *v)).collect(),
+            routing_blocks : console.routing.blocks().map(|(k, v)| (k.clone(), v.clone())).collect(),
+            user_controls : console.user_controls.clone(),
+            monitor : console.monitor,
+            safes : console.safes,
+            device : console.device.clone(),
+        }
+    }
+}
+
+impl Snapshot {
+    /// Current schema version
+    pub const VERSION: u32 = 2;
+
+    /// Apply to a console, replacing its tracked state - see
+    /// [`X32Console::restore`]
+    pub fn restore(&self, console : &mut X32Console) {
+        console.replace_state(|console| self.apply(console));
+    }
+
+    /// Replace the tracked state, without recording or reporting it
+    fn apply(&self, console : &mut X32Console) {
+        console.clear_state();
+
+        for fader in &self.faders {
+            console.faders.update(FaderUpdate {
+                source : fader.source.clone(),
+                label : Some(fader.label.clone()),
+                level : Some(fader.level),
+                level_db : None,
+                is_on : Some(fader.is_on),
+                color : Some(fader.color),
+                pan : Some(fader.pan),
+                mute_groups : Some(fader.mute_groups),
+                dca_groups : Some(fader.dca_groups),
+                trim : fader.trim,
+            });
+        }
+
+        console.cues.clone_from(&self.cues);
+        console.scenes.clone_from(&self.scenes);
+        console.snippets.clone_from(&self.snippets);
+
+        console.show_mode = self.show_mode;
+        console.current_cue = self.current_cue;
+
+        console.mute_groups = self.mute_groups;
+        console.preamps = self.preamps.iter().cloned().collect();
+        console.eqs = self.eqs.iter().cloned().collect();
+        for (source, target, send) in &self.sends {
+            console.sends.update(SendUpdate {
+                source : source.clone(),
+                target : target.clone(),
+                level : Some(send.level),
+                is_on : Some(send.is_on),
+            });
+        }
+        console.routing = crate::x32::Routing::from_parts(&self.outputs, &self.routing_blocks);
+        console.user_controls.clone_from(&self.user_controls);
+        console.monitor = self.monitor;
+        console.safes = self.safes;
+        console.device.clone_from(&self.device);
+
+        // restored, not reported by the console
+        console.faders.clear_ages();
+    }
+}
+
+/// Schema migrations, `MIGRATIONS[n]` upgrades version `n` to `n + 1`
+#[cfg(feature = "serde")]
+const MIGRATIONS: [fn(&mut serde_json::Value); Snapshot::VERSION as usize] = [
+    // 0: unversioned snapshots, same fields as version 1
+    |_| (),
+    // 1: version 2 adds strip groups and subsystem state, all defaulted
+    |_| (),
+];
+
+#[cfg(feature = "serde")]
+impl Snapshot {
+    /// Serialize as JSON
+    ///
+    /// # Errors
+    /// fails if serialization fails
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Load from JSON, migrating older schema versions
+    ///
+    /// # Errors
+    /// - [`crate::enums::X32Error::SnapshotVersion`] if written by a newer version
+    /// - [`crate::enums::X32Error::InvalidSnapshot`] if the JSON is not a snapshot
+    pub fn from_json(json : &str) -> Result<Self, crate::enums::Error> {
+        let value:serde_json::Value = serde_json::from_str(json)
+            .map_err(|_| crate::enums::Error::X32(crate::enums::X32Error::InvalidSnapshot))?;
+
+        Self::from_value(value)
+    }
+
+    /// Load from an already parsed value (of any self-describing format),
+    /// migrating older schema versions
+    ///
+    /// # Errors
+    /// - [`crate::enums::X32Error::SnapshotVersion`] if written by a newer version
+    /// - [`crate::enums::X32Error::InvalidSnapshot`] if the value is not a snapshot
+    pub fn from_value(mut value : serde_json::Value) -> Result<Self, crate::enums::Error> {
+        let invalid = crate::enums::Error::X32(crate::enums::X32Error::InvalidSnapshot);
+
+        let version = match value.get("version") {
+            None => 0,
+            Some(v) => v.as_u64().and_then(|v| usize::try_from(v).ok()).ok_or(invalid)?,
+        };
+
+        let migrations = MIGRATIONS.get(version..)
+            .ok_or(crate::enums::Error::X32(crate::enums::X32Error::SnapshotVersion))?;
+
+        for migrate in migrations {
+            migrate(&mut value);
+        }
+
+        if let Some(map) = value.as_object_mut() {
+            map.insert(String::from("version"), Self::VERSION.into());
+        }
+
+        serde_json::from_value(value).map_err(|_| invalid)
+    }
+}
+
+impl From<X32Console> for Snapshot {
+    fn from(console : X32Console) -> Self {
+        Self::from(&console)
+    }
+}
+
+impl From<Snapshot> for X32Console {
+    fn from(snapshot : Snapshot) -> Self {
+        let mut console = Self::new();
+        snapshot.restore(&mut console);
+        console
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<serde_json::Value> for X32Console {
+    type Error = crate::enums::Error;
+
+    /// Restore a serialized [`Snapshot`], migrating older schema versions
+    fn try_from(value : serde_json::Value) -> Result<Self, Self::Error> {
+        Snapshot::from_value(value).map(Self::from)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::enums::{Error, X32Error};
+use crate::osc::Message;
+use crate::X32Console;
+use super::{ConsoleMessage, ConsoleRequest, NodeCommand, NodeTokens};
+
+// MARK: SnippetFile
+/// A `.snp` snippet file, as saved to USB by the console
+///
+/// The file is a `#version#` header line (with the snippet name) followed
+/// by node format set commands with full addresses, e.g.
+/// `/ch/01/mix ON -10.0 ON +0 OFF -oo`.
+///
+/// ```
+/// use x32_osc_state::X32Console;
+/// use x32_osc_state::enums::FaderIndex;
+/// use x32_osc_state::x32::SnippetFile;
+///
+/// let snippet = SnippetFile::parse("#2.7# \"Mics Up\" 1 1 0 32768 1\n/ch/01/config \"Lead Vox\" 1 RD 1\n").expect("valid snippet");
+///
+/// let mut console = X32Console::new();
+/// snippet.apply_to(&mut console);
+///
+/// assert_eq!(snippet.name(), "Mics Up");
+/// assert_eq!(console.fader(&FaderIndex::Channel(1)).expect("valid fader").label(), "Lead Vox");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetFile {
+    /// file format version, from the header
+    version : String,
+    /// snippet name, from the header
+    name : String,
+    /// contained set commands
+    commands : Vec<NodeCommand>,
+}
+
+impl SnippetFile {
+    /// Parse the contents of a `.snp` file
+    ///
+    /// # Errors
+    /// [`X32Error::InvalidFile`] if the header is missing
+    pub fn parse(text : &str) -> Result<Self, Error> {
+        let mut lines = text.lines().map(str::trim).filter(|v| !v.is_empty());
+
+        let (version, rest) = lines.next()
+            .and_then(|v| v.strip_prefix('#'))
+            .and_then(|v| v.split_once('#'))
+            .ok_or(Error::X32(X32Error::InvalidFile))?;
+
+        Ok(Self {
+            version : version.to_owned(),
+            name : NodeTokens::unescape(NodeTokens::new(rest).next().unwrap_or_default()).into_owned(),
+            commands : lines.filter(|v| v.starts_with('/')).filter_map(NodeCommand::parse).collect(),
+        })
+    }
+
+    /// File format version, e.g. `2.7`
+    #[must_use]
+    pub fn version(&self) -> &str { &self.version }
+
+    /// Snippet name
+    #[must_use]
+    pub fn name(&self) -> &str { &self.name }
+
+    /// Contained set commands, in file order
+    #[must_use]
+    pub fn commands(&self) -> &[NodeCommand] { &self.commands }
+
+    /// Apply the commands to the tracked state, as if the console had
+    /// recalled the snippet, returning how many were understood
+    pub fn apply_to(&self, console : &mut X32Console) -> usize {
+        self.commands.iter()
+            .filter_map(|v| ConsoleMessage::try_from(&Message::new_with_string("node", &v.to_string())).ok())
+            .map(|update| console.update(update))
+            .count()
+    }
+
+    /// Requests that send the commands to a live console - send them with
+    /// [`X32Console::request`] to apply address rewriting
+    #[must_use]
+    pub fn requests(&self) -> Vec<ConsoleRequest> {
+        self.commands.iter().cloned().map(ConsoleRequest::Node).collect()
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::BTreeSet;
+
+use crate::enums::{Error, FaderIndex, X32Error};
+
+// MARK: SoloUpdate
+/// Solo update processed
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub enum SoloUpdate {
+    /// A strip solo switch (`/-stat/solosw/NN`)
+    Strip(FaderIndex, bool),
+    /// The global "solo active" lamp (`/-stat/solo`)
+    Active(bool),
+}
+
+impl SoloUpdate {
+    /// Strip for a solo switch number (`01` - `80`)
+    ///
+    /// Switches are numbered channels (1-32), aux ins (33-40), fx returns
+    /// (41-48), buses (49-64), matrices (65-70), main, mono, then DCAs (73-80)
+    ///
+    /// # Errors
+    /// fails on out of range switches, and fx returns (which are not tracked)
+    pub fn strip_from_switch(index : &str) -> Result<FaderIndex, Error> {
+        match index.parse::<usize>() {
+            Ok(v @ 1..=32) => Ok(FaderIndex::Channel(v)),
+            Ok(v @ 33..=40) => Ok(FaderIndex::Aux(v - 32)),
+            Ok(v @ 49..=64) => Ok(FaderIndex::Bus(v - 48)),
+            Ok(v @ 65..=70) => Ok(FaderIndex::Matrix(v - 64)),
+            Ok(v @ 71..=72) => Ok(FaderIndex::Main(v - 70)),
+            Ok(v @ 73..=80) => Ok(FaderIndex::Dca(v - 72)),
+            _ => Err(Error::X32(X32Error::InvalidFader)),
+        }
+    }
+}
+
+// MARK: SoloState
+/// Tracked solo state
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoloState {
+    /// soloed strips
+    strips : BTreeSet<FaderIndex>,
+    /// console solo lamp
+    active : bool,
+}
+
+impl SoloState {
+    /// Apply an update
+    pub fn update(&mut self, update : SoloUpdate) {
+        match update {
+            SoloUpdate::Strip(source, true) => { self.strips.insert(source); },
+            SoloUpdate::Strip(source, false) => { self.strips.remove(&source); },
+            SoloUpdate::Active(v) => self.active = v,
+        }
+    }
+
+    /// Is a strip soloed
+    #[must_use]
+    pub fn is_soloed(&self, source : &FaderIndex) -> bool {
+        self.strips.contains(source)
+    }
+
+    /// Soloed strips
+    pub fn strips(&self) -> impl Iterator<Item = &FaderIndex> {
+        self.strips.iter()
+    }
+
+    /// Is anything soloed - the console lamp, or any tracked strip
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active || !self.strips.is_empty()
+    }
+
+    /// Clear all solos
+    pub fn clear(&mut self) {
+        self.strips.clear();
+        self.active = false;
+    }
+}
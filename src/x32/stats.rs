@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::enums::Error;
+use crate::osc::{Message, Type};
+
+// MARK: Stats
+/// Traffic counters, see [`crate::X32Console::stats`]
+///
+/// Counts start when the console is created, or at the last
+/// [`crate::X32Console::take_stats`] - call that from a timer for per
+/// period figures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// inputs processed, including ones that failed to decode
+    pub packets : u64,
+    /// bytes processed (buffer and datagram inputs only)
+    pub bytes : u64,
+    /// inputs that failed to decode or parse
+    pub failures : u64,
+    /// most recent decode or parse error
+    pub last_error : Option<Error>,
+    /// decoded messages, by address (node messages by node address) -
+    /// at most [`Stats::MAX_ADDRESSES`] of them
+    pub by_address : BTreeMap<String, u64>,
+    /// decoded messages to addresses that did not fit in `by_address`
+    pub other : u64,
+    /// start of the counting period
+    pub since : Instant,
+}
+
+impl Stats {
+    /// Most addresses counted separately - the console only has a few
+    /// hundred, anything past this is likely junk and counted in `other`
+    pub const MAX_ADDRESSES : usize = 1024;
+
+    /// New, empty counters
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            packets : 0,
+            bytes : 0,
+            failures : 0,
+            last_error : None,
+            by_address : BTreeMap::new(),
+            other : 0,
+            since : Instant::now(),
+        }
+    }
+
+    /// Count an input of `bytes` length (0 when unknown)
+    pub fn record(&mut self, bytes : usize) {
+        self.packets += 1;
+        self.bytes += u64::try_from(bytes).unwrap_or_default();
+    }
+
+    /// Count the bytes of a datagram whose messages are counted separately
+    pub fn record_bytes(&mut self, bytes : usize) {
+        self.bytes += u64::try_from(bytes).unwrap_or_default();
+    }
+
+    /// Count a decoded message by address
+    pub fn record_address(&mut self, msg : &Message) {
+        let address = match (msg.address.as_str(), msg.args.first()) {
+            ("node", Some(Type::String(v))) => v.split_whitespace().next().unwrap_or_default(),
+            (address, _) => address,
+        };
+        if let Some(count) = self.by_address.get_mut(address) {
+            *count += 1;
+        } else if self.by_address.len() < Self::MAX_ADDRESSES {
+            self.by_address.insert(address.to_owned(), 1);
+        } else {
+            self.other += 1;
+        }
+    }
+
+    /// Count a decode or parse failure
+    pub fn record_failure(&mut self, error : Error) {
+        self.failures += 1;
+        self.last_error = Some(error);
+    }
+
+    /// Length of the counting period so far
+    #[must_use]
+    pub fn elapsed(&self) -> Duration { self.since.elapsed() }
+
+    /// Inputs per second over the counting period
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub fn packet_rate(&self) -> f64 {
+        let secs = self.elapsed().as_secs_f64();
+        if secs > 0_f64 { self.packets as f64 / secs } else { 0_f64 }
+    }
+
+    /// The `count` most frequent addresses, busiest first
+    #[must_use]
+    pub fn busiest(&self, count : usize) -> Vec<(&str, u64)> {
+        let mut list:Vec<(&str, u64)> = self.by_address.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        list.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        list.truncate(count);
+        list
+    }
+
+    /// Clear every counter, starting a new period
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self { Self::new() }
+}
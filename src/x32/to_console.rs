@@ -1,9 +1,109 @@
-use crate::osc::{Message, Buffer};
-use super::super::enums::FaderIndex;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+use crate::osc::{Bundle, Message, Buffer, Packet};
+use super::super::enums::{FaderColor, FaderIndex};
+use super::{Dialect, MeterBank, NodeCommand, RecorderKind, TransportState};
 // use super::util;
 
+/// Encoded [`ConsoleRequest::full_update`] buffers, built on first use
+static FULL_UPDATE: LazyLock<Vec<Buffer>> = LazyLock::new(|| {
+    let mut buffers:Vec<Buffer> = vec![];
+
+    buffers.extend(ConsoleRequest::ShowInfo());
+    buffers.extend(ConsoleRequest::ShowMode());
+    buffers.extend(ConsoleRequest::CurrentCue());
+
+    let faders = FaderIndex::mains()
+        .chain(FaderIndex::aux_inputs())
+        .chain(FaderIndex::matrices())
+        .chain(FaderIndex::buses())
+        .chain(FaderIndex::dcas())
+        .chain(FaderIndex::channels());
+
+    buffers.extend(faders.flat_map(ConsoleRequest::Fader));
+    buffers
+});
+
+/// Nodes (`ch/01/config`) asked for by [`ConsoleRequest::full_update`]
+static FULL_UPDATE_NODES: LazyLock<BTreeSet<String>> = LazyLock::new(|| {
+    FULL_UPDATE.iter()
+        .filter_map(|buffer| Message::try_from(buffer.clone()).ok())
+        .filter(|msg| msg.address == "/node")
+        .map(|msg| msg.first_default(String::new()))
+        .collect()
+});
+
+/// Subscription parameters for [`ConsoleRequest::BatchSubscribe`] and
+/// [`ConsoleRequest::FormatSubscribe`]
+///
+/// Replies arrive at `alias` as a single blob (see
+/// [`crate::X32ProcessResult::Subscription`]) for about 10 seconds - send
+/// `/renew` with the alias to keep them coming
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Subscription {
+    /// reply address, e.g. `/fdr`
+    pub alias : String,
+    /// subscribed address or pattern, e.g. `/ch/**/mix/fader`
+    pub address : String,
+    /// first and last item (meaning depends on the address)
+    pub range : (i32, i32),
+    /// update time factor (the console sends every `factor` x 50ms)
+    pub factor : i32,
+}
+
+impl Subscription {
+    /// Subscribe message for a subscribe `command`
+    fn to_message(&self, command : &str) -> Message {
+        Message::builder(command)
+            .string(&self.alias)
+            .string(&self.address)
+            .int(self.range.0)
+            .int(self.range.1)
+            .int(self.factor)
+            .build()
+    }
+}
+
+/// Fader parameter address - DCAs have no `mix` node
+fn fader_address(index : &FaderIndex, parameter : &str) -> Option<String> {
+    match (index, parameter) {
+        (FaderIndex::Unknown, _) => None,
+        (FaderIndex::Dca(_), "fader" | "on") => Some(format!("/{}/{parameter}", index.get_x32_address())),
+        (_, "fader" | "on") => Some(format!("/{}/mix/{parameter}", index.get_x32_address())),
+        _ => Some(format!("/{}/config/{parameter}", index.get_x32_address())),
+    }
+}
+
+// MARK: FaderLevel
+/// Fader level (0.0 - 1.0) for [`ConsoleRequest::SetLevel`] - out of range
+/// levels are clamped, non-finite ones rejected
+///
+/// ```
+/// use x32_osc_state::x32::FaderLevel;
+///
+/// assert_eq!(FaderLevel::new(1.5).map(FaderLevel::get), Some(1.0));
+/// assert_eq!(FaderLevel::new(f32::NAN), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FaderLevel(u32);
+
+impl FaderLevel {
+    /// Level from a float, `None` if it is NaN or infinite
+    #[must_use]
+    pub fn new(level : f32) -> Option<Self> {
+        // adding zero folds -0.0 into 0.0, so equal levels have equal bits
+        level.is_finite().then(|| Self((level.clamp(0_f32, 1_f32) + 0_f32).to_bits()))
+    }
+
+    /// Level as a float
+    #[must_use]
+    pub const fn get(self) -> f32 { f32::from_bits(self.0) }
+}
+
+// MARK: ConsoleRequest
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
-/// Get info from the console
+/// Get info from the console, or change it
 pub enum ConsoleRequest {
     /// Matrix with index
     Fader(FaderIndex),
@@ -15,32 +115,132 @@ pub enum ConsoleRequest {
     CurrentCue(),
     /// /xremote command
     KeepAlive(),
+    /// Subscribe to a meter bank - `/meters ,si /meters/<id> <time factor>`
+    ///
+    /// The console sends the bank every `time factor` x 50ms for about
+    /// 10 seconds, see [`ConsoleRequest::RenewMeters`]
+    Meters(MeterBank, i32),
+    /// Renew a meter subscription - `/renew ,s /meters/<id>`
+    RenewMeters(MeterBank),
+    /// `/batchsubscribe ,ssiii <alias> <address> <from> <to> <factor>`
+    BatchSubscribe(Subscription),
+    /// `/formatsubscribe ,ssiii <alias> <pattern> <first> <last> <factor>`
+    FormatSubscribe(Subscription),
+    /// Renew a subscription by alias - `/renew ,s <alias>`
+    Renew(String),
+    /// Set a recorder's transport state - `/-stat/tape/state ,i` or `/-stat/urec/state ,i`
+    Recorder(RecorderKind, TransportState),
+    /// Console identity - `/xinfo` and `/info`
+    Info(),
+    /// Set fader level - `/ch/01/mix/fader ,f`
+    SetLevel(FaderIndex, FaderLevel),
+    /// Set fader mute - `/ch/01/mix/on ,i` (note: on is *unmuted*)
+    SetMute(FaderIndex, bool),
+    /// Set fader label - `/ch/01/config/name ,s`
+    SetLabel(FaderIndex, String),
+    /// Set fader color - `/ch/01/config/color ,i`
+    SetColor(FaderIndex, FaderColor),
+    /// Node format set command - `/ ,s "<address> <args>"`
+    Node(NodeCommand),
+    /// Fire a cue by list index - `/-action/gocue ,i`
+    GoCue(usize),
+    /// Load a scene - `/-action/goscene ,i`
+    LoadScene(usize),
+    /// Load a snippet - `/-action/gosnippet ,i`
+    LoadSnippet(usize),
+    /// Fire the next cue, scene, or snippet (by show mode) after the
+    /// current one, stepping over skipped cues.  Needs the tracked list -
+    /// resolved by [`crate::X32Console::request`], sends nothing on its own
+    NextCue(),
+    /// Fire the previous cue, scene, or snippet (by show mode) before the
+    /// current one, stepping over skipped cues.  Needs the tracked list -
+    /// resolved by [`crate::X32Console::request`], sends nothing on its own
+    PrevCue(),
 }
 
 impl ConsoleRequest {
+    /// Largest UDP payload that fits an Ethernet frame unfragmented, a
+    /// safe `max_len` for [`ConsoleRequest::pack`]
+    pub const MAX_DATAGRAM : usize = 1472;
+
+    /// Pack request buffers into bundles of at most `max_len` bytes, in
+    /// order, so a full update takes a handful of datagrams rather than
+    /// one per query - see [`Bundle::pack`].  Buffers that do not decode
+    /// are passed through on their own
+    ///
+    /// ```
+    /// use x32_osc_state::x32::ConsoleRequest;
+    ///
+    /// let packed = ConsoleRequest::pack(ConsoleRequest::full_update(), ConsoleRequest::MAX_DATAGRAM);
+    /// assert!(packed.len() < 10);
+    /// assert!(packed.iter().all(|v| v.len() <= ConsoleRequest::MAX_DATAGRAM));
+    /// ```
+    pub fn pack<T: IntoIterator<Item = Buffer>>(buffers : T, max_len : usize) -> Vec<Buffer> {
+        let mut packed:Vec<Buffer> = vec![];
+        let mut pending:Vec<(Buffer, Packet)> = vec![];
+
+        for buffer in buffers {
+            if let Ok(packet) = Packet::try_from(buffer.clone()) {
+                pending.push((buffer, packet));
+                continue;
+            }
+            packed.extend(Self::encode_packed(std::mem::take(&mut pending), max_len));
+            packed.push(buffer);
+        }
+        packed.extend(Self::encode_packed(pending, max_len));
+
+        packed
+    }
+
+    /// Pack decoded packets and encode them again - if any bundle fails to
+    /// encode, the original buffers go out unpacked instead
+    fn encode_packed(pending : Vec<(Buffer, Packet)>, max_len : usize) -> Vec<Buffer> {
+        let (buffers, packets):(Vec<Buffer>, Vec<Packet>) = pending.into_iter().unzip();
+        Bundle::pack(packets, max_len).into_iter()
+            .map(Buffer::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or(buffers)
+    }
+
     /// Full update of all tracked data request
     #[must_use]
     pub fn full_update() -> Vec<Buffer> {
-        let mut buffers:Vec<Buffer> = vec![];
-
-        buffers.extend(Self::ShowInfo());
-        buffers.extend(Self::ShowMode());
-        buffers.extend(Self::CurrentCue());
-        buffers.extend(Self::Fader(FaderIndex::Main(1)));
-        buffers.extend(Self::Fader(FaderIndex::Main(2)));
-
-        let aux:Vec<Buffer> = (1..=8).flat_map(|i|Self::Fader(FaderIndex::Aux(i))).collect();
-        let mtx:Vec<Buffer> = (1..=6).flat_map(|i|Self::Fader(FaderIndex::Matrix(i))).collect();
-        let bus:Vec<Buffer> = (1..=16).flat_map(|i|Self::Fader(FaderIndex::Bus(i))).collect();
-        let dca:Vec<Buffer> = (1..=8).flat_map(|i|Self::Fader(FaderIndex::Dca(i))).collect();
-        let ch:Vec<Buffer>  = (1..=32).flat_map(|i|Self::Fader(FaderIndex::Channel(i))).collect();
-
-        buffers.extend(aux);
-        buffers.extend(mtx);
-        buffers.extend(bus);
-        buffers.extend(dca);
-        buffers.extend(ch);
-        buffers
+        Self::full_update_buffers().to_vec()
+    }
+
+    /// Full update of all tracked data request, without copying - the
+    /// buffers are encoded once and shared
+    #[must_use]
+    pub fn full_update_buffers() -> &'static [Buffer] {
+        &FULL_UPDATE
+    }
+
+    /// Is `node` (`ch/01/config`) one of those [`ConsoleRequest::full_update`]
+    /// asks for
+    #[must_use]
+    pub fn is_update_node(node : &str) -> bool {
+        FULL_UPDATE_NODES.contains(node)
+    }
+
+    /// Full update for a console speaking `dialect` - only the faders it has
+    #[must_use]
+    pub fn full_update_for(dialect : Dialect) -> Vec<Buffer> {
+        match dialect {
+            Dialect::X32 => Self::full_update(),
+            Dialect::XAir => dialect.outgoing(dialect.faders().flat_map(Self::Fader)),
+            #[cfg(feature = "wing")]
+            Dialect::Wing => dialect.faders().flat_map(|v| Self::Fader(v).encode_wing()).collect(),
+        }
+    }
+
+    /// Buffers for this request, for a console speaking `dialect`
+    #[must_use]
+    pub fn encode_for(self, dialect : Dialect) -> Vec<Buffer> {
+        match dialect {
+            Dialect::X32 | Dialect::XAir => dialect.outgoing(self),
+            #[cfg(feature = "wing")]
+            Dialect::Wing => self.encode_wing(),
+        }
     }
 }
 
@@ -70,6 +270,51 @@ impl From<ConsoleRequest> for Vec<Buffer> {
             ConsoleRequest::KeepAlive() => vec![
                 Message::new("/xremote").try_into().unwrap_or_default()
             ],
+            ConsoleRequest::Meters(bank, factor) => vec![
+                Message::builder("/meters").string(&bank.address()).int(factor).build().try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::RenewMeters(bank) => vec![
+                Message::new_with_string("/renew", &bank.address()).try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::BatchSubscribe(v) => vec![
+                v.to_message("/batchsubscribe").try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::FormatSubscribe(v) => vec![
+                v.to_message("/formatsubscribe").try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::Renew(alias) => vec![
+                Message::new_with_string("/renew", &alias).try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::Info() => vec![
+                Message::new("/xinfo").try_into().unwrap_or_default(),
+                Message::new("/info").try_into().unwrap_or_default(),
+            ],
+            ConsoleRequest::Recorder(kind, state) => state.to_message(kind)
+                .and_then(|v| Buffer::try_from(v).ok())
+                .into_iter().collect(),
+            ConsoleRequest::Node(v) => Buffer::try_from(v).into_iter().collect(),
+            ConsoleRequest::GoCue(v) => vec![
+                Message::builder("/-action/gocue").int(i32::try_from(v).unwrap_or_default()).build().try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::LoadScene(v) => vec![
+                Message::builder("/-action/goscene").int(i32::try_from(v).unwrap_or_default()).build().try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::LoadSnippet(v) => vec![
+                Message::builder("/-action/gosnippet").int(i32::try_from(v).unwrap_or_default()).build().try_into().unwrap_or_default()
+            ],
+            ConsoleRequest::NextCue() | ConsoleRequest::PrevCue() => vec![],
+            ConsoleRequest::SetLevel(index, level) => fader_address(&index, "fader").map(|address|
+                Message::builder(&address).float(level.get()).build().try_into().unwrap_or_default()
+            ).into_iter().collect(),
+            ConsoleRequest::SetMute(index, is_muted) => fader_address(&index, "on").map(|address|
+                Message::builder(&address).int(i32::from(!is_muted)).build().try_into().unwrap_or_default()
+            ).into_iter().collect(),
+            ConsoleRequest::SetLabel(index, label) => fader_address(&index, "name").map(|address|
+                Message::new_with_string(&address, &label).try_into().unwrap_or_default()
+            ).into_iter().collect(),
+            ConsoleRequest::SetColor(index, color) => fader_address(&index, "color").map(|address|
+                Message::builder(&address).int(color.as_int()).build().try_into().unwrap_or_default()
+            ).into_iter().collect(),
         }
     }
 }
\ No newline at end of file
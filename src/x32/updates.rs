@@ -1,4 +1,4 @@
-use super::super::enums::{Error, FaderIndex, Fader, FaderColor, FaderIndexParse};
+use super::super::enums::{Error, FaderIndex, Fader, FaderColor};
 
 
 /// CUE record
@@ -14,6 +14,9 @@ pub struct CueUpdate {
     pub snippet : Option<usize>,
     /// associated scene (or None)
     pub scene : Option<usize>,
+    /// skipped when stepping through the list - the record's third field
+    /// is the cue's "active" flag, so `0` means skipped
+    pub skip : bool,
 }
 
 /// Snippet record
@@ -36,17 +39,37 @@ pub struct SceneUpdate {
 
 /// Fader update processed
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
+#[non_exhaustive]
 pub struct FaderUpdate {
     /// Type of fader
     pub source : FaderIndex,
     /// scribble strip label
     pub label : Option<String>,
-    /// level of fader, as number
+    /// level of fader, as number (on the X32 taper)
     pub level : Option<f32>,
+    /// level of fader in dB, when the console sent dB - used instead of
+    /// `level` when applied, converted on the fader's own taper
+    pub level_db : Option<f32>,
     /// mute status, as bool
     pub is_on : Option<bool>,
     /// color
-    pub color : Option<FaderColor>
+    pub color : Option<FaderColor>,
+    /// pan, as number (0.0 left - 0.5 center - 1.0 right)
+    pub pan : Option<f32>,
+    /// mute group membership, as a bitmask (bit 0 is group 1)
+    pub mute_groups : Option<u8>,
+    /// DCA membership, as a bitmask (bit 0 is DCA 1)
+    pub dca_groups : Option<u8>,
+    /// input trim, in dB (-18 - +18)
+    pub trim : Option<f32>,
+}
+
+impl FaderUpdate {
+    /// Empty update for a fader, set the fields to change
+    #[must_use]
+    pub fn new(source : FaderIndex) -> Self {
+        Self { source, ..Self::default() }
+    }
 }
 
 impl Default for FaderUpdate {
@@ -54,11 +77,69 @@ impl Default for FaderUpdate {
         source : FaderIndex::Unknown,
         label : None,
         level : None,
+        level_db : None,
         is_on : None,
-        color : None
+        color : None,
+        pan : None,
+        mute_groups : None,
+        dca_groups : None,
+        trim : None,
     } }
 }
 
+/// Raw `/meters` blob, decoded on demand
+///
+/// Values are little-endian `f32`, index 0 is the element count sent by
+/// the console (see [`crate::X32ProcessResult::Meters`]).  Trailing bytes
+/// that do not make up a full value are ignored.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct MeterBlob {
+    /// blob bytes, as received
+    data : Vec<u8>,
+}
+
+impl From<Vec<u8>> for MeterBlob {
+    fn from(data : Vec<u8>) -> Self { Self { data } }
+}
+
+impl MeterBlob {
+    /// Number of values
+    #[must_use]
+    pub fn len(&self) -> usize { self.data.len() / 4 }
+
+    /// Blob holds no values
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Decode a single value
+    #[must_use]
+    pub fn get(&self, index : usize) -> Option<f32> {
+        let start = index.checked_mul(4)?;
+        let bytes = self.data.get(start..start.checked_add(4)?)?;
+        Some(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Iterate over the decoded values
+    pub fn iter(&self) -> impl Iterator<Item = f32> + '_ {
+        self.data.chunks_exact(4).map(|f| f32::from_le_bytes([f[0], f[1], f[2], f[3]]))
+    }
+
+    /// Decode every value
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<f32> { self.iter().collect() }
+
+    /// Raw blob bytes
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] { &self.data }
+
+    /// Decode as typed [`super::Meters`] for a `/meters/<id>` bank
+    ///
+    /// # Errors
+    /// fails if the blob is shorter than the bank layout
+    pub fn decode(&self, bank : usize) -> Result<super::Meters, Error> {
+        super::Meters::decode(super::MeterBank::from_id(bank), self)
+    }
+}
 
 /// Fader bank name
 pub struct FaderName(pub String);
@@ -94,7 +175,7 @@ impl TryFrom<FaderUpdateParse> for FaderUpdate {
             FaderUpdateParse::StdMute(b, i, _) |
             FaderUpdateParse::StdName(b, i, _) |
             FaderUpdateParse::StdColor(b, i, _) =>
-                FaderIndex::try_from(FaderIndexParse::String(b.0.clone(), i.0.clone()))?,
+                FaderIndex::from_parts(&b.0, &i.0)?,
         };
 
         let is_on = match &value {
@@ -109,6 +190,11 @@ impl TryFrom<FaderUpdateParse> for FaderUpdate {
             _ => None
         };
 
+        let level_db = match &value {
+            FaderUpdateParse::NodeMix(_, _, _, t) => Fader::db_from_string(t),
+            _ => None
+        };
+
         let label = match &value {
             FaderUpdateParse::NodeConfig(_, _, t, _) |
             FaderUpdateParse::StdName(_, _, t) => Some(t.clone()),
@@ -122,6 +208,6 @@ impl TryFrom<FaderUpdateParse> for FaderUpdate {
         };
 
 
-        Ok(Self { source, label, level, is_on, color })
+        Ok(Self { source, label, level, level_db, is_on, color, pan : None, mute_groups : None, dca_groups : None, trim : None })
     }
 }
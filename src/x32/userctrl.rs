@@ -0,0 +1,122 @@
+use crate::enums::{Error, FaderColor, X32Error};
+
+// MARK: UserControlUpdate
+/// User assignable control update processed
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd)]
+pub struct UserControlUpdate {
+    /// bank index, 0 - 2 (`A` - `C`)
+    pub bank : usize,
+    /// bank color
+    pub color : Option<FaderColor>,
+    /// encoder assignments, as (encoder 1 - 4, assignment)
+    pub encoders : Vec<(usize, String)>,
+    /// button assignments, as (button 5 - 12, assignment)
+    pub buttons : Vec<(usize, String)>,
+}
+
+impl UserControlUpdate {
+    /// Bank index from an address part (`A`, `B`, or `C`)
+    ///
+    /// # Errors
+    /// fails on unknown banks
+    pub fn bank_from_part(v : &str) -> Result<usize, Error> {
+        match v {
+            "A" => Ok(0),
+            "B" => Ok(1),
+            "C" => Ok(2),
+            _ => Err(Error::X32(X32Error::InvalidFader)),
+        }
+    }
+}
+
+// MARK: UserAssignment
+/// What a user assignable encoder or button controls, parsed from the
+/// console's control code
+///
+/// ```
+/// use x32_osc_state::x32::UserAssignment;
+///
+/// assert_eq!(UserAssignment::parse("-"), UserAssignment::Unassigned);
+/// assert_eq!(UserAssignment::parse("MP13000"), UserAssignment::Code { family : 'M', argument : String::from("P13000") });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UserAssignment {
+    /// nothing assigned (`""`, or `-` in scene files)
+    #[default]
+    Unassigned,
+    /// control code, as the function family (the leading letter) and its
+    /// argument - `MP13000` is family `M`, argument `P13000`
+    Code {
+        /// function family
+        family : char,
+        /// family specific argument
+        argument : String,
+    },
+}
+
+impl UserAssignment {
+    /// Parse a control code
+    #[must_use]
+    pub fn parse(code : &str) -> Self {
+        let mut chars = code.chars();
+        match (code, chars.next()) {
+            ("-", _) | (_, None) => Self::Unassigned,
+            (_, Some(family)) => Self::Code { family, argument : chars.as_str().to_owned() },
+        }
+    }
+}
+
+// MARK: UserBank
+/// Tracked user assignable bank - 4 encoders and 8 buttons (numbered 5 - 12)
+///
+/// Assignments are the console's control codes (e.g. `MP13000`), an
+/// empty string is unassigned - see [`UserBank::button_assignment`] for
+/// the parsed form
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserBank {
+    /// bank color
+    pub color : FaderColor,
+    /// encoder 1 - 4 assignments
+    pub encoders : [String; 4],
+    /// button 5 - 12 assignments
+    pub buttons : [String; 8],
+}
+
+impl UserBank {
+    /// Apply an update
+    pub fn update(&mut self, update : UserControlUpdate) {
+        if let Some(v) = update.color { self.color = v; }
+
+        for (index, value) in update.encoders {
+            if let Some(slot) = index.checked_sub(1).and_then(|v| self.encoders.get_mut(v)) { *slot = value; }
+        }
+        for (index, value) in update.buttons {
+            if let Some(slot) = index.checked_sub(5).and_then(|v| self.buttons.get_mut(v)) { *slot = value; }
+        }
+    }
+
+    /// Encoder assignment (1 - 4)
+    #[must_use]
+    pub fn encoder(&self, index : usize) -> Option<&str> {
+        self.encoders.get(index.checked_sub(1)?).map(String::as_str)
+    }
+
+    /// Button assignment (5 - 12)
+    #[must_use]
+    pub fn button(&self, index : usize) -> Option<&str> {
+        self.buttons.get(index.checked_sub(5)?).map(String::as_str)
+    }
+
+    /// Parsed encoder assignment (1 - 4)
+    #[must_use]
+    pub fn encoder_assignment(&self, index : usize) -> Option<UserAssignment> {
+        self.encoder(index).map(UserAssignment::parse)
+    }
+
+    /// Parsed button assignment (5 - 12)
+    #[must_use]
+    pub fn button_assignment(&self, index : usize) -> Option<UserAssignment> {
+        self.button(index).map(UserAssignment::parse)
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use crate::enums::FaderIndex;
+use crate::osc::Buffer;
+use crate::{X32Console, X32ProcessResult};
+use super::{ConsoleInput, FaderState, Snapshot};
+
+// MARK: WarmStart
+/// Warm start from a previous session's [`Snapshot`], followed by a
+/// verification sync against the live console
+///
+/// [`X32Console::warm_start`] restores the snapshot immediately (so a UI
+/// is populated before the console answers) and returns the full update
+/// requests to send.  Feed replies through [`WarmStart::process`] - results
+/// that only confirm the cached state are reported as
+/// [`X32ProcessResult::NoOperation`], so only corrections reach the UI.
+///
+/// ```
+/// use x32_osc_state::{X32Console, X32ProcessResult};
+/// use x32_osc_state::osc::Message;
+///
+/// let mut previous = X32Console::new();
+/// previous.process(Message::new_with_string("node", "/ch/01/mix ON -6.0 OFF +0 OFF -oo"));
+///
+/// let mut console = X32Console::new();
+/// let (mut warm, _requests) = console.warm_start(&previous.snapshot());
+///
+/// // console agrees with the cache - nothing to report
+/// let same = warm.process(&mut console, Message::new_with_string("node", "/ch/01/mix ON -6.0 OFF +0 OFF -oo"));
+/// assert_eq!(same, X32ProcessResult::NoOperation);
+///
+/// // console differs - a correction
+/// let changed = warm.process(&mut console, Message::new_with_string("node", "/ch/02/mix ON -6.0 OFF +0 OFF -oo"));
+/// assert!(matches!(changed, X32ProcessResult::Fader(_)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WarmStart {
+    /// cached fader state, by index
+    cached : BTreeMap<FaderIndex, FaderState>,
+    /// verification replies still expected, by fader
+    awaiting : BTreeMap<FaderIndex, usize>,
+    /// cached active cue text
+    cached_cue : String,
+    /// cue verification replies still expected (show mode and current cue)
+    awaiting_cue : usize,
+}
+
+impl WarmStart {
+    /// Restore `snapshot` into `console`, returning the verification tracker
+    /// and the (address mapped) requests that start the verification sync
+    pub fn begin(console : &mut X32Console, snapshot : &Snapshot) -> (Self, Vec<Buffer>) {
+        console.restore(snapshot);
+
+        let warm = Self {
+            cached : snapshot.faders.iter().map(|v| (v.source.clone(), v.clone())).collect(),
+            awaiting : console.dialect.faders().map(|v| { let count = v.get_x32_update().len(); (v, count) }).collect(),
+            cached_cue : console.active_cue(),
+            awaiting_cue : 2,
+        };
+
+        (warm, console.full_update())
+    }
+
+    /// Process console data, suppressing results that confirm the cached state
+    pub fn process<T: ConsoleInput>(&mut self, console : &mut X32Console, v : T) -> X32ProcessResult {
+        let result = console.process(v);
+
+        match &result {
+            X32ProcessResult::Fader((fader, _)) => {
+                let Some(awaiting) = self.awaiting.get_mut(fader.source()) else { return result };
+                *awaiting -= 1;
+                if *awaiting == 0 { self.awaiting.remove(fader.source()); }
+
+                if self.cached.get(fader.source()) == Some(&FaderState::from(fader)) {
+                    X32ProcessResult::NoOperation
+                } else {
+                    result
+                }
+            },
+            X32ProcessResult::CurrentCue(cue) if self.awaiting_cue > 0 => {
+                self.awaiting_cue -= 1;
+
+                if *cue == self.cached_cue { X32ProcessResult::NoOperation } else { result }
+            },
+            _ => result,
+        }
+    }
+
+    /// Every verification reply has been seen
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.awaiting.is_empty() && self.awaiting_cue == 0
+    }
+
+    /// Faders still waiting for a verification reply
+    pub fn pending(&self) -> impl Iterator<Item = &FaderIndex> {
+        self.awaiting.keys()
+    }
+}
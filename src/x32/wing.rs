@@ -0,0 +1,114 @@
+use crate::enums::{Error, Fader, FaderIndex, X32Error};
+use crate::osc::{Buffer, Message, Type};
+use super::updates::FaderUpdate;
+use super::{ConsoleMessage, ConsoleRequest};
+
+/// Wing parameter address for a fader, e.g. `/ch/1/fdr`
+fn wing_address(index : &FaderIndex, parameter : &str) -> Option<String> {
+    let bank = match index {
+        FaderIndex::Channel(_) => "ch",
+        FaderIndex::Aux(_) => "aux",
+        FaderIndex::Bus(_) => "bus",
+        FaderIndex::Matrix(_) => "mtx",
+        FaderIndex::Main(_) => "main",
+        FaderIndex::Dca(_) => "dca",
+        FaderIndex::Unknown => return None,
+    };
+    Some(format!("/{bank}/{}/{parameter}", index.get_index()))
+}
+
+/// Value of a Wing reply
+///
+/// Replies to a query are `,sff` - display text, normalized position, and
+/// value - while changes pushed to subscribers carry just the value.  The
+/// last numeric argument wins, falling back to the display text
+/// (`-oo` is the bottom of a fader)
+fn wing_value(msg : &Message) -> Option<f32> {
+    #[expect(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    let numeric = msg.args.iter().rev().find_map(|v| match v {
+        Type::Float(v) => Some(*v),
+        Type::Double(v) => Some(*v as f32),
+        Type::Integer(v) => Some(*v as f32),
+        _ => None,
+    });
+
+    numeric.or_else(|| match msg.args.first() {
+        Some(Type::String(v)) if v.trim() == "-oo" => Some(f32::NEG_INFINITY),
+        Some(Type::String(v)) => v.trim().parse::<f32>().ok(),
+        _ => None,
+    })
+}
+
+impl ConsoleMessage {
+    /// Match a Behringer Wing message - fader level (`fdr`, in dB), mute
+    /// (`mute`), and name (`name`) of channels, aux inputs, buses, matrices,
+    /// mains, and DCAs
+    ///
+    /// The Wing has more strips than the X32 banks tracked by the crate -
+    /// channels 33 - 40, matrices 7 - 8, mains 3 - 4, and DCAs 9 - 16 are
+    /// rejected with [`X32Error::InvalidFader`]
+    ///
+    /// # Errors
+    /// - [`X32Error::UnimplementedPacket`] for anything else
+    /// - [`X32Error::MalformedPacket`] for missing or unreadable values
+    pub fn try_from_wing(msg : &Message) -> Result<Self, Error> {
+        let (bank, index, parameter, rest) = Self::split_address(&msg.address);
+
+        let bank = match bank {
+            "ch" | "bus" | "mtx" | "main" | "dca" => bank,
+            "aux" => "auxin",
+            _ => return Err(Error::X32(X32Error::UnimplementedPacket)),
+        };
+        if !rest.is_empty() || !matches!(parameter, "fdr" | "mute" | "name") {
+            return Err(Error::X32(X32Error::UnimplementedPacket));
+        }
+
+        let source = FaderIndex::from_parts(bank, index)?;
+        let update = match parameter {
+            "fdr" => {
+                let db = wing_value(msg).ok_or(Error::X32(X32Error::MalformedPacket))?;
+                FaderUpdate { level : Some(Fader::level_from_db(db)), level_db : Some(db), ..FaderUpdate::default() }
+            },
+            "mute" => FaderUpdate {
+                is_on : Some(wing_value(msg).ok_or(Error::X32(X32Error::MalformedPacket))? < 0.5_f32),
+                ..FaderUpdate::default()
+            },
+            _ => FaderUpdate {
+                label : Some(msg.first_default(String::new())),
+                ..FaderUpdate::default()
+            },
+        };
+
+        Ok(Self::Fader(FaderUpdate { source, ..update }))
+    }
+}
+
+impl ConsoleRequest {
+    /// Buffers for this request in the Wing dialect
+    ///
+    /// Only fader queries and level, mute, and name changes have a Wing
+    /// equivalent, and [`ConsoleRequest::KeepAlive`] sends the `/*S`
+    /// subscription - everything else encodes to nothing
+    #[must_use]
+    pub fn encode_wing(self) -> Vec<Buffer> {
+        let messages:Vec<Message> = match self {
+            Self::KeepAlive() => vec![Message::new("/*S")],
+            Self::Fader(index) => ["fdr", "mute", "name"].iter()
+                .filter_map(|v| wing_address(&index, v))
+                .map(|v| Message::new(&v))
+                .collect(),
+            Self::SetLevel(index, level) => wing_address(&index, "fdr")
+                .map(|v| Message::builder(&v).float(Fader::level_to_db(level.get())).build())
+                .into_iter().collect(),
+            Self::SetMute(index, is_muted) => wing_address(&index, "mute")
+                .map(|v| Message::builder(&v).int(i32::from(is_muted)).build())
+                .into_iter().collect(),
+            Self::SetLabel(index, label) => wing_address(&index, "name")
+                .map(|v| Message::new_with_string(&v, &label))
+                .into_iter().collect(),
+            _ => vec![],
+        };
+
+        messages.into_iter().filter_map(|v| Buffer::try_from(v).ok()).collect()
+    }
+}
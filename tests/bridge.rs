@@ -0,0 +1,62 @@
+#![cfg(feature = "bridge")]
+use std::time::Duration;
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::tungstenite::Message;
+use x32_osc_state::{bridge, osc, X32Console, X32ProcessResult};
+use x32_osc_state::enums::FaderChange;
+
+#[test]
+fn result_frames() {
+    let mut console = X32Console::new();
+
+    let result = console.process(osc::Message::builder("/ch/02/mix/fader").float(0.75).build());
+    let frame:serde_json::Value = serde_json::from_str(&bridge::to_json(&result).expect("fader frame")).expect("valid json");
+    assert_eq!(frame["type"], "fader");
+    assert_eq!(frame["changed"], serde_json::json!(["level"]));
+    assert_eq!(frame["fader"]["source"]["name"], "Ch02");
+
+    let repeat = console.process(osc::Message::builder("/ch/02/mix/fader").float(0.75).build());
+    assert!(matches!(repeat, X32ProcessResult::Fader((_, change)) if change == FaderChange::NONE));
+    assert_eq!(bridge::to_json(&repeat), None);
+
+    let frame:serde_json::Value = serde_json::from_str(&bridge::to_json(&X32ProcessResult::Solo(true)).expect("solo frame")).expect("valid json");
+    assert_eq!(frame, serde_json::json!({ "type" : "solo", "active" : true }));
+
+    let frame:serde_json::Value = serde_json::from_str(&bridge::snapshot_json(&console.snapshot()).expect("snapshot frame")).expect("valid json");
+    assert_eq!(frame["type"], "snapshot");
+    assert!(frame["state"].is_object());
+}
+
+#[tokio::test]
+async fn bridge_round_trip() {
+    let server = bridge::Bridge::bind("127.0.0.1:0".parse().expect("address")).await.expect("bind bridge");
+    let mut console = X32Console::new();
+    server.set_snapshot(&console.snapshot());
+
+    let url = format!("ws://{}", server.local_addr());
+    let (mut socket, _) = tokio_tungstenite::connect_async(url).await.expect("connect");
+
+    let next = async |socket : &mut tokio_tungstenite::WebSocketStream<_>| -> serde_json::Value {
+        loop {
+            let frame = tokio::time::timeout(Duration::from_secs(5), socket.next()).await.expect("frame received").expect("open").expect("valid frame");
+            if let Message::Text(text) = frame { return serde_json::from_str(&text).expect("valid json") }
+        }
+    };
+
+    assert_eq!(next(&mut socket).await["type"], "snapshot");
+
+    while server.subscribers() == 0 { tokio::task::yield_now().await; }
+    server.publish(&X32ProcessResult::NoOperation);
+    server.publish(&X32ProcessResult::CurrentCue(String::from("1.0.0 :: Intro")));
+    let result = console.process(osc::Message::builder("/dca/3/on").int(1).build());
+    server.publish(&result);
+
+    let first = next(&mut socket).await;
+    assert_eq!(first, serde_json::json!({ "type" : "cue", "cue" : "1.0.0 :: Intro" }));
+    let second = next(&mut socket).await;
+    assert_eq!(second["type"], "fader");
+    assert_eq!(second["changed"], serde_json::json!(["mute"]));
+
+    socket.send(Message::text("snapshot")).await.expect("request snapshot");
+    assert_eq!(next(&mut socket).await["type"], "snapshot");
+}
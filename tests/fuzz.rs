@@ -0,0 +1,21 @@
+#![cfg(feature = "arbitrary")]
+use x32_osc_state::fuzz::fuzz_target;
+use x32_osc_state::osc;
+
+#[test]
+fn fuzz_target_survives_seeds() {
+    let node:osc::Buffer = osc::Message::builder("node").string("/ch/01/config \"").build().try_into().expect("valid buffer");
+    let bundle:osc::Buffer = osc::Bundle::default().try_into().expect("valid buffer");
+
+    for seed in [
+        Vec::new(),
+        vec![0_u8; 3],
+        b"#bundle\0".to_vec(),
+        b"/ch/01/mix/fader\0\0\0\0,f".to_vec(),
+        node.as_slice().to_vec(),
+        bundle.as_slice().to_vec(),
+        (0..=255).collect(),
+    ] {
+        fuzz_target(&seed);
+    }
+}
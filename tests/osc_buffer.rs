@@ -1,3 +1,4 @@
+#![expect(clippy::unwrap_used)]
 use x32_osc_state::osc::Buffer;
 use x32_osc_state::enums::{Error, PacketError, OSCError, X32Error};
 
@@ -69,7 +70,7 @@ fn arbitrary_bytes() {
 
 #[test]
 fn error_type_check() {
-    let empty_byte:Buffer = Default::default();
+    let empty_byte = Buffer::default();
     let three_byte = Buffer::from(rnd_buffer(3));
     let four_byte = Buffer::from(rnd_buffer(4));
     let unterminated_string = Buffer::from(rnd_buffer(4));
@@ -136,4 +137,28 @@ fn error_source() {
     assert_eq!(crate::Error::X32(X32Error::InvalidFader).source().unwrap().to_string(), "invalid fader");
     assert_eq!(crate::Error::Packet(PacketError::InvalidBuffer).source().unwrap().to_string(), "buffer contains invalid data");
 
-}
\ No newline at end of file
+}
+#[test]
+fn hex_dump_and_json() {
+    let mut msg = x32_osc_state::osc::Message::new("/ch/01/mix/on");
+    msg.add_item(1_i32);
+    let buffer = Buffer::try_from(msg).unwrap();
+
+    assert_eq!(
+        format!("{buffer:x}"),
+        "00000000  2f 63 68 2f 30 31 2f 6d  69 78 2f 6f 6e 00 00 00  |/ch/01/mix/on...|\n\
+         00000010  2c 69 00 00 00 00 00 01                           |,i......|\n"
+    );
+    assert_eq!(format!("{:x}", Buffer::default()), "");
+
+    #[cfg(feature = "serde")]
+    {
+        let json:serde_json::Value = serde_json::from_str(&buffer.to_json()).unwrap();
+        assert_eq!(json["length"], 24);
+        assert_eq!(json["hex"], "2f63682f30312f6d69782f6f6e0000002c69000000000001");
+        assert_eq!(json["decoded"], "|s:/ch/01/mix/on•••[16]||,:,i••[4]||i:1|");
+
+        let json:serde_json::Value = serde_json::from_str(&Buffer::from(vec![0x1, 0x2]).to_json()).unwrap();
+        assert_eq!(json["decoded"], serde_json::Value::Null);
+    }
+}
@@ -1,4 +1,5 @@
-use x32_osc_state::osc::{Buffer, Packet, Bundle, Message, Type};
+#![expect(clippy::unwrap_used)]
+use x32_osc_state::osc::{Buffer, BufferWriter, Packet, Bundle, Message, Type, TimeTag, DecodeOptions};
 use x32_osc_state::enums::{Error, PacketError};
 
 #[test]
@@ -44,12 +45,12 @@ fn single_message() {
 
     assert_eq!(re_read, data);
 
-    match bundle.messages.get(0).unwrap() {
+    match bundle.messages.first().unwrap() {
         Packet::Message(msg) => {
-            let arg_1 = msg.args.get(0).expect("no args");
+            let arg_1 = msg.args.first().expect("no args");
             assert_eq!(<Type as TryInto<i32>>::try_into(arg_1.clone()), Ok(23_i32));
         },
-        _ => { panic!("wrong payload")}
+        Packet::Bundle(_) => { panic!("wrong payload")}
     }
 }
 
@@ -178,12 +179,12 @@ fn single_message_one_step() {
 
     assert_eq!(re_read, data);
 
-    match bundle.messages.get(0).unwrap() {
+    match bundle.messages.first().unwrap() {
         Packet::Message(msg) => {
-            let arg_1 = msg.args.get(0).expect("no args");
+            let arg_1 = msg.args.first().expect("no args");
             assert_eq!(<Type as TryInto<i32>>::try_into(arg_1.clone()), Ok(23_i32));
         },
-        _ => { panic!("wrong payload")}
+        Packet::Bundle(_) => { panic!("wrong payload")}
     }
 }
 
@@ -203,4 +204,136 @@ fn single_message_bad_message() {
     let buffer = Buffer::try_from(data.clone());
 
     assert_eq!(buffer.unwrap_err(), Error::Packet(PacketError::InvalidMessage));
-}
\ No newline at end of file
+}
+#[test]
+fn flatten_nested_bundles() {
+    let mut inner = Bundle::new_with_future(5000);
+    inner.add(Message::new("/inner/1"));
+    inner.add(Bundle::new());
+    inner.add(Message::new("/inner/2"));
+
+    let mut outer = Bundle::new();
+    outer.add(Message::new("/outer/1"));
+    outer.add(inner.clone());
+    outer.add(Message::new("/outer/2"));
+
+    let outer_time = outer.time;
+    let packet = Packet::Bundle(outer);
+
+    let addresses:Vec<&str> = packet.messages().map(|m| m.address.as_str()).collect();
+    assert_eq!(addresses, vec!["/outer/1", "/inner/1", "/inner/2", "/outer/2"]);
+
+    let times:Vec<_> = packet.messages_with_time().map(|(t, _)| t).collect();
+    assert_eq!(times, vec![Some(outer_time), Some(inner.time), Some(inner.time), Some(outer_time)]);
+
+    let single = Packet::Message(Message::new("/single"));
+    let mut iter = single.messages_with_time();
+    assert_eq!(iter.next().map(|(t, m)| (t, m.address.as_str())), Some((None, "/single")));
+    assert!(iter.next().is_none());
+
+    assert_eq!(Packet::Bundle(Bundle::new()).messages().count(), 0);
+}
+
+#[test]
+fn decode_limits() {
+    let mut nested = Bundle::new();
+    nested.add(Message::new("/deep"));
+
+    for _ in 0..20 {
+        let mut outer = Bundle::new();
+        outer.add(nested);
+        nested = outer;
+    }
+
+    let buffer = Buffer::try_from(nested).expect("valid bundle");
+
+    assert_eq!(Packet::try_from(buffer.clone()), Err(Error::Packet(PacketError::LimitExceeded)));
+
+    let options = DecodeOptions { max_depth : 32, ..DecodeOptions::default() };
+    let packet = Packet::decode(buffer, &options).expect("within limit");
+    assert_eq!(packet.messages().count(), 1);
+
+    let mut wide = Bundle::new();
+    for _ in 0..5 { wide.add(Message::new("/wide")); }
+    let buffer = Buffer::try_from(wide).expect("valid bundle");
+
+    let options = DecodeOptions { max_elements : 4, ..DecodeOptions::default() };
+    assert_eq!(Packet::decode(buffer.clone(), &options), Err(Error::Packet(PacketError::LimitExceeded)));
+
+    let options = DecodeOptions { max_elements : 5, ..DecodeOptions::default() };
+    assert!(Packet::decode(buffer, &options).is_ok());
+
+    let mut args = Message::new("/args");
+    for v in 0..5 { args.add_item(v); }
+    let buffer = Buffer::try_from(args).expect("valid message");
+
+    let options = DecodeOptions { max_elements : 4, ..DecodeOptions::default() };
+    assert_eq!(Packet::decode(buffer, &options), Err(Error::Packet(PacketError::LimitExceeded)));
+
+    let mut huge_block = x32_osc_state::enums::BUNDLE_TAG.to_vec();
+    huge_block.extend([0_u8, 0, 0, 0, 0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF]);
+    let huge_block = Buffer::from(huge_block);
+    assert_eq!(Packet::try_from(huge_block), Err(Error::Packet(PacketError::InvalidBuffer)));
+}
+
+#[test]
+fn writer_matches_encoded_len() {
+    let msg = Message::builder("/ch/01/config/name")
+        .string("Lead Vox")
+        .int(1)
+        .double(0.5)
+        .blob(vec![1, 2, 3])
+        .time(TimeTag::IMMEDIATE)
+        .bool(true)
+        .build();
+    let empty = Message::builder("/xinfo").force_empty_args().build();
+
+    let mut inner = Bundle::new();
+    inner.add(empty.clone());
+
+    let mut bundle = Bundle::new();
+    bundle.add(msg.clone());
+    bundle.add(inner);
+
+    for packet in [Packet::from(msg), Packet::from(empty), Packet::from(bundle)] {
+        let mut writer = BufferWriter::with_capacity(packet.encoded_len());
+        writer.write_packet(&packet).expect("valid packet");
+
+        let buffer = writer.finish();
+        assert_eq!(buffer.len(), packet.encoded_len());
+        assert_eq!(Packet::try_from(buffer).expect("round trip"), packet);
+    }
+
+    let mut bad = Bundle::new();
+    bad.add(Message::new(""));
+
+    let mut writer = BufferWriter::new();
+    assert!(writer.write_bundle(&bad).is_err());
+    assert!(writer.is_empty());
+}
+
+#[test]
+fn bundle_packing() {
+    let packets:Vec<Packet> = (1..=32)
+        .map(|v| Message::new(&format!("/ch/{v:02}/config/name")).into())
+        .collect();
+
+    let packed = Bundle::pack(packets.clone(), 256);
+    assert!(packed.len() > 1);
+    assert!(packed.len() < packets.len());
+    for packet in &packed {
+        assert!(packet.encoded_len() <= 256);
+        let buffer = Buffer::try_from(packet.clone()).expect("valid packet");
+        assert_eq!(buffer.len(), packet.encoded_len());
+        assert!(matches!(packet, Packet::Bundle(v) if v.time.is_immediate()));
+    }
+
+    let unpacked:Vec<&Message> = packed.iter().flat_map(Packet::messages).collect();
+    let original:Vec<&Message> = packets.iter().flat_map(Packet::messages).collect();
+    assert_eq!(unpacked, original);
+
+    // a lone (or oversized) packet is not wrapped
+    assert_eq!(Bundle::pack(packets[..1].to_vec(), 256), packets[..1]);
+    assert_eq!(Bundle::pack(packets[..2].to_vec(), 8), packets[..2]);
+    assert!(Bundle::pack(vec![], 256).is_empty());
+}
@@ -1,5 +1,6 @@
-use x32_osc_state::osc::{Buffer, Type, Message, Packet};
-use x32_osc_state::enums::{Error, PacketError};
+#![expect(clippy::unwrap_used)]
+use x32_osc_state::osc::{Buffer, Type, Message, Packet, DecodeOptions, TextDecoding};
+use x32_osc_state::enums::{Error, PacketError, OSCError};
 use chrono::DateTime;
 use std::time::SystemTime;
 
@@ -10,7 +11,7 @@ fn address_only() {
     let osc_packet = Message::new("/hello");
     let expected_buffer = Buffer::from(vec![
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
-    ]).into();
+    ]);
 
     let actual_buffer:Buffer = osc_packet.clone().try_into().expect("buffer pack failed");
 
@@ -29,7 +30,7 @@ fn address_only_force_empty_list() {
     let expected_buffer = Buffer::from(vec![
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', C_NULL, C_NULL, C_NULL
-    ]).into();
+    ]);
 
     osc_packet.force_empty_args = true;
 
@@ -61,7 +62,7 @@ fn single_add_by_primitive_string() {
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', 's', C_NULL, C_NULL,
         'w', 'o', 'r', 'l', 'd', C_NULL, C_NULL, C_NULL
-    ]).into();
+    ]);
 
     osc_packet.add_item(String::from("world"));
 
@@ -73,7 +74,7 @@ fn single_add_by_primitive_string() {
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_ok());
-    assert_eq!(osc_packet, re_pack.unwrap())
+    assert_eq!(osc_packet, re_pack.unwrap());
 }
 
 #[test]
@@ -82,7 +83,7 @@ fn all_null_types() {
     let expected_buffer = Buffer::from(vec![
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', 'I', 'N', 'T', 'F', C_NULL, C_NULL, C_NULL,
-    ]).into();
+    ]);
 
     osc_packet.add_item(Type::Bang());
     osc_packet.add_item(Type::Null());
@@ -97,7 +98,7 @@ fn all_null_types() {
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_ok());
-    assert_eq!(osc_packet, re_pack.unwrap())
+    assert_eq!(osc_packet, re_pack.unwrap());
 }
 
 #[test]
@@ -109,7 +110,7 @@ fn all_number_types() {
         C_NULL, C_NULL, C_NULL, char::from(23),
         C_NULL, C_NULL, C_NULL, C_NULL, C_NULL, C_NULL, C_NULL, char::from(23),
         C_NULL, C_NULL, C_NULL, 'x',
-    ]).into();
+    ]);
 
     let num_float = (69.69_f32).to_be_bytes();
     let num_double = (69.69_f64).to_be_bytes();
@@ -131,7 +132,7 @@ fn all_number_types() {
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_ok());
-    assert_eq!(osc_packet, re_pack.unwrap())
+    assert_eq!(osc_packet, re_pack.unwrap());
 }
 
 
@@ -146,10 +147,10 @@ fn complex_numbers() {
     let mut expected_buffer:Buffer = Buffer::from(vec![
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', 't', 'r', C_NULL,
-    ]).into();
+    ]);
 
-    let num_s = (3165615030_u32).to_be_bytes();
-    let num_f = (536870912_u32).to_be_bytes();
+    let num_s = (3_165_615_030_u32).to_be_bytes();
+    let num_f = (536_870_912_u32).to_be_bytes();
 
     expected_buffer.extend(&Buffer::from(num_s.to_vec()));
     expected_buffer.extend(&Buffer::from(num_f.to_vec()));
@@ -158,7 +159,7 @@ fn complex_numbers() {
     expected_buffer.extend(&Buffer::from(color.clone().to_vec()));
 
     osc_packet.add_item(time_arg);
-    osc_packet.add_item(color.clone());
+    osc_packet.add_item(color);
 
     let actual_buffer:Buffer = osc_packet.clone().try_into().expect("buffer pack failed");
 
@@ -168,7 +169,7 @@ fn complex_numbers() {
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_ok());
-    assert_eq!(osc_packet, re_pack.unwrap())
+    assert_eq!(osc_packet, re_pack.unwrap());
 }
 
 #[test]
@@ -176,7 +177,7 @@ fn decode_unknown_type() {
     let buffer:Buffer = Buffer::from(vec![
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', 'x', C_NULL, C_NULL,
-    ]).into();
+    ]);
 
     let osc_packet:Result<Message, _> = buffer.try_into();
 
@@ -191,7 +192,7 @@ fn invalid_buffer() {
     let decode:Result<Message, _> = buffer.try_into();
 
     assert!(decode.is_err());
-    assert_eq!(decode, Err(Error::Packet(PacketError::NotFourByte)))
+    assert_eq!(decode, Err(Error::Packet(PacketError::NotFourByte)));
 }
 
 
@@ -242,7 +243,7 @@ fn single_send_blob() {
         C_NULL, C_NULL, C_NULL, char::from(6),
         'A', 'B', 'C', 'D', 'E', 'F', C_NULL, C_NULL,
         C_NULL, C_NULL, C_NULL, char::from(23)
-    ]).into();
+    ]);
 
     let data_buffer = vec![0x41_u8, 0x42, 0x43, 0x44, 0x45, 0x46];
 
@@ -257,7 +258,7 @@ fn single_send_blob() {
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_ok());
-    assert_eq!(osc_packet, re_pack.unwrap())
+    assert_eq!(osc_packet, re_pack.unwrap());
 }
 
 
@@ -268,7 +269,7 @@ fn empty_send_blob() {
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', 'b', C_NULL, C_NULL,
         C_NULL, C_NULL, C_NULL, char::from(0),
-    ]).into();
+    ]);
 
     let data_buffer = vec![];
 
@@ -282,7 +283,7 @@ fn empty_send_blob() {
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_ok());
-    assert_eq!(osc_packet, re_pack.unwrap())
+    assert_eq!(osc_packet, re_pack.unwrap());
 }
 
 
@@ -292,10 +293,78 @@ fn decode_blob_buffer_underrun() {
         '/', 'h', 'e', 'l', 'l', 'o', C_NULL, C_NULL,
         ',', 'b', C_NULL, C_NULL,
         C_NULL, C_NULL, C_NULL, char::from(3),
-    ]).into();
+    ]);
 
     let re_pack:Result<Message, _> = expected_buffer.clone().try_into();
 
     assert!(re_pack.is_err());
     assert_eq!(re_pack, Err(Error::Packet(PacketError::InvalidTypesForMessage)));
-}
\ No newline at end of file
+}
+#[test]
+fn builder_matches_add_item() {
+    let mut expected = Message::new("/hello");
+    expected.add_item(1_i32);
+    expected.add_item(String::from("x"));
+    expected.add_item(0.5_f32);
+    expected.add_item(Type::Blob(vec![1, 2]));
+    expected.add_item(true);
+
+    let built = Message::builder("/hello")
+        .int(1)
+        .string("x")
+        .float(0.5)
+        .blob(vec![1, 2])
+        .bool(true)
+        .build();
+
+    assert_eq!(built, expected);
+    assert_eq!(x32_osc_state::msg!("/hello", 1_i32, "x", 0.5_f32, Type::Blob(vec![1, 2]), true), expected);
+
+    let empty = Message::builder("/hello").force_empty_args().build();
+    assert!(empty.force_empty_args);
+    assert_eq!(x32_osc_state::msg!("/hello"), Message::new("/hello"));
+}
+
+#[test]
+fn decode_latin1_string() {
+    let buffer = Buffer::from(vec![
+        0x2f, 0x61, 0x0, 0x0,
+        0x2c, 0x73, 0x0, 0x0,
+        0x43, 0x61, 0x66, 0xe9, 0x0, 0x0, 0x0, 0x0,
+    ]);
+
+    let strict:Result<Message, _> = buffer.clone().try_into();
+    assert_eq!(strict, Err(Error::Packet(PacketError::InvalidTypesForMessage)));
+
+    let options = DecodeOptions { text: TextDecoding::Latin1, ..DecodeOptions::default() };
+    let latin = Message::decode(buffer.clone(), &options).expect("latin-1 decode failed");
+    assert_eq!(latin.args, vec![Type::String(String::from("Café"))]);
+
+    let options = DecodeOptions { text: TextDecoding::Utf8Lossy, ..DecodeOptions::default() };
+    let lossy = Message::decode(buffer, &options).expect("lossy decode failed");
+    assert_eq!(lossy.args, vec![Type::String(String::from("Caf\u{fffd}"))]);
+}
+
+#[test]
+fn typed_argument_accessors() {
+    let msg = Message::builder("/xinfo")
+        .string("192.168.1.77")
+        .int(23)
+        .float(0.5)
+        .blob(vec![1, 2, 3])
+        .bool(true)
+        .long(-5)
+        .build();
+
+    assert_eq!(msg.get_str(0), Ok("192.168.1.77"));
+    assert_eq!(msg.get_int(1), Ok(23));
+    assert_eq!(msg.get_float(2), Ok(0.5));
+    assert_eq!(msg.get_blob(3), Ok([1_u8, 2, 3].as_slice()));
+    assert_eq!(msg.get_bool(4), Ok(true));
+    assert_eq!(msg.get_long(5), Ok(-5));
+
+    assert_eq!(msg.get_int(0), Err(Error::OSC(OSCError::InvalidTypeConversion)));
+    assert_eq!(msg.get_float(1), Err(Error::OSC(OSCError::InvalidTypeConversion)));
+    assert_eq!(msg.get_str(6), Err(Error::OSC(OSCError::MissingArgument)));
+    assert_eq!(msg.get(6), Err(Error::OSC(OSCError::MissingArgument)));
+}
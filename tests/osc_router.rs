@@ -0,0 +1,84 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use x32_osc_state::osc::{address_matches, Bundle, Message, Packet, Router, TimeTag};
+
+#[test]
+fn pattern_matching() {
+    assert!(address_matches("/ch/01/mix/fader", "/ch/01/mix/fader"));
+    assert!(address_matches("/node", "node"));
+    assert!(address_matches("/ch/*/mix/fader", "/ch/12/mix/fader"));
+    assert!(address_matches("/ch/0?/mix/*", "/ch/05/mix/on"));
+    assert!(address_matches("/ch/[0-3][0-9]/mix/on", "/ch/32/mix/on"));
+    assert!(address_matches("/{ch,bus}/01/mix/on", "/bus/01/mix/on"));
+    assert!(address_matches("/ch/[!0]?/mix/on", "/ch/12/mix/on"));
+
+    assert!(!address_matches("/ch/*", "/ch/01/mix/fader"));
+    assert!(!address_matches("/ch/0?/mix/on", "/ch/12/mix/on"));
+    assert!(!address_matches("/{ch,bus}/01/mix/on", "/mtx/01/mix/on"));
+    assert!(!address_matches("/ch/[!0]?/mix/on", "/ch/05/mix/on"));
+    assert!(!address_matches("/ch/[0-3/mix/on", "/ch/0/mix/on"));
+}
+
+#[test]
+fn dispatch_message_and_nested_bundles() {
+    let seen = Rc::new(RefCell::new(vec![]));
+    let faders = Rc::new(RefCell::new(0));
+
+    let mut router = Router::new();
+
+    let seen_handler = Rc::clone(&seen);
+    let fader_handler = Rc::clone(&faders);
+
+    router
+        .add("/*/*/mix/*", move |msg: &Message| seen_handler.borrow_mut().push(msg.address.clone()))
+        .add("/ch/*/mix/fader", move |_: &Message| *fader_handler.borrow_mut() += 1);
+
+    let mut inner = Bundle::new();
+    inner.add(Message::new("/ch/02/mix/fader"));
+    inner.add(Message::new("/xremote"));
+
+    let mut outer = Bundle::new();
+    outer.add(Message::new("/ch/01/mix/fader"));
+    outer.add(Message::new("/bus/01/mix/on"));
+    outer.add(inner);
+
+    let calls = router.dispatch(&Packet::Bundle(outer));
+
+    assert_eq!(calls, 5);
+    assert_eq!(*faders.borrow(), 2);
+    assert_eq!(*seen.borrow(), vec!["/ch/01/mix/fader", "/bus/01/mix/on", "/ch/02/mix/fader"]);
+
+    assert_eq!(router.dispatch(&Packet::Message(Message::new("/unmatched"))), 0);
+}
+
+#[test]
+fn dispatch_honors_time_tags() {
+    let count = Rc::new(RefCell::new(0));
+    let handler = Rc::clone(&count);
+
+    let mut router = Router::new();
+    router
+        .honor_time_tags(true)
+        .add("/hello", move |_: &Message| *handler.borrow_mut() += 1);
+
+    let mut bundle = Bundle::new_with_future(60_000);
+    bundle.add(Message::new("/hello"));
+
+    let now = TimeTag::now();
+    assert_eq!(router.dispatch_at(&Packet::Bundle(bundle.clone()), now), 0);
+    assert_eq!(router.pending_len(), 1);
+
+    assert_eq!(router.dispatch_pending(now), 0);
+    assert_eq!(router.pending_len(), 1);
+
+    assert_eq!(router.dispatch_pending(bundle.time), 1);
+    assert_eq!(router.pending_len(), 0);
+    assert_eq!(*count.borrow(), 1);
+
+    let mut immediate = Router::new();
+    let handler = Rc::clone(&count);
+    immediate.add("/hello", move |_: &Message| *handler.borrow_mut() += 1);
+
+    assert_eq!(immediate.dispatch(&Packet::Bundle(bundle)), 1);
+    assert_eq!(*count.borrow(), 2);
+}
@@ -0,0 +1,33 @@
+use x32_osc_state::osc::{Bundle, Message, Packet, Scheduler, TimeTag};
+
+#[test]
+fn release_in_time_order() {
+    let now = TimeTag::now();
+
+    let mut late = Bundle::new_with_future(2_000);
+    late.add(Message::new("/late"));
+
+    let mut early = Bundle::new_with_future(1_000);
+    early.add(Message::new("/early"));
+    early.add(Message::new("/early/two"));
+
+    let mut scheduler = Scheduler::new();
+    assert!(scheduler.is_empty());
+
+    scheduler.add(late.clone());
+    scheduler.add(early.clone());
+    scheduler.add_packet(&Packet::Message(Message::new("/now")));
+
+    assert_eq!(scheduler.len(), 4);
+    assert_eq!(scheduler.next_due(), Some(TimeTag::IMMEDIATE));
+
+    let due : Vec<String> = scheduler.due(now).into_iter().map(|m| m.address).collect();
+    assert_eq!(due, vec!["/now"]);
+    assert_eq!(scheduler.next_due(), Some(early.time));
+
+    let due : Vec<String> = scheduler.due(late.time).into_iter().map(|m| m.address).collect();
+    assert_eq!(due, vec!["/early", "/early/two", "/late"]);
+
+    assert!(scheduler.is_empty());
+    assert_eq!(scheduler.next_due(), None);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "serde")]
+use x32_osc_state::osc::{Bundle, Message, Packet, TimeTag, Type};
+
+#[test]
+fn message_json_round_trip() {
+    let msg = Message::builder("/ch/01/mix/fader")
+        .float(0.75)
+        .string("hello")
+        .blob(vec![1, 2, 3])
+        .bool(true)
+        .build();
+
+    let json = serde_json::to_string(&msg).expect("serializes");
+    assert_eq!(
+        json,
+        r#"{"address":"/ch/01/mix/fader","args":[{"Float":0.75},{"String":"hello"},{"Blob":[1,2,3]},{"Boolean":true}],"force_empty_args":false}"#
+    );
+
+    let back:Message = serde_json::from_str(&json).expect("deserializes");
+    assert_eq!(back, msg);
+}
+
+#[test]
+fn packet_fixture_replay() {
+    let fixture = r#"{"Bundle":{"time":{"seconds":0,"fractional":1},"messages":[
+        {"Message":{"address":"/xremote","args":[]}},
+        {"Message":{"address":"/ch/02/mix/on","args":[{"Integer":1}]}}
+    ]}}"#;
+
+    let packet:Packet = serde_json::from_str(fixture).expect("deserializes");
+
+    let mut expected = Bundle::new();
+    expected.time = TimeTag::IMMEDIATE;
+    expected.add(Message::new("/xremote"));
+    expected.add(Message::builder("/ch/02/mix/on").int(1).build());
+
+    assert_eq!(packet, Packet::Bundle(expected));
+    assert_eq!(serde_json::to_string(&Type::Null()).expect("serializes"), r#"{"Null":[]}"#);
+}
@@ -1,3 +1,4 @@
+#![expect(clippy::unwrap_used)]
 use x32_osc_state::enums::{Error, OSCError, PacketError};
 use x32_osc_state::osc::{Buffer, Type};
 use chrono::DateTime;
@@ -113,7 +114,7 @@ fn encode_unknown_type() {
 
 #[test]
 fn cast_default_type() {
-    let osc_type:Type = Default::default();
+    let osc_type = Type::default();
 
     assert_eq!(osc_type, Type::Unknown());
 }
@@ -249,7 +250,7 @@ fn blob_type_short_twelve() {
     let re_pack = Type::try_from_vec(&expect_buffer, 'b');
 
     assert!(re_pack.is_err());
-    assert_eq!(re_pack, Err(Error::Packet(PacketError::Underrun)))
+    assert_eq!(re_pack, Err(Error::Packet(PacketError::Underrun)));
 }
 
 
@@ -263,3 +264,16 @@ fn blob_type_empty() {
     assert_eq!(re_pack, Err(Error::Packet(PacketError::Underrun)));
 }
 
+
+#[test]
+fn lossy_numeric_coercion() {
+    assert_eq!(Type::Integer(2).as_f32_lossy(), Some(2.0));
+    assert_eq!(Type::Double(0.5).as_f32_lossy(), Some(0.5));
+    assert_eq!(Type::Boolean(true).as_f32_lossy(), Some(1.0));
+    assert_eq!(Type::String(String::from("1")).as_f32_lossy(), None);
+
+    assert_eq!(Type::Float(0.6).as_i32_lossy(), Some(1));
+    assert_eq!(Type::LongInteger(i64::MAX).as_i32_lossy(), Some(i32::MAX));
+    assert_eq!(Type::Boolean(false).as_i32_lossy(), Some(0));
+    assert_eq!(Type::Null().as_i32_lossy(), None);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "test-support")]
+use std::time::Duration;
+use x32_osc_state::test_support::{TrafficGenerator, TrafficMix};
+use x32_osc_state::{X32Console, X32ProcessResult};
+
+#[test]
+fn simulated_hour_without_drift() {
+    let mut console = X32Console::new();
+    let mut traffic = TrafficGenerator::new(0x5eed);
+    let mut meters = 0;
+
+    for (_, buffer) in traffic.by_ref().take_while(|(t, _)| *t < Duration::from_secs(3600)) {
+        if matches!(console.process(buffer), X32ProcessResult::Meters(_)) { meters += 1; }
+    }
+
+    assert!(traffic.elapsed() >= Duration::from_secs(3600));
+    assert!(meters > 0);
+    assert!(console.compatibility_report().known > 0);
+    assert_eq!(traffic.drift(&console), vec![]);
+}
+
+#[test]
+fn reproducible_from_seed() {
+    let first:Vec<_> = TrafficGenerator::new(7).take(100).collect();
+    let second:Vec<_> = TrafficGenerator::new(7).take(100).collect();
+
+    assert_eq!(first, second);
+    assert_ne!(first, TrafficGenerator::new(8).take(100).collect::<Vec<_>>());
+}
+
+#[test]
+fn malformed_only() {
+    let mut console = X32Console::new();
+    let mix = TrafficMix { node : 0, standard : 0, meters : 0, malformed : 1 };
+
+    for (_, buffer) in TrafficGenerator::new(1).with_mix(mix).take(5_000) {
+        let result = console.process(buffer.clone());
+        assert!(matches!(result, X32ProcessResult::NoOperation | X32ProcessResult::Unhandled { .. }), "{buffer:?}");
+    }
+}
+
+#[test]
+fn saturated_weights() {
+    let mix = TrafficMix { node : u32::MAX, standard : u32::MAX, meters : u32::MAX, malformed : u32::MAX };
+    assert_eq!(TrafficGenerator::new(3).with_mix(mix).take(1_000).count(), 1_000);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "ui")]
+use x32_osc_state::enums::FaderIndex;
+use x32_osc_state::osc::Message;
+use x32_osc_state::ui::{ConsoleView, ViewModel};
+use x32_osc_state::X32Console;
+
+fn node(line : &str) -> Message {
+    Message::builder("node").string(line).build()
+}
+
+#[test]
+fn console_view_tracks_changes() {
+    let mut console = X32Console::new();
+    console.process(node("/-show/showfile/cue/000 100 \"Intro\" 1 -1 -1 0 1 0 0"));
+
+    let mut view = ConsoleView::new(&console);
+    assert_eq!(view.strips.len(), 72);
+    assert_eq!(view.cues.len(), 1);
+    assert_eq!(view.cues[0].name, "Intro");
+    assert_eq!(view.cues[0].scene, "--");
+    assert!(!view.cues[0].skip);
+
+    let result = console.process(node("/ch/02/config \"Bass\" 1 BL 1"));
+    assert!(view.apply(&result));
+    assert!(!view.apply(&result));
+
+    let strip = view.strip(&FaderIndex::Channel(2)).expect("strip exists");
+    assert_eq!(strip.name, "Bass");
+
+    let result = console.process(node("/-show/prepos/current 0"));
+    assert!(view.apply(&result));
+    assert_eq!(view.active_cue, "Cue: 1.0.0 :: Intro [--] [--]");
+
+    let result = console.process(node("/-show/showfile/cue/001 200 \"Verse\" 1 -1 -1 0 1 0 0"));
+    assert!(view.apply(&result));
+    assert!(view.cues_stale);
+
+    view.sync_cues(&console);
+    assert_eq!(view.current_cue, Some(0));
+    assert_eq!(view.cues.len(), 2);
+    assert!(!view.cues_stale);
+}
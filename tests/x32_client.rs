@@ -0,0 +1,74 @@
+#![cfg(feature = "tokio")]
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use x32_osc_state::{osc, x32, X32Console, X32ProcessResult};
+use x32_osc_state::enums::FaderIndex;
+
+#[tokio::test]
+async fn client_round_trip() {
+    let desk = UdpSocket::bind("127.0.0.1:0").await.expect("bind fake console");
+    let remote = desk.local_addr().expect("fake console address");
+
+    let mut client = Box::pin(x32::Client::connect_with(remote, X32Console::new(), Duration::from_secs(3600))).await.expect("connect");
+
+    let mut buf = [0_u8; 1024];
+    let (len, from) = tokio::time::timeout(Duration::from_secs(5), desk.recv_from(&mut buf)).await.expect("keep alive sent").expect("valid read");
+    let first = osc::Message::try_from(osc::Buffer::from(buf[..len].to_vec())).expect("valid message");
+    assert_eq!(first.address, "/xremote");
+
+    let reply:osc::Buffer = osc::Message::builder("/ch/03/mix/fader").float(0.5).build().try_into().expect("valid buffer");
+    desk.send_to(reply.as_slice(), from).await.expect("send reply");
+
+    let result = tokio::time::timeout(Duration::from_secs(5), client.recv()).await.expect("result received");
+    assert!(matches!(result, Some(X32ProcessResult::Fader((fader, _))) if *fader.source() == FaderIndex::Channel(3)));
+    assert!((client.console().fader(&FaderIndex::Channel(3)).expect("valid fader").level().0 - 0.5).abs() < f32::EPSILON);
+
+    client.send(x32::ConsoleRequest::GoCue(2)).await.expect("send request");
+    loop {
+        let (len, _) = tokio::time::timeout(Duration::from_secs(5), desk.recv_from(&mut buf)).await.expect("request sent").expect("valid read");
+        let msg = osc::Message::try_from(osc::Buffer::from(buf[..len].to_vec())).expect("valid message");
+        if msg.address == "/-action/gocue" { break }
+    }
+}
+
+#[tokio::test]
+async fn result_stream() {
+    let desk = UdpSocket::bind("127.0.0.1:0").await.expect("bind fake console");
+    let socket = UdpSocket::bind("127.0.0.1:0").await.expect("bind local socket");
+    let local = socket.local_addr().expect("local address");
+
+    let mut results = X32Console::new().into_stream(socket);
+
+    let bundle = osc::Bundle::new_with_messages(vec![
+        osc::Message::builder("/ch/01/mix/fader").float(0.5).build(),
+        osc::Message::builder("/-stat/solosw/03").int(1).build(),
+    ]);
+    let bundle = osc::Buffer::try_from(osc::Packet::Bundle(bundle)).expect("valid bundle");
+    desk.send_to(bundle.as_slice(), local).await.expect("send bundle");
+
+    let first = tokio::time::timeout(Duration::from_secs(5), results.next()).await.expect("result received");
+    assert!(matches!(first, Some(X32ProcessResult::Fader((fader, _))) if *fader.source() == FaderIndex::Channel(1)));
+    let second = tokio::time::timeout(Duration::from_secs(5), results.next()).await.expect("result received");
+    assert_eq!(second, Some(X32ProcessResult::Solo(true)));
+
+    let (console, _) = results.into_inner();
+    assert!((console.fader(&FaderIndex::Channel(1)).expect("valid fader").level().0 - 0.5).abs() < f32::EPSILON);
+}
+
+#[tokio::test]
+async fn client_survives_unreachable_console() {
+    let desk = UdpSocket::bind("127.0.0.1:0").await.expect("bind fake console");
+    let remote = desk.local_addr().expect("fake console address");
+    drop(desk);
+
+    // the keep-alive to the closed port comes back as connection refused
+    let mut client = Box::pin(x32::Client::connect_with(remote, X32Console::new(), Duration::from_secs(3600))).await.expect("connect");
+    tokio::time::sleep(Duration::from_millis(250)).await;
+
+    let desk = UdpSocket::bind(remote).await.expect("rebind fake console");
+    let reply:osc::Buffer = osc::Message::builder("/ch/04/mix/fader").float(0.5).build().try_into().expect("valid buffer");
+    desk.send_to(reply.as_slice(), client.local_addr().expect("client address")).await.expect("send reply");
+
+    let result = tokio::time::timeout(Duration::from_secs(5), client.recv()).await.expect("result received");
+    assert!(matches!(result, Some(X32ProcessResult::Fader((fader, _))) if *fader.source() == FaderIndex::Channel(4)));
+}
@@ -8,7 +8,7 @@ use buffer_common::random_data_node;
 
 fn fader_level_mute_test(fader: FaderIndex, level: f32, is_on: bool) {
     let address = match fader {
-        FaderIndex::Dca(_) => format!("{}", fader.get_x32_address()),
+        FaderIndex::Dca(_) => fader.get_x32_address(),
         _ => format!("{}/mix", fader.get_x32_address())
     };
     let msg = osc::Message::new_with_string(
@@ -16,12 +16,15 @@ fn fader_level_mute_test(fader: FaderIndex, level: f32, is_on: bool) {
         &format!("{address} {}   {level:.1} OFF +0 OFF   -oo", if is_on { "ON" } else { "OFF" })
     );
 
-    let expected = x32::updates::FaderUpdate{
-        source: fader,
-        level: Some(Fader::level_from_string(&format!("{level}"))),
-        is_on : Some(is_on),
-        ..Default::default()
+    let pan = match fader {
+        FaderIndex::Dca(_) => None,
+        _ => Some(0.5),
     };
+    let mut expected = x32::updates::FaderUpdate::new(fader);
+    expected.level = Some(Fader::level_from_string(&format!("{level}")));
+    expected.level_db = Fader::db_from_string(&format!("{level:.1}"));
+    expected.is_on = Some(is_on);
+    expected.pan = pan;
     let update = x32::ConsoleMessage::try_from(msg);
     assert_eq!(update, Ok(x32::ConsoleMessage::Fader(expected)));
 }
@@ -33,12 +36,9 @@ fn name_test(fader: FaderIndex, name : &str) {
         &format!("{}/config \"{name}\" 1 RD 33", fader.get_x32_address())
     );
 
-    let expected = x32::updates::FaderUpdate{
-        source: fader,
-        label: Some(name.to_owned()),
-        color : Some(FaderColor::Red),
-        ..Default::default()
-    };
+    let mut expected = x32::updates::FaderUpdate::new(fader);
+    expected.label = Some(name.to_owned());
+    expected.color = Some(FaderColor::Red);
 
     let update = x32::ConsoleMessage::try_from(msg);
     assert_eq!(update, Ok(x32::ConsoleMessage::Fader(expected)));
@@ -119,6 +119,30 @@ fn cue_position() {
     assert_eq!(update, Ok(x32::ConsoleMessage::CurrentCue(-1)));
 }
 
+#[test]
+fn recall_actions() {
+    let mut msg = osc::Message::new("node");
+    msg.add_item(String::from("/-action/goscene 12"));
+
+    let update = x32::ConsoleMessage::try_from(msg);
+    assert_eq!(update, Ok(x32::ConsoleMessage::Recall(x32_osc_state::enums::ShowMode::Scenes, 12)));
+
+    let mut msg = osc::Message::new("node");
+    msg.add_item(String::from("/-action/gocue 70000"));
+
+    let update = x32::ConsoleMessage::try_from(msg);
+    assert_eq!(update, Err(Error::X32(X32Error::MalformedPacket)));
+}
+
+#[test]
+fn current_cue_out_of_range() {
+    let mut msg = osc::Message::new("node");
+    msg.add_item(String::from("/-show/prepos/current 70000"));
+
+    let update = x32::ConsoleMessage::try_from(msg);
+    assert_eq!(update, Err(Error::X32(X32Error::MalformedPacket)));
+}
+
 #[test]
 fn show_mode() {
     let msg = osc::Message::new("node");
@@ -189,7 +213,8 @@ fn read_cue() {
         cue_number: String::from("12.0.0"),
         name: String::from("Cue Idx0 Num1200"),
         snippet: None,
-        scene: Some(1)
+        scene: Some(1),
+        skip: false,
     })));
 }
 
@@ -207,7 +232,8 @@ fn read_cue_2() {
         cue_number: String::from("1.0.0"),
         name: String::from("Cue with snip"),
         snippet: Some(23),
-        scene: None
+        scene: None,
+        skip: false,
     })));
 }
 
@@ -238,3 +264,38 @@ fn read_snippet() {
         name: String::from("Aaa"),
     })));
 }
+
+#[test]
+fn truncated_node_lines() {
+    for line in [
+        "/-show/showfile/cue/000 1200 \"Cue\" 1",
+        "/-show/showfile/cue/000 1",
+        "/-show/showfile/scene/001",
+        "/-show/showfile/snippet/030",
+        "/-show/prepos/current",
+        "/-prefs/show_control",
+        "/ch/01/config \"Label\" 1",
+        "/ch/01/mix ON",
+        "/ch/01/eq/1 PEQ 1k",
+        "/config/mute ON ON ON",
+        "/config/talk/A -10",
+    ] {
+        let msg = osc::Message::new_with_string("node", line);
+        assert_eq!(x32::ConsoleMessage::try_from(msg), Err(Error::X32(X32Error::MalformedPacket)), "{line}");
+    }
+}
+
+#[test]
+fn read_escaped_labels() {
+    let msg = osc::Message::new_with_string("node", r#"/-show/showfile/cue/002 1210 "Act \"2\" – Ouvertüre" 1 4 -1 0 1 0 0"#);
+
+    let update = x32::ConsoleMessage::try_from(msg);
+    assert_eq!(update, Ok(x32::ConsoleMessage::Cue(x32::updates::CueUpdate {
+        index: 2,
+        cue_number: String::from("12.1.0"),
+        name: String::from("Act \"2\" – Ouvertüre"),
+        snippet: None,
+        scene: Some(4),
+        skip: false,
+    })));
+}
@@ -1,6 +1,6 @@
 use x32_osc_state::x32;
 use x32_osc_state::osc;
-use x32_osc_state::enums::{ShowMode, FaderIndex};
+use x32_osc_state::enums::{ShowMode, FaderIndex, FaderColor, Fader};
 use x32_osc_state::enums::{Error, X32Error};
 
 mod buffer_common;
@@ -16,11 +16,8 @@ fn level_test(fader: FaderIndex, level: f32) {
 
     msg.add_item(level);
 
-    let expected = x32::updates::FaderUpdate{
-        source: fader,
-        level: Some(level),
-        ..Default::default()
-    };
+    let mut expected = x32::updates::FaderUpdate::new(fader);
+    expected.level = Some(level);
     let update = x32::ConsoleMessage::try_from(msg);
     assert_eq!(update, Ok(x32::ConsoleMessage::Fader(expected)));
 }
@@ -32,13 +29,10 @@ fn mute_test(fader: FaderIndex, is_on: bool) {
     };
     let mut msg = osc::Message::new(&format!("/{}/{mix_str}", fader.get_x32_address()));
 
-    msg.add_item(is_on as i32);
+    msg.add_item(i32::from(is_on));
 
-    let expected = x32::updates::FaderUpdate{
-        source: fader,
-        is_on: Some(is_on),
-        ..Default::default()
-    };
+    let mut expected = x32::updates::FaderUpdate::new(fader);
+    expected.is_on = Some(is_on);
 
     let update = x32::ConsoleMessage::try_from(msg);
     assert_eq!(update, Ok(x32::ConsoleMessage::Fader(expected)));
@@ -46,15 +40,12 @@ fn mute_test(fader: FaderIndex, is_on: bool) {
 
 fn name_test(fader: FaderIndex, name : &str) {
     let address = &format!("/{}/config/name", fader.get_x32_address());
-    let mut msg = osc::Message::new(&address);
+    let mut msg = osc::Message::new(address);
 
     msg.add_item(name.to_owned());
 
-    let expected = x32::updates::FaderUpdate{
-        source: fader,
-        label: Some(name.to_owned()),
-        ..Default::default()
-    };
+    let mut expected = x32::updates::FaderUpdate::new(fader);
+    expected.label = Some(name.to_owned());
 
     let update = x32::ConsoleMessage::try_from(msg);
     assert_eq!(update, Ok(x32::ConsoleMessage::Fader(expected)));
@@ -164,6 +155,24 @@ fn cue_position() {
     assert_eq!(update, Ok(x32::ConsoleMessage::CurrentCue(-1)));
 }
 
+#[test]
+fn recall_actions() {
+    let update = x32::ConsoleMessage::try_from(osc::Message::builder("/-action/goscene").int(4).build());
+    assert_eq!(update, Ok(x32::ConsoleMessage::Recall(ShowMode::Scenes, 4)));
+
+    let update = x32::ConsoleMessage::try_from(osc::Message::builder("/-action/gosnippet").int(2).build());
+    assert_eq!(update, Ok(x32::ConsoleMessage::Recall(ShowMode::Snippets, 2)));
+
+    let update = x32::ConsoleMessage::try_from(osc::Message::builder("/-action/gocue").int(7).build());
+    assert_eq!(update, Ok(x32::ConsoleMessage::Recall(ShowMode::Cues, 7)));
+
+    let update = x32::ConsoleMessage::try_from(osc::Message::builder("/-action/undopt").int(1).build());
+    assert_eq!(update, Err(Error::X32(X32Error::UnimplementedPacket)));
+
+    let update = x32::ConsoleMessage::try_from(osc::Message::builder("/-action/gocue").int(65_536).build());
+    assert_eq!(update, Err(Error::X32(X32Error::MalformedPacket)));
+}
+
 #[test]
 fn show_mode() {
     let msg = osc::Message::new("/-prefs/show_control");
@@ -230,4 +239,58 @@ fn invalid_faders() {
     assert_eq!(u_mute, Err(Error::X32(X32Error::InvalidFader)));
     assert_eq!(u_name, Err(Error::X32(X32Error::InvalidFader)));
     assert_eq!(u_color, Err(Error::X32(X32Error::InvalidFader)));
-}
\ No newline at end of file
+}
+#[test]
+fn lenient_numeric_types() {
+    let level = osc::Message::builder("/ch/01/mix/fader").int(1).build();
+    let mute = osc::Message::builder("/ch/01/mix/on").float(1.0).build();
+    let color = osc::Message::builder("/ch/01/config/color").float(2.0).build();
+
+    let mut expected = x32::updates::FaderUpdate::new(FaderIndex::Channel(1));
+    expected.level = Some(1.0);
+    assert_eq!(x32::ConsoleMessage::try_from(level), Ok(x32::ConsoleMessage::Fader(expected)));
+
+    let mut expected = x32::updates::FaderUpdate::new(FaderIndex::Channel(1));
+    expected.is_on = Some(true);
+    assert_eq!(x32::ConsoleMessage::try_from(mute), Ok(x32::ConsoleMessage::Fader(expected)));
+
+    let mut expected = x32::updates::FaderUpdate::new(FaderIndex::Channel(1));
+    expected.color = Some(FaderColor::Green);
+    assert_eq!(x32::ConsoleMessage::try_from(color), Ok(x32::ConsoleMessage::Fader(expected)));
+}
+
+#[test]
+fn pan_messages() {
+    for (address, source) in [("/ch/05/mix/pan", FaderIndex::Channel(5)), ("/bus/02/mix/pan", FaderIndex::Bus(2)), ("/auxin/03/mix/pan", FaderIndex::Aux(3))] {
+        let msg = osc::Message::builder(address).float(0.25).build();
+
+        let mut expected = x32::updates::FaderUpdate::new(source);
+        expected.pan = Some(0.25);
+        assert_eq!(x32::ConsoleMessage::try_from(msg), Ok(x32::ConsoleMessage::Fader(expected)));
+    }
+
+    let mut state = x32_osc_state::X32Console::default();
+    state.process(osc::Message::new_with_string("node", "/ch/07/mix ON -6.0 ON -50 OFF -oo"));
+    assert_eq!(state.fader(&FaderIndex::Channel(7)).expect("valid fader").pan(), (0.25, String::from("L50")));
+
+    assert_eq!(Fader::pan_to_string(0.5), "C");
+    assert_eq!(Fader::pan_to_string(1.0), "R100");
+    assert_eq!(Fader::pan_to_string(0.0), "L100");
+    assert_eq!(Fader::pan_from_string("+100"), Some(1.0));
+    assert_eq!(Fader::pan_from_string("x"), None);
+}
+
+#[test]
+fn main_stereo_and_mono() {
+    let stereo = x32::ConsoleMessage::try_from(osc::Message::new_with_string("/main/st/config/name", "LR"));
+    let mono = x32::ConsoleMessage::try_from(osc::Message::new_with_string("/main/m/config/name", "Center"));
+    let mono_node = x32::ConsoleMessage::try_from(osc::Message::new_with_string("node", "/main/m/mix ON -10 OFF +0"));
+    let bogus = x32::ConsoleMessage::try_from(osc::Message::new("/main/mc/mix/fader"));
+    let bogus_node = x32::ConsoleMessage::try_from(osc::Message::new_with_string("node", "/main/03/mix ON -10"));
+
+    assert!(matches!(stereo, Ok(x32::ConsoleMessage::Fader(v)) if v.source == FaderIndex::Main(1)));
+    assert!(matches!(mono, Ok(x32::ConsoleMessage::Fader(v)) if v.source == FaderIndex::Main(2)));
+    assert!(matches!(mono_node, Ok(x32::ConsoleMessage::Fader(v)) if v.source == FaderIndex::Main(2)));
+    assert_eq!(bogus, Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!(bogus_node, Err(Error::X32(X32Error::InvalidFader)));
+}
@@ -1,6 +1,8 @@
+#![expect(clippy::unwrap_used, clippy::float_cmp)]
 use x32_osc_state::x32::ConsoleMessage;
 use x32_osc_state::osc::Buffer;
-use x32_osc_state::enums::{Fader, FaderColor, FaderIndex, FaderIndexParse};
+use x32_osc_state::enums::{Fader, FaderColor, FaderIndex, FaderIndexParse, fit_width, fit_width_right};
+use x32_osc_state::x32::updates::FaderUpdate;
 use x32_osc_state::enums::{Error, X32Error};
 
 #[test]
@@ -31,6 +33,38 @@ fn address_split() {
     assert_eq!(items_4.3, "simpson");
 }
 
+#[test]
+fn node_split() {
+    let (address, args) = ConsoleMessage::split_node("/ch/01/config \"Lead  Vox\" 1 RD 1");
+    assert_eq!(address, "/ch/01/config");
+    assert_eq!(args.collect::<Vec<_>>(), vec!["Lead  Vox", "1", "RD", "1"]);
+
+    let (address, args) = ConsoleMessage::split_node_msg("/-show/showfile/scene/001 \"\" \"aaa\"   %111111110 1\n");
+    assert_eq!(address, "/-show/showfile/scene/001");
+    assert_eq!(args, vec!["", "aaa", "%111111110", "1"]);
+
+    let (address, args) = ConsoleMessage::split_node("/-prefs/name \"unterminated");
+    assert_eq!(address, "/-prefs/name");
+    assert_eq!(args.collect::<Vec<_>>(), vec!["unterminated"]);
+
+    let (address, mut args) = ConsoleMessage::split_node("");
+    assert_eq!(address, "");
+    assert_eq!(args.next(), None);
+}
+
+#[test]
+fn node_split_escapes_and_unicode() {
+    let (address, args) = ConsoleMessage::split_node_msg(r#"/ch/01/config "12\" Snare" 1 RD 1"#);
+    assert_eq!(address, "/ch/01/config");
+    assert_eq!(args, vec!["12\" Snare", "1", "RD", "1"]);
+
+    let (_, args) = ConsoleMessage::split_node_msg(r#"/ch/02/config "Chœur 50% \\ Ünïcode"OFF %0101"#);
+    assert_eq!(args, vec!["Chœur 50% \\ Ünïcode", "OFF", "%0101"]);
+
+    let (_, args) = ConsoleMessage::split_node_msg("/-show/showfile/scene/001 \"Große Bühne  \n");
+    assert_eq!(args, vec!["Große Bühne"]);
+}
+
 
 #[test]
 fn check_level_conversion() {
@@ -71,6 +105,73 @@ fn check_level_conversion() {
     }
 }
 
+#[test]
+fn level_db_values() {
+    let mut fader = Fader::new(FaderIndex::Channel(1));
+    let mut update = FaderUpdate::new(FaderIndex::Channel(1));
+    update.level = Some(0.75);
+    fader.update(update.clone());
+    assert!(fader.level_db().abs() < f32::EPSILON);
+
+    update.level = Some(0.0);
+    fader.update(update);
+    assert!((fader.level_db() + 90.0).abs() < f32::EPSILON);
+
+    for db in [-80.0_f32, -45.0, -20.0, -5.0, 0.0, 10.0] {
+        let level = Fader::level_from_db(db);
+        assert!((Fader::level_to_db(level) - db).abs() < 0.001, "{db} dB");
+    }
+
+    assert!((Fader::level_from_db(20.0) - 1.0).abs() < f32::EPSILON);
+    assert!(Fader::level_from_db(-120.0).abs() < f32::EPSILON);
+    assert!((Fader::quantize_level(Fader::level_from_db(-10.4)) - 0.4946).abs() < f32::EPSILON);
+}
+
+#[test]
+fn fader_taper() {
+    use x32_osc_state::enums::{LinearTaper, Taper, X32Taper};
+
+    static LINEAR:LinearTaper = LinearTaper::new(-60.0, 0.0);
+
+    assert!((X32Taper.level_to_db(0.75)).abs() < f32::EPSILON);
+    assert!((X32Taper.db_to_level(-10.0) - 0.5).abs() < f32::EPSILON);
+    assert!((LINEAR.level_to_db(0.5) + 30.0).abs() < f32::EPSILON);
+    assert!((LINEAR.db_to_level(-15.0) - 0.75).abs() < f32::EPSILON);
+    assert!((LINEAR.db_to_level(6.0) - 1.0).abs() < f32::EPSILON);
+
+    let mut update = FaderUpdate::new(FaderIndex::Channel(1));
+    update.level = Some(0.5);
+
+    let now = std::time::Instant::now();
+    let mut x32 = Fader::new(FaderIndex::Channel(1));
+    x32.update_at(update.clone(), now);
+    let mut linear = Fader::new(FaderIndex::Channel(1)).with_taper(&LINEAR);
+    linear.update_at(update, now);
+
+    assert_eq!(x32.level().1, "-10.0 dB");
+    assert_eq!(linear.level().1, "-30.0 dB");
+    assert!((linear.level_db() + 30.0).abs() < f32::EPSILON);
+    assert_eq!(x32, linear);
+
+    let mut bottom = FaderUpdate::new(FaderIndex::Channel(1));
+    bottom.level = Some(0.0);
+    linear.update(bottom);
+    assert_eq!(linear.level().1, "-oo dB");
+    assert!((linear.taper().min_db() + 60.0).abs() < f32::EPSILON);
+
+    let Ok(ConsoleMessage::Fader(node)) = ConsoleMessage::try_from(
+        x32_osc_state::osc::Message::builder("node").string("/ch/01/mix ON -30.0 OFF +0 OFF -oo").build()
+    ) else { panic!("node mix did not parse") };
+    linear.update(node.clone());
+    assert_eq!(linear.level().1, "-30.0 dB");
+    x32.update(node);
+    assert_eq!(x32.level().0, Fader::level_from_string("-30.0"));
+
+    let flat = LinearTaper::new(-10.0, -10.0);
+    assert_eq!(flat.db_to_level(-20.0), 0.0);
+    assert_eq!(flat.db_to_level(-10.0), 1.0);
+}
+
 #[test]
 fn fader_color() {
     assert_eq!(FaderColor::parse_str("OFF"), FaderColor::Off);
@@ -106,6 +207,28 @@ fn fader_color() {
     assert_eq!(FaderColor::parse_int(15), FaderColor::WhiteInverted);
     assert_eq!(FaderColor::parse_int(8), FaderColor::Off);
     assert_eq!(FaderColor::parse_int(0), FaderColor::Off);
+
+    for i in (0..=15).filter(|v| *v != 8) {
+        assert_eq!(FaderColor::parse_int(i).as_int(), i);
+    }
+}
+
+#[test]
+fn fader_color_render() {
+    assert_eq!(FaderColor::Red.rgb(), (255, 0, 0));
+    assert_eq!(FaderColor::RedInverted.rgb(), (255, 0, 0));
+    assert_eq!(FaderColor::Off.rgb(), (0, 0, 0));
+
+    assert_eq!(FaderColor::Cyan.hex(), "#00FFFF");
+    assert_eq!(FaderColor::WhiteInverted.hex(), "#FFFFFF");
+
+    assert_eq!(FaderColor::Yellow.ansi(), "\x1b[93m");
+    assert_eq!(FaderColor::YellowInverted.ansi(), "\x1b[30;103m");
+    assert_eq!(format!("{}Kick{}", FaderColor::Blue.ansi(), FaderColor::ANSI_RESET), "\x1b[94mKick\x1b[0m");
+
+    assert!(FaderColor::GreenInverted.is_inverted());
+    assert!(!FaderColor::Green.is_inverted());
+    assert!(!FaderColor::Off.is_inverted());
 }
 
 #[test]
@@ -146,4 +269,77 @@ fn fader_index_stuff() {
     let fake_fader:Result<FaderIndex, _> = fake_fader.try_into();
 
     assert_eq!(fake_fader.unwrap_err(), Error::X32(X32Error::InvalidFader));
-}
\ No newline at end of file
+}
+
+#[test]
+fn fader_index_enumerate() {
+    assert_eq!(FaderIndex::all().count(), 72);
+    assert_eq!(FaderIndex::channels().count(), 32);
+    assert_eq!(FaderIndex::aux_inputs().count(), 8);
+    assert_eq!(FaderIndex::buses().count(), 16);
+    assert_eq!(FaderIndex::matrices().count(), 6);
+    assert_eq!(FaderIndex::dcas().count(), 8);
+    assert_eq!(FaderIndex::mains().collect::<Vec<_>>(), vec![FaderIndex::Main(1), FaderIndex::Main(2)]);
+
+    assert_eq!(FaderIndex::all().next(), Some(FaderIndex::Channel(1)));
+    assert_eq!(FaderIndex::all().last(), Some(FaderIndex::Main(2)));
+    assert!(FaderIndex::all().all(|v| v.to_string().parse::<FaderIndex>() == Ok(v.clone())));
+}
+
+#[test]
+fn fader_index_names() {
+    assert_eq!("ch/05".parse::<FaderIndex>(), Ok(FaderIndex::Channel(5)));
+    assert_eq!("ch5".parse::<FaderIndex>(), Ok(FaderIndex::Channel(5)));
+    assert_eq!("/CH/32".parse::<FaderIndex>(), Ok(FaderIndex::Channel(32)));
+    assert_eq!("dca3".parse::<FaderIndex>(), Ok(FaderIndex::Dca(3)));
+    assert_eq!("bus/12".parse::<FaderIndex>(), Ok(FaderIndex::Bus(12)));
+    assert_eq!("aux7".parse::<FaderIndex>(), Ok(FaderIndex::Aux(7)));
+    assert_eq!("matrix/2".parse::<FaderIndex>(), Ok(FaderIndex::Matrix(2)));
+    assert_eq!("main/st".parse::<FaderIndex>(), Ok(FaderIndex::Main(1)));
+    assert_eq!("main/m".parse::<FaderIndex>(), Ok(FaderIndex::Main(2)));
+    assert_eq!("main".parse::<FaderIndex>(), Ok(FaderIndex::Main(1)));
+    assert_eq!("/main/02".parse::<FaderIndex>(), Ok(FaderIndex::Main(2)));
+
+    assert_eq!("ch/33".parse::<FaderIndex>(), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!("dca0".parse::<FaderIndex>(), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!("main/x".parse::<FaderIndex>(), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!("main/3".parse::<FaderIndex>(), Err(Error::X32(X32Error::InvalidFader)));
+
+    assert_eq!(FaderIndex::from_parts("main", "st"), Ok(FaderIndex::Main(1)));
+    assert_eq!(FaderIndex::from_parts("main", "m"), Ok(FaderIndex::Main(2)));
+    assert_eq!(FaderIndex::from_parts("main", "mc"), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!(FaderIndex::from_parts("main", ""), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!("fx/1".parse::<FaderIndex>(), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!("".parse::<FaderIndex>(), Err(Error::X32(X32Error::InvalidFader)));
+
+    assert_eq!(FaderIndex::Channel(5).to_string(), "ch/05");
+    assert_eq!(FaderIndex::Dca(3).to_string(), "dca/3");
+    assert_eq!(FaderIndex::Main(2).to_string(), "main/m");
+
+    for index in [FaderIndex::Aux(8), FaderIndex::Bus(16), FaderIndex::Matrix(6), FaderIndex::Main(1), FaderIndex::Main(2)] {
+        assert_eq!(index.to_string().parse::<FaderIndex>(), Ok(index));
+    }
+}
+#[test]
+fn display_width() {
+    assert_eq!(fit_width("Vocals", 8), "Vocals  ");
+    assert_eq!(fit_width("Lead Vocals", 8), "Lead Voc");
+    assert_eq!(fit_width_right("ON", 3), " ON");
+    assert_eq!(fit_width("Cafe\u{301} Mic", 5), "Cafe\u{301} ");
+    assert_eq!(fit_width("🎸🎸🎸", 4), "🎸🎸");
+    assert_eq!(fit_width("🎸🎸🎸", 3), "🎸 ");
+    assert_eq!(fit_width_right("鼓", 3), " 鼓");
+
+    let mut fader = Fader::new(FaderIndex::Channel(1));
+    assert_eq!(fader.display_name(6), "Ch01  ");
+
+    let mut update = FaderUpdate::new(FaderIndex::Channel(1));
+    update.label = Some(String::from("Kick Drum In"));
+    update.level = Some(0.0);
+    update.is_on = Some(true);
+    fader.update(update);
+
+    assert_eq!(fader.display_name(8), "Kick Dru");
+    assert_eq!(fader.vor_on_field(), " ON");
+    assert_eq!(fader.vor_level_field(), "  -oo dB");
+}
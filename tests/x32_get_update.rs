@@ -1,12 +1,14 @@
-use x32_osc_state::x32;
-use x32_osc_state::osc::Buffer;
-use x32_osc_state::enums::{FaderBank, FaderBankKey};
+use x32_osc_state::{x32, X32Console, X32ProcessResult};
+use x32_osc_state::osc::{Buffer, Message, Type};
+use x32_osc_state::enums::{Error, FaderBank, FaderBankKey, FaderColor, FaderIndex, X32Error};
 
 #[test]
 fn enum_full_update() {
     let update = x32::ConsoleRequest::full_update();
 
     assert_eq!(update.len(), 147);
+    assert_eq!(update.as_slice(), x32::ConsoleRequest::full_update_buffers());
+    assert!(std::ptr::eq(x32::ConsoleRequest::full_update_buffers(), x32::ConsoleRequest::full_update_buffers()));
 
     // for (i, item) in update.iter().enumerate() {
     // 	println!("{i:03}\n---\n{item}\n\n");
@@ -18,7 +20,7 @@ fn keep_alive() {
     let update:Vec<Buffer> = x32::ConsoleRequest::KeepAlive().into();
 
     assert_eq!(update.len(), 1);
-    assert_eq!(update.get(0), Some(&Buffer::from(vec![0x2f, 0x78, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x0, 0x0, 0x0, 0x0])));
+    assert_eq!(update.first(), Some(&Buffer::from(vec![0x2f, 0x78, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x0, 0x0, 0x0, 0x0])));
 }
 
 #[test]
@@ -32,3 +34,363 @@ fn vor_output() {
     assert_eq!(f_bank.vor_bundle(&FaderBankKey::Channel).len(), 32);
     assert_eq!(f_bank.vor_bundle(&FaderBankKey::Dca).len(), 8);
 }
+
+#[test]
+fn vor_output_changed() {
+    let mut console = x32_osc_state::X32Console::new();
+
+    let (generation, packets) = console.vor_bundle_since(&FaderBankKey::Channel, 0);
+    assert!(packets.is_empty());
+
+    console.process(Message::builder("/ch/03/mix/fader").float(0.5).build());
+    console.process(Message::builder("/ch/07/mix/on").int(1).build());
+    console.process(Message::builder("/bus/01/mix/fader").float(0.5).build());
+
+    let (generation, packets) = console.vor_bundle_since(&FaderBankKey::Channel, generation);
+    assert_eq!(packets.len(), 2);
+    assert!(packets[0].to_string().contains("/ch/03"));
+
+    let (generation, packets) = console.vor_bundle_since(&FaderBankKey::Channel, generation);
+    assert!(packets.is_empty());
+
+    // same value again is not a change
+    console.process(Message::builder("/ch/03/mix/fader").float(0.5).build());
+    assert!(console.vor_bundle_since(&FaderBankKey::Channel, generation).1.is_empty());
+
+    assert_eq!(console.vor_bundle_since(&FaderBankKey::Bus, 0).1.len(), 1);
+}
+
+#[test]
+fn meter_requests() {
+    let update:Vec<Buffer> = x32::ConsoleRequest::Meters(x32::MeterBank::Overview, 2).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+
+    assert_eq!(msg.address, "/meters");
+    assert_eq!(msg.args, vec![Type::String(String::from("/meters/0")), Type::Integer(2)]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::RenewMeters(x32::MeterBank::from_id(6)).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+
+    assert_eq!(msg.address, "/renew");
+    assert_eq!(msg.args, vec![Type::String(String::from("/meters/6"))]);
+}
+
+#[test]
+fn subscription_requests() {
+    let sub = x32::Subscription {
+        alias : String::from("/fdr"),
+        address : String::from("/ch/**/mix/fader"),
+        range : (1, 32),
+        factor : 4,
+    };
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::FormatSubscribe(sub.clone()).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+
+    assert_eq!(msg.address, "/formatsubscribe");
+    assert_eq!(msg.args, vec![
+        Type::String(String::from("/fdr")),
+        Type::String(String::from("/ch/**/mix/fader")),
+        Type::Integer(1),
+        Type::Integer(32),
+        Type::Integer(4),
+    ]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::BatchSubscribe(sub.clone()).into();
+    assert_eq!(Message::try_from(update[0].clone()).expect("valid message").address, "/batchsubscribe");
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::Renew(String::from("/fdr")).into();
+    assert_eq!(Message::try_from(update[0].clone()).expect("valid message").args, vec![Type::String(String::from("/fdr"))]);
+
+    let reply = Message::builder("/fdr").blob(vec![0, 0, 0, 0]).build();
+    assert!(matches!(x32::ConsoleMessage::subscription(&reply), Some(x32::ConsoleMessage::Subscription((alias, _))) if alias == "/fdr"));
+    assert_eq!(x32::ConsoleMessage::try_from(reply.clone()), Err(Error::X32(X32Error::UnimplementedPacket)));
+
+    let mut state = X32Console::new();
+    assert_eq!(state.process(reply.clone()), X32ProcessResult::Unhandled { address : String::from("/fdr") });
+    state.add_subscription(&sub);
+    assert!(state.subscriptions().contains("/fdr"));
+    assert!(matches!(state.process(reply.clone()), X32ProcessResult::Subscription((alias, _)) if alias == "/fdr"));
+    assert!(matches!(state.process(Message::builder("/fdr").int(1).build()), X32ProcessResult::Unhandled { .. }));
+    state.remove_subscription("/fdr");
+    assert!(state.subscriptions().is_empty());
+    assert!(matches!(state.process(reply), X32ProcessResult::Unhandled { .. }));
+}
+
+#[test]
+fn recorder_requests() {
+    let update:Vec<Buffer> = x32::ConsoleRequest::Recorder(x32::RecorderKind::Tape, x32::TransportState::Record).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/-stat/tape/state");
+    assert_eq!(msg.args, vec![Type::Integer(4)]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::Recorder(x32::RecorderKind::Card, x32::TransportState::Record).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/-stat/urec/state");
+    assert_eq!(msg.args, vec![Type::Integer(3)]);
+}
+
+#[test]
+fn device_info() {
+    let update:Vec<Buffer> = x32::ConsoleRequest::Info().into();
+    let addresses:Vec<String> = update.into_iter().map(|v| Message::try_from(v).expect("valid message").address).collect();
+    assert_eq!(addresses, vec!["/xinfo", "/info"]);
+
+    let mut state = x32_osc_state::X32Console::default();
+
+    state.process(Message::builder("/xinfo")
+        .string("192.168.1.64").string("X32-02-4A-53").string("X32").string("4.06").build());
+    state.process(Message::builder("/info")
+        .string("V2.07").string("X32-02-4A-53").string("X32RACK").string("4.06").build());
+
+    let device = state.device.clone().expect("device info");
+    assert_eq!(device.ip.as_deref(), Some("192.168.1.64"));
+    assert_eq!(device.model, "X32RACK");
+    assert_eq!(device.firmware_version(), Some((4, 6)));
+    assert!(device.firmware_at_least(4, 0));
+    assert!(!device.firmware_at_least(4, 10));
+
+    assert!(x32::ConsoleMessage::try_from(Message::builder("/xinfo").string("192.168.1.64").build()).is_err());
+}
+
+#[test]
+fn console_model() {
+    let mut state = x32_osc_state::X32Console::default();
+    assert_eq!(state.model(), x32::ConsoleModel::Unknown);
+    assert_eq!(state.request(x32::ConsoleRequest::Meters(x32::MeterBank::Surface, 2)).len(), 1);
+
+    state.process(Message::builder("/xinfo")
+        .string("192.168.1.65").string("M32C-0A-1B-2C").string("M32C").string("4.02").build());
+
+    assert_eq!(state.model(), x32::ConsoleModel::M32Core);
+    assert!(state.model().is_midas());
+    assert_eq!(state.dialect, x32::Dialect::X32);
+    assert!(state.request(x32::ConsoleRequest::Meters(x32::MeterBank::Surface, 2)).is_empty());
+    assert_eq!(state.request(x32::ConsoleRequest::Meters(x32::MeterBank::Channels, 2)).len(), 1);
+
+    state.process(Message::builder("/xinfo")
+        .string("192.168.1.66").string("XR18-5E-91-2A").string("XR18").string("1.17").build());
+
+    assert_eq!(state.model(), x32::ConsoleModel::XAir);
+    assert_eq!(state.dialect, x32::Dialect::XAir);
+    assert!(state.request(x32::ConsoleRequest::RenewMeters(x32::MeterBank::Overview)).is_empty());
+
+    assert_eq!(x32::ConsoleModel::from_model("x32rack"), x32::ConsoleModel::X32Rack);
+    assert_eq!(x32::ConsoleModel::from_model("WING"), x32::ConsoleModel::Unknown);
+}
+
+#[test]
+fn set_fader_commands() {
+    let update:Vec<Buffer> = x32::ConsoleRequest::SetLevel(FaderIndex::Channel(1), x32::FaderLevel::new(0.75).expect("finite level")).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/ch/01/mix/fader");
+    assert_eq!(msg.args, vec![Type::Float(0.75)]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::SetMute(FaderIndex::Dca(3), true).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/dca/3/on");
+    assert_eq!(msg.args, vec![Type::Integer(0)]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::SetLabel(FaderIndex::Main(1), String::from("Mains")).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/main/st/config/name");
+    assert_eq!(msg.args, vec![Type::String(String::from("Mains"))]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::SetColor(FaderIndex::Bus(2), FaderColor::Blue).into();
+    let msg = Message::try_from(update[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/bus/02/config/color");
+    assert_eq!(msg.args, vec![Type::Integer(4)]);
+
+    let update:Vec<Buffer> = x32::ConsoleRequest::SetLevel(FaderIndex::Unknown, x32::FaderLevel::default()).into();
+    assert!(update.is_empty());
+
+    assert_eq!(x32::FaderLevel::new(-0.0), x32::FaderLevel::new(0.0));
+    assert_eq!(x32::FaderLevel::new(f32::INFINITY), None);
+    assert!(x32::ConsoleRequest::SetLevel(FaderIndex::Channel(1), x32::FaderLevel::default()) < x32::ConsoleRequest::SetLevel(FaderIndex::Channel(1), x32::FaderLevel::new(0.5).expect("finite level")));
+}
+
+#[test]
+fn push_fader_state() {
+    let mut state = x32_osc_state::X32Console::default();
+    state.process(Message::builder("/ch/05/mix/fader").float(0.5).build());
+    state.process(Message::builder("/ch/05/mix/on").int(1).build());
+    state.process(Message::builder("/ch/05/config/name").string("Vox").build());
+    state.process(Message::builder("/dca/2/config/color").int(3).build());
+
+    let fader = state.fader(&FaderIndex::Channel(5)).expect("valid fader");
+    let addresses:Vec<String> = fader.to_console_messages().into_iter().map(|v| Message::try_from(v).expect("valid message").address).collect();
+    assert_eq!(addresses, vec!["/ch/05/mix/fader", "/ch/05/mix/on", "/ch/05/config/name", "/ch/05/config/color"]);
+
+    let pushed = state.faders.to_console_messages();
+    assert_eq!(pushed.len(), 72 * 4);
+
+    let mut copy = x32_osc_state::X32Console::default();
+    copy.process_all(pushed);
+    for index in [FaderIndex::Channel(5), FaderIndex::Dca(2)] {
+        let (copied, original) = (copy.fader(&index).expect("valid fader"), state.fader(&index).expect("valid fader"));
+        assert!(copied.changes_from(original).is_empty());
+    }
+}
+
+#[test]
+fn keep_alive_cadence() {
+    use std::time::{Duration, Instant};
+
+    let mut keep = x32::KeepAlive::new();
+    keep.renew(x32::ConsoleRequest::Renew(String::from("/fdr")));
+
+    let start = Instant::now();
+    assert!(keep.is_expired(start));
+    assert_eq!(keep.next_due(), None);
+
+    let sent:Vec<String> = keep.due(start).into_iter().map(|v| Message::try_from(v).expect("valid message").address).collect();
+    assert_eq!(sent, vec!["/xremote", "/renew"]);
+    assert_eq!(keep.expires_at(), Some(start + x32::KeepAlive::LEASE));
+    assert!(!keep.is_expired(start + Duration::from_secs(9)));
+
+    assert!(keep.due(start + Duration::from_secs(4)).is_empty());
+    assert_eq!(keep.next_due(), Some(start + Duration::from_secs(5)));
+    assert_eq!(keep.due(start + Duration::from_secs(6)).len(), 2);
+    assert!(keep.is_expired(start + Duration::from_secs(16)));
+
+    keep.reset();
+    assert_eq!(keep.due(start + Duration::from_secs(7)).len(), 2);
+
+    let slow = x32::KeepAlive::new().with_interval(Duration::from_secs(60));
+    assert!(slow.interval() < x32::KeepAlive::LEASE);
+
+    let mut fast = x32::KeepAlive::new().with_interval(Duration::ZERO);
+    assert!(fast.interval() > Duration::ZERO);
+    assert_eq!(fast.due(start).len(), 1);
+    assert!(fast.due(start).is_empty());
+}
+
+#[test]
+fn paced_sender() {
+    use std::time::{Duration, Instant};
+
+    let gap = Duration::from_millis(20);
+    let mut pacing = x32::PacedSender::new().with_gap(gap).with_burst(3);
+    let start = Instant::now();
+
+    let waits:Vec<_> = (0..5).map(|_| pacing.delay(start)).collect();
+    assert_eq!(waits, [Duration::ZERO, Duration::ZERO, Duration::ZERO, gap, gap * 2]);
+
+    // the budget refills one packet per gap while idle
+    let later = start + gap * 10;
+    assert_eq!(pacing.delay(later), Duration::ZERO);
+    assert_eq!(pacing.delay(later + gap), Duration::ZERO);
+    assert_eq!(pacing.delay(later + gap), Duration::ZERO);
+    assert_eq!(pacing.delay(later + gap), Duration::ZERO);
+    assert_eq!(pacing.delay(later + gap), gap);
+
+    pacing.reset();
+    assert_eq!(pacing.delay(later + gap), Duration::ZERO);
+
+    let buffers = x32::ConsoleRequest::full_update();
+    let paced:Vec<_> = x32::PacedSender::new().pace(buffers.clone()).collect();
+    assert_eq!(paced.len(), buffers.len());
+    assert_eq!(paced[0].1, Duration::ZERO);
+    assert!(paced.iter().skip(1).all(|(_, wait)| *wait == Duration::from_millis(50)));
+    assert_eq!(paced.into_iter().map(|(item, _)| item).collect::<Vec<_>>(), buffers);
+
+    assert_eq!(x32::PacedSender::new().with_burst(0).burst(), 1);
+}
+
+#[test]
+fn packed_full_update() {
+    let buffers = x32::ConsoleRequest::full_update();
+    let packed = x32::ConsoleRequest::pack(buffers.clone(), x32::ConsoleRequest::MAX_DATAGRAM);
+
+    assert!(packed.len() < buffers.len() / 10);
+    assert!(packed.iter().all(|v| v.len() <= x32::ConsoleRequest::MAX_DATAGRAM));
+
+    let messages = |list : &[Buffer]| -> Vec<Message> {
+        list.iter()
+            .map(|v| x32_osc_state::osc::Packet::try_from(v.clone()).expect("valid packet"))
+            .flat_map(|v| v.messages().cloned().collect::<Vec<_>>())
+            .collect()
+    };
+    assert_eq!(messages(&packed), messages(&buffers));
+
+    // undecodable buffers keep their place, on their own
+    let junk = Buffer::from(vec![0x01, 0x02]);
+    let packed = x32::ConsoleRequest::pack(buffers[..2].iter().cloned().chain([junk.clone()]).chain(buffers[2..4].iter().cloned()), 512);
+    assert_eq!(packed.len(), 3);
+    assert_eq!(packed[1], junk);
+
+    let mut state = x32_osc_state::X32Console::new();
+    state.dialect = x32::Dialect::XAir;
+    assert_eq!(messages(&state.full_update_packed(512)), messages(&state.full_update()));
+}
+
+#[test]
+fn discover_consoles() {
+    use std::net::UdpSocket;
+    use std::time::Duration;
+
+    let desk = UdpSocket::bind("127.0.0.1:0").expect("bind fake console");
+    let desk_addr = desk.local_addr().expect("fake console address");
+
+    let responder = std::thread::spawn(move || {
+        let mut buf = [0_u8; 512];
+        let (len, from) = desk.recv_from(&mut buf).expect("request received");
+        let request = Message::try_from(Buffer::from(buf[..len].to_vec())).expect("valid message");
+        assert_eq!(request.address, "/xinfo");
+
+        let reply:Buffer = Message::builder("/xinfo")
+            .string("127.0.0.1").string("X32-02-4A-53").string("X32RACK").string("4.06")
+            .build().try_into().expect("valid buffer");
+        desk.send_to(reply.as_slice(), from).expect("reply sent");
+    });
+
+    let found = x32::DiscoveredConsole::scan(&[desk_addr], Duration::from_millis(500)).expect("scan");
+    responder.join().expect("responder finished");
+
+    assert_eq!(found, vec![x32::DiscoveredConsole {
+        addr : desk_addr,
+        name : String::from("X32-02-4A-53"),
+        model : String::from("X32RACK"),
+        firmware : String::from("4.06"),
+    }]);
+}
+
+#[cfg(feature = "test-support")]
+#[test]
+fn emulated_console() {
+    use std::time::{Duration, Instant};
+    use x32_osc_state::x32::emulator::FakeConsole;
+
+    let mut desk = FakeConsole::new();
+    let now = Instant::now();
+
+    desk.state_mut().process(Message::new_with_string("node", "/-show/showfile/cue/000 100 \"Intro\" 1 1 -1 0 1 0 0"));
+    desk.state_mut().process(Message::new_with_string("node", "/-show/showfile/scene/001 \"SceneAAA\" \"\" %111111110 1"));
+    for request in x32::ConsoleRequest::SetMute(FaderIndex::Dca(1), false) { desk.handle(request, now); }
+
+    let mut app = x32_osc_state::X32Console::new();
+    for request in x32::ConsoleRequest::full_update() {
+        app.process_all(desk.handle(request, now));
+    }
+    assert_eq!(app.cue_list_size(), (1, 1, 0));
+    assert!(app.fader(&FaderIndex::Dca(1)).expect("valid fader").is_on().0);
+
+    let reply = desk.handle(Message::new("/dca/1/on").try_into().expect("valid buffer"), now);
+    assert_eq!(Message::try_from(reply[0].clone()).expect("valid message").args, vec![Type::Integer(1)]);
+
+    let reply = desk.handle(x32::ConsoleRequest::GoCue(0).into_iter().next().expect("request"), now);
+    app.process_all(reply);
+    assert_eq!(app.current_cue, Some(0));
+
+    for request in x32::ConsoleRequest::Meters(x32::MeterBank::Overview, 2) { desk.handle(request, now); }
+    let blobs = desk.tick(now);
+    assert_eq!(blobs.len(), 1);
+    assert!(matches!(app.process(blobs[0].clone()), x32_osc_state::X32ProcessResult::Meters((0, _))));
+    assert!(desk.tick(now + Duration::from_millis(50)).is_empty());
+    assert_eq!(desk.tick(now + Duration::from_millis(100)).len(), 1);
+    assert!(desk.tick(now + Duration::from_secs(11)).is_empty());
+
+    assert!(!desk.is_remote_active(now));
+    desk.handle(x32::ConsoleRequest::KeepAlive().into_iter().next().expect("request"), now);
+    assert!(desk.is_remote_active(now));
+}
@@ -0,0 +1,76 @@
+use x32_osc_state::x32::{self, NodeCommand, ConsoleMessage};
+use x32_osc_state::osc::{self, Type};
+use x32_osc_state::enums::{Fader, FaderColor, FaderIndex};
+
+/// Feed a command back through the parser as if the console sent it
+fn round_trip(cmd : &NodeCommand) -> ConsoleMessage {
+    let sent = osc::Message::from(cmd.clone());
+
+    assert_eq!(sent.address, "/");
+    assert_eq!(sent.args, vec![Type::String(cmd.to_string())]);
+
+    let reply = osc::Message::new_with_string("node", &cmd.to_string());
+    ConsoleMessage::try_from(reply).expect("parse failed")
+}
+
+#[test]
+fn formatting() {
+    let cmd = NodeCommand::new("/ch/01/grp")
+        .bitmask(0b101, 8)
+        .bitmask(0b11, 6);
+    assert_eq!(cmd.to_string(), "/ch/01/grp %00000101 %000011");
+
+    let cmd = NodeCommand::new("/ch/01/mix").on(true).level(0.0).level(0.75).level(1.0);
+    assert_eq!(cmd.to_string(), "/ch/01/mix ON -oo +0.0 +10.0");
+
+    let cmd = NodeCommand::new("/ch/01/config").string("Say \"Hi\"").string("");
+    assert_eq!(cmd.to_string(), r#"/ch/01/config "Say \"Hi\"" """#);
+    assert_eq!(NodeCommand::parse(&cmd.to_string()).as_ref(), Some(&cmd));
+
+    let path = NodeCommand::new("/ch/01/config").string(r"C:\Show");
+    assert_eq!(path.to_string(), r#"/ch/01/config "C:\\Show""#);
+    assert_eq!(NodeCommand::parse(&path.to_string()).as_ref(), Some(&path));
+
+    assert_eq!(cmd.address(), "/ch/01/config");
+    assert_eq!(cmd.args().len(), 2);
+
+    let buffer = osc::Buffer::try_from(cmd).expect("buffer pack failed");
+    assert!(buffer.is_valid());
+}
+
+#[test]
+fn round_trip_mix() {
+    for level in [0.0_f32, 0.1, 0.33, 0.5, 0.75, 1.0] {
+        let expected = Fader::level_from_string(&Fader::level_to_string(level));
+        let cmd = NodeCommand::new("/bus/03/mix")
+            .on(false)
+            .level(level)
+            .on(true)
+            .raw("+0")
+            .on(false)
+            .level(0.0);
+
+        let mut update = x32::updates::FaderUpdate::new(FaderIndex::Bus(3));
+        update.level = Some(expected);
+        update.level_db = Fader::db_from_string(&Fader::level_to_string(level));
+        update.is_on = Some(false);
+        update.pan = Some(0.5);
+        assert_eq!(round_trip(&cmd), ConsoleMessage::Fader(update));
+    }
+}
+
+#[test]
+fn round_trip_config() {
+    for color in [FaderColor::Off, FaderColor::Red, FaderColor::White, FaderColor::CyanInverted] {
+        let cmd = NodeCommand::new("/ch/12/config")
+            .string("Pastor mic")
+            .int(1)
+            .color(color)
+            .int(12);
+
+        let mut update = x32::updates::FaderUpdate::new(FaderIndex::Channel(12));
+        update.label = Some(String::from("Pastor mic"));
+        update.color = Some(color);
+        assert_eq!(round_trip(&cmd), ConsoleMessage::Fader(update));
+    }
+}
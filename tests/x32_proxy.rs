@@ -0,0 +1,46 @@
+#![cfg(feature = "tokio")]
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use x32_osc_state::{osc, x32};
+use x32_osc_state::enums::FaderIndex;
+
+/// Next message received on a socket, with the sender
+async fn next_message(socket : &UdpSocket) -> (osc::Message, std::net::SocketAddr) {
+    let mut buf = [0_u8; 1024];
+    let (len, from) = tokio::time::timeout(Duration::from_secs(5), socket.recv_from(&mut buf)).await.expect("datagram received").expect("valid read");
+    (osc::Message::try_from(osc::Buffer::from(buf[..len].to_vec())).expect("valid message"), from)
+}
+
+#[tokio::test]
+async fn proxy_relay() {
+    let desk = UdpSocket::bind("127.0.0.1:0").await.expect("bind fake console");
+    let proxy = x32::Proxy::bind("127.0.0.1:0".parse().expect("address"), desk.local_addr().expect("fake console address")).await.expect("bind proxy");
+    let listen = proxy.local_addr().expect("proxy address");
+
+    let (lease, upstream) = next_message(&desk).await;
+    assert_eq!(lease.address, "/xremote");
+
+    let clients = [
+        UdpSocket::bind("127.0.0.1:0").await.expect("bind client"),
+        UdpSocket::bind("127.0.0.1:0").await.expect("bind client"),
+    ];
+    let keep_alive:osc::Buffer = osc::Message::new("/xremote").try_into().expect("valid buffer");
+    let query:osc::Buffer = osc::Message::new("/ch/01/mix/fader").try_into().expect("valid buffer");
+    clients[0].send_to(keep_alive.as_slice(), listen).await.expect("send keep alive");
+    clients[1].send_to(query.as_slice(), listen).await.expect("send query");
+
+    let (forwarded, from) = next_message(&desk).await;
+    assert_eq!(forwarded.address, "/ch/01/mix/fader", "client keep alive is not forwarded");
+    assert_eq!(from, upstream);
+    assert_eq!(proxy.clients().len(), 2);
+
+    let reply:osc::Buffer = osc::Message::builder("/ch/01/mix/fader").float(0.5).build().try_into().expect("valid buffer");
+    desk.send_to(reply.as_slice(), upstream).await.expect("send reply");
+
+    for client in &clients {
+        let (msg, from) = next_message(client).await;
+        assert_eq!(msg.address, "/ch/01/mix/fader");
+        assert_eq!(from, listen);
+    }
+    assert!((proxy.console().fader(&FaderIndex::Channel(1)).expect("valid fader").level().0 - 0.5).abs() < f32::EPSILON);
+}
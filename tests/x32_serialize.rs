@@ -1,3 +1,5 @@
+#![cfg(feature = "serde")]
+#![expect(clippy::unwrap_used)]
 use x32_osc_state::enums::{FaderIndex, Fader};
 
 #[test]
@@ -0,0 +1,171 @@
+use x32_osc_state::enums::{FaderColor, FaderIndex};
+use x32_osc_state::osc::Message;
+use x32_osc_state::{X32Console, X32ProcessResult};
+
+fn node(line : &str) -> Message {
+    Message::builder("node").string(line).build()
+}
+
+#[test]
+fn snapshot_contents() {
+    let mut console = X32Console::new();
+    console.process(node("/ch/03/config \"Keys\" 1 GN 3"));
+    console.process(node("/-show/showfile/cue/004 100 \"Top\" 1 -1 -1 0 1 0 0"));
+    console.process(node("/-show/showfile/scene/002 \"Act 1\" \"\" %111111110 1"));
+
+    let snapshot = console.snapshot();
+
+    assert_eq!(snapshot.faders.len(), 72);
+    let keys = snapshot.faders.iter().find(|f| f.source == FaderIndex::Channel(3)).expect("fader exists");
+    assert_eq!(keys.label, "Keys");
+    assert_eq!(keys.color, FaderColor::Green);
+
+    assert_eq!(snapshot.cues.keys().copied().collect::<Vec<_>>(), vec![4]);
+    assert_eq!(snapshot.scenes.get(&2).map(String::as_str), Some("Act 1"));
+    assert!(snapshot.snippets.is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn shutdown_guard_writes_on_drop_and_panic() {
+    use std::sync::{Arc, Mutex};
+    use x32_osc_state::x32::ShutdownGuard;
+
+    /// Writer shared with the test, so it survives the panic
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().map_err(|_| std::io::Error::other("poisoned"))?.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+    }
+
+    let out = Shared::default();
+    let writer = out.clone();
+
+    let result = std::panic::catch_unwind(move || {
+        let mut console = ShutdownGuard::new(X32Console::new(), writer);
+        console.process(node("/ch/01/config \"Lead\" 1 RD 1"));
+        panic!("mid-show crash");
+    });
+    assert!(result.is_err());
+
+    let written = String::from_utf8(out.0.lock().expect("not poisoned").clone()).expect("utf8");
+    assert_eq!(written.lines().count(), 1);
+    assert!(written.contains("\"label\":\"Lead\""));
+
+    let mut out = vec![];
+    {
+        let mut console = ShutdownGuard::new(X32Console::new(), &mut out).with_audit();
+        console.process(node("/ch/02/config \"Bass\" 1 RD 1"));
+        console.process(node("/-prefs/show_control SCENES"));
+    }
+    let written = String::from_utf8(out).expect("utf8");
+    let audit:serde_json::Value = serde_json::from_str(written.lines().nth(1).expect("audit line")).expect("valid json");
+    assert_eq!(audit["audit"][0]["change"]["Fader"]["name"], "Ch02");
+    assert_eq!(audit["audit"][1]["change"], "CurrentCue");
+    assert_eq!(audit["audit"][1]["generation"], 2);
+
+    let mut out = vec![];
+    let guard = ShutdownGuard::new(X32Console::new(), &mut out);
+    let console = guard.into_inner();
+    assert!(out.is_empty());
+    assert_eq!(console.current_cue, None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn snapshot_round_trip_and_migration() {
+    use x32_osc_state::enums::{Error, X32Error};
+    use x32_osc_state::x32::Snapshot;
+
+    let mut console = X32Console::new();
+    console.process(node("/ch/03/config \"Keys\" 1 GN 3"));
+    console.process(node("/ch/03/mix ON -6.0 ON +0 OFF -oo"));
+    console.process(node("/-show/showfile/cue/004 100 \"Top\" 1 -1 -1 0 1 0 0"));
+    console.process(node("/-show/prepos/current 4"));
+
+    let json = console.snapshot().to_json().expect("serializes");
+    let loaded = Snapshot::from_json(&json).expect("loads");
+    assert_eq!(loaded, console.snapshot());
+
+    let mut restored = X32Console::new();
+    restored.restore(&loaded);
+    assert_eq!(restored.fader(&FaderIndex::Channel(3)).zip(console.fader(&FaderIndex::Channel(3))).map(|(a, b)| a.changes_from(b)), Some(x32_osc_state::enums::FaderChange::NONE));
+    assert_eq!(restored.active_cue(), console.active_cue());
+
+    // unversioned, with fields missing
+    let legacy = r#"{"faders":[{"source":{"index":2,"type":"bus","name":"MixBus02"},"label":"Mon 2"}],"current_cue":1}"#;
+    let loaded = Snapshot::from_json(legacy).expect("migrates");
+    assert_eq!(loaded.version, Snapshot::VERSION);
+    assert_eq!(loaded.faders[0].source, FaderIndex::Bus(2));
+    assert_eq!(loaded.faders[0].label, "Mon 2");
+    assert_eq!(loaded.faders[0].color, FaderColor::White);
+    assert_eq!(loaded.current_cue, Some(1));
+    assert!(loaded.cues.is_empty());
+
+    assert_eq!(Snapshot::from_json(r#"{"version":99}"#), Err(Error::X32(X32Error::SnapshotVersion)));
+    assert_eq!(Snapshot::from_json("[1, 2]"), Err(Error::X32(X32Error::InvalidSnapshot)));
+    assert_eq!(
+        Snapshot::from_json(r#"{"faders":[{"source":{"index":40,"type":"channel"}}]}"#),
+        Err(Error::X32(X32Error::InvalidSnapshot))
+    );
+}
+
+#[test]
+fn warm_start_reports_corrections() {
+    let mut previous = X32Console::new();
+    previous.process(node("/ch/01/config \"Kick\" 1 RD 1"));
+    previous.process(node("/-prefs/show_control CUES"));
+
+    let mut console = X32Console::new();
+    let (mut warm, requests) = console.warm_start(&previous.snapshot());
+
+    assert_eq!(requests.len(), 147);
+    assert_eq!(console.fader(&FaderIndex::Channel(1)).expect("valid fader").name(), "Kick");
+    assert!(!warm.is_complete());
+
+    assert_eq!(warm.process(&mut console, node("/ch/01/config \"Kick\" 1 RD 1")), X32ProcessResult::NoOperation);
+    assert!(matches!(warm.process(&mut console, node("/ch/01/mix OFF -10.0 OFF +0 OFF -oo")), X32ProcessResult::Fader(_)));
+    assert!(warm.pending().all(|v| *v != FaderIndex::Channel(1)));
+
+    assert_eq!(warm.process(&mut console, Message::builder("/-prefs/show_control").int(0).build()), X32ProcessResult::NoOperation);
+
+    // verification finished for the strip, later changes always pass through
+    assert!(matches!(warm.process(&mut console, node("/ch/01/config \"Kick\" 1 RD 1")), X32ProcessResult::Fader(_)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn console_serde_round_trip() {
+    use x32_osc_state::x32::OutputPort;
+
+    let mut console = X32Console::new();
+    console.process(node("/ch/03/config \"Keys\" 1 GN 3"));
+    console.process(node("/ch/03/grp %00000010 %000001"));
+    console.process(node("/-show/showfile/scene/002 \"Verse\" \"\" %000000000 1"));
+    console.process(Message::builder("/ch/03/mix/02/level").float(0.5).build());
+    console.process(Message::builder("/ch/03/eq/1/g").float(1.0).build());
+    console.process(Message::builder("/headamp/002/phantom").int(1).build());
+    console.process(Message::builder("/outputs/main/01/src").int(4).build());
+    console.process(Message::builder("/config/mute/2").int(1).build());
+
+    let json = serde_json::to_string(&console).expect("serializes");
+    let restored:X32Console = serde_json::from_str(&json).expect("deserializes");
+
+    assert_eq!(restored.snapshot(), console.snapshot());
+    assert_eq!(restored.fader(&FaderIndex::Channel(3)).zip(console.fader(&FaderIndex::Channel(3))).map(|(a, b)| a.changes_from(b)), Some(x32_osc_state::enums::FaderChange::NONE));
+    assert_eq!(restored.scene(2), Some("Verse"));
+    assert_eq!(restored.send(&FaderIndex::Channel(3), &FaderIndex::Bus(2)), console.send(&FaderIndex::Channel(3), &FaderIndex::Bus(2)));
+    assert_eq!(restored.eq(&FaderIndex::Channel(3)), console.eq(&FaderIndex::Channel(3)));
+    assert!(restored.preamp(&FaderIndex::Channel(3)).expect("tracked preamp").phantom);
+    assert_eq!(restored.routing.output(&OutputPort::Main(1)), console.routing.output(&OutputPort::Main(1)));
+    assert_eq!(restored.mute_groups, console.mute_groups);
+
+    assert!(serde_json::from_str::<X32Console>(r#"{"version":99}"#).is_err_and(|v| v.to_string().contains("version")));
+    let legacy:X32Console = serde_json::from_str(r#"{"scenes":{"2":"Verse"}}"#).expect("migrates");
+    assert_eq!(legacy.scene(2), Some("Verse"));
+}
@@ -1,5 +1,8 @@
+#![expect(clippy::float_cmp)]
 use x32_osc_state::enums::{Fader, FaderIndex, FaderColor};
-use x32_osc_state::osc;
+use x32_osc_state::{osc, x32};
+use x32_osc_state::x32::updates::MeterBlob;
+use x32_osc_state::display::CueFormat;
 use x32_osc_state::{X32ProcessResult, X32Console};
 
 mod buffer_common;
@@ -24,7 +27,7 @@ fn make_and_test_cues() {
     state.process(make_node_message("/-show/showfile/scene/002 \"SceneBBB\" \"aaa\" %111111110 1"));
 
     let result = state.process(make_node_message("/-show/showfile/snippet/000 \"Snip-001\" 1 1 0 32768 1 "));
-    assert_eq!(result, X32ProcessResult::NoOperation);
+    assert_eq!(result, X32ProcessResult::CueListChanged { kind : x32_osc_state::enums::ShowMode::Snippets, index : 0 });
 
     assert_eq!(state.cue_list_size(), (3,2,1));
 
@@ -54,7 +57,76 @@ fn make_and_test_cues() {
     assert_eq!(state.active_cue(), "Scene: --");
 }
 
-fn make_fader_messages(f : &str, i : usize, v :(f32, bool, String)) -> [osc::Message;2] {
+#[test]
+fn follow_scene_and_snippet_recalls() {
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/-show/showfile/scene/001 \"SceneAAA\" \"aaa\" %111111110 1"));
+    state.process(make_node_message("/-show/showfile/scene/002 \"SceneBBB\" \"aaa\" %111111110 1"));
+    state.process(make_node_message("/-show/showfile/snippet/003 \"Snip-004\" 1 1 0 32768 1 "));
+    state.process(make_node_message("/-prefs/show_control SCENES"));
+
+    let result = state.process(osc::Message::builder("/-action/goscene").int(2).build());
+    assert_eq!(result, X32ProcessResult::CurrentCue(String::from("Scene: 02:SceneBBB")));
+    assert_eq!(state.current_cue, Some(2));
+
+    // recalls from a list the show control is not tracking are ignored
+    let result = state.process(osc::Message::builder("/-action/gosnippet").int(3).build());
+    assert_eq!(result, X32ProcessResult::NoOperation);
+    assert_eq!(state.active_cue(), "Scene: 02:SceneBBB");
+
+    state.process(make_node_message("/-prefs/show_control SNIPPETS"));
+    state.process(make_node_message("/-action/gosnippet 3"));
+    assert_eq!(state.active_cue(), "Snippet: 03:Snip-004");
+
+    assert_eq!(state.process(osc::Message::builder("/-action/gosnippet").int(-1).build()), X32ProcessResult::NoOperation);
+    assert_eq!(state.current_cue, Some(3));
+}
+
+#[test]
+fn resolve_cue_links() {
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/-show/showfile/cue/000 100 \"Cue Idx0 Num100\" 1 1 0 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/cue/001 110 \"Cue Idx1 Num110\" 1 5 -1 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/scene/001 \"SceneAAA\" \"aaa\" %111111110 1"));
+    state.process(make_node_message("/-show/showfile/snippet/000 \"Snip-001\" 1 1 0 32768 1 "));
+
+    let cue = state.cue(0).expect("cue exists");
+    assert_eq!(state.cue_scene(cue), Some("SceneAAA"));
+    assert_eq!(state.cue_snippet(cue), Some("Snip-001"));
+
+    let resolved = state.resolve_cue(0).expect("cue exists");
+    assert_eq!(resolved.index, 0);
+    assert_eq!(resolved.cue.name, "Cue Idx0 Num100");
+    assert_eq!(resolved.to_string(), "1.0.0 :: Cue Idx0 Num100 [01:SceneAAA] [00:Snip-001]");
+
+    let resolved = state.resolve_cue(1).expect("cue exists");
+    assert_eq!(resolved.scene, None);
+    assert_eq!(resolved.snippet, None);
+    assert_eq!(resolved.to_string(), "1.1.0 :: Cue Idx1 Num110 [--] [--]");
+
+    state.cue_format = CueFormat::Short;
+    state.process(make_node_message("/-show/prepos/current 0"));
+    assert_eq!(state.active_cue(), "Cue: 1.0.0 Cue Idx0 Num100");
+
+    state.cue_format = CueFormat::Template(String::from("{snippet} / {scene} / {num}"));
+    assert_eq!(state.active_cue(), "Cue: 00:Snip-001 / 01:SceneAAA / 1.0.0");
+
+    state.process(make_node_message("/-show/prepos/current 5"));
+    assert_eq!(state.active_cue(), "Cue: -- / -- / 0.0.0");
+
+    state.cue_format = CueFormat::Template(String::from("{{num}} {name}"));
+    state.process(make_node_message("/-show/showfile/cue/000 100 \"{scene}\" 1 1 0 0 1 0 0"));
+    state.process(make_node_message("/-show/prepos/current 0"));
+    assert_eq!(state.active_cue(), "Cue: {1.0.0} {scene}");
+
+    assert!(state.resolve_cue(2).is_none());
+    assert!(state.resolve_cue(10_000).is_none());
+    assert_eq!(state.scene(10_000), None);
+}
+
+fn make_fader_messages(f : &str, i : usize, v :&(f32, bool, String)) -> [osc::Message;2] {
     let mix = format!("/{f}/{i:02}/mix {}   {:.1} OFF +0 OFF   -oo", if v.1 { "ON" } else { "OFF" } , v.0);
     let name = format!("/{f}/{i:02}/config \"{}\" 1 RD 33", v.2);
 
@@ -72,12 +144,12 @@ fn make_and_test_faders() {
     let channel = random_data_node();
     let aux = random_data_node();
 
-    make_fader_messages("auxin", 2, aux.clone()).iter().for_each(|item|{ state.process(item.clone()); });
-    make_fader_messages("bus", 8, bus.clone()).iter().for_each(|item|{ state.process(item.clone()); });
-    make_fader_messages("mtx", 4, mtx.clone()).iter().for_each(|item|{ state.process(item.clone()); });
-    make_fader_messages("ch", 23, channel.clone()).iter().for_each(|item|{ state.process(item.clone()); });
-    make_fader_messages("main", 1, main.clone()).iter().for_each(|item|{ state.process(item.clone()); });
-    make_fader_messages("dca", 3, dca.clone()).iter().for_each(|item|{ state.process(item.clone()); });
+    for item in make_fader_messages("auxin", 2, &aux) { state.process(item); }
+    for item in make_fader_messages("bus", 8, &bus) { state.process(item); }
+    for item in make_fader_messages("mtx", 4, &mtx) { state.process(item); }
+    for item in make_fader_messages("ch", 23, &channel) { state.process(item); }
+    for item in make_fader_messages("main", 1, &main) { state.process(item); }
+    for item in make_fader_messages("dca", 3, &dca) { state.process(item); }
 
     let aux_fader = state.fader(&FaderIndex::Aux(2)).expect("invalid fader");
 
@@ -122,9 +194,9 @@ fn make_and_test_faders() {
 
     assert_eq!(dca_fader.name(), "DCA3");
     assert_eq!(dca_fader.level().0, 0_f32);
-    assert_eq!(dca_fader.is_on().0, false);
+    assert!(!dca_fader.is_on().0);
 
-    let msg1 = make_fader_messages("bus", 2, bus);
+    let msg1 = make_fader_messages("bus", 2, &bus);
     let result = state.process(msg1[0].clone());
     assert!(matches!(result, X32ProcessResult::Fader(_)));
 }
@@ -136,19 +208,1290 @@ fn meter_test() {
 
     let mut buffer_msg = osc::Message::new("/meters/0");
     let float_packed = float_original
-        .map(|f| f.to_le_bytes())
+        .map(f32::to_le_bytes)
         .iter()
         .flat_map(|u| *u)
         .collect::<Vec<u8>>();
 
-    buffer_msg.add_item(osc::Type::Blob(float_packed));
+    buffer_msg.add_item(osc::Type::Blob(float_packed.clone()));
 
     let result = state.process(buffer_msg);
-    let expected = X32ProcessResult::Meters((0, float_original.clone().to_vec()));
+    let expected = X32ProcessResult::Meters((0, MeterBlob::from(float_packed)));
     assert_eq!(result, expected);
 
+    let X32ProcessResult::Meters((_, blob)) = result else { panic!("expected meters") };
+    assert_eq!(blob.len(), 5);
+    assert_eq!(blob.get(3), Some(0.5));
+    assert_eq!(blob.get(5), None);
+    assert_eq!(blob.iter().last(), Some(0.75));
+    assert_eq!(blob.to_vec(), float_original.to_vec());
+
     let mut buffer_msg = osc::Message::new("/meters/0");
     buffer_msg.add_item(String::from("bad type"));
     let result = state.process(buffer_msg);
-    assert_eq!(result, X32ProcessResult::NoOperation);
-}
\ No newline at end of file
+    assert_eq!(result, X32ProcessResult::Unhandled { address : String::from("/meters/0") });
+}
+#[test]
+fn compatibility_report() {
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/ch/01/mix ON -10.0 OFF +0 OFF -oo"));
+    state.process(osc::Message::new("/fx/1/type"));
+    state.process(osc::Message::new("/fx/4/par/01"));
+    state.process(osc::Message::new("/-usb/dir"));
+    state.process(make_node_message("/fx/1 HALL"));
+
+    let report = state.compatibility_report();
+
+    assert!(report.is_partial());
+    assert_eq!(report.known, 1);
+    assert_eq!(report.unknown, 4);
+    assert_eq!(report.by_prefix.get("/fx"), Some(&2));
+    assert_eq!(report.by_prefix.get("/-usb"), Some(&1));
+    assert_eq!(report.by_prefix.get("node /fx"), Some(&1));
+
+    state.reset_compatibility();
+    assert!(!state.compatibility_report().is_partial());
+}
+
+#[test]
+fn latin1_scribble_strip() {
+    let mut state = X32Console::default();
+    let buffer = osc::Buffer::from(vec![
+        0x2f, 0x63, 0x68, 0x2f, 0x30, 0x31, 0x2f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x2f, 0x6e, 0x61, 0x6d, 0x65, 0x0, 0x0,
+        0x2c, 0x73, 0x0, 0x0,
+        0x43, 0x61, 0x66, 0xe9, 0x0, 0x0, 0x0, 0x0,
+    ]);
+
+    assert_eq!(state.process(buffer.clone()), X32ProcessResult::NoOperation);
+
+    state.decode_options.text = osc::TextDecoding::Latin1;
+    assert!(matches!(state.process(buffer), X32ProcessResult::Fader(_)));
+    assert_eq!(state.fader(&FaderIndex::Channel(1)).expect("invalid fader").name(), "Café");
+}
+
+#[test]
+fn address_remapping() {
+    let mut state = X32Console::default();
+    state.address_map.add_prefix("/foh/node", "node").strip_prefix("/foh");
+
+    let mut mute = osc::Message::new("/foh/ch/01/mix/on");
+    mute.add_item(0_i32);
+    assert!(matches!(state.process(mute), X32ProcessResult::Fader(_)));
+    assert!(!state.fader(&FaderIndex::Channel(1)).expect("valid fader").is_on().0);
+
+    let mut node = osc::Message::new("/foh/node");
+    node.add_item(String::from("/ch/02/config \"Snare\" 1 RD 2"));
+    state.process(node);
+    assert_eq!(state.fader(&FaderIndex::Channel(2)).expect("valid fader").name(), "Snare");
+
+    let requests = state.request(x32::ConsoleRequest::KeepAlive());
+    let request = osc::Message::try_from(requests[0].clone()).expect("valid message");
+    assert_eq!(request.address, "/foh/xremote");
+
+    state.address_map.clear();
+    let requests = state.request(x32::ConsoleRequest::KeepAlive());
+    let request = osc::Message::try_from(requests[0].clone()).expect("valid message");
+    assert_eq!(request.address, "/xremote");
+}
+
+#[test]
+fn compare_strips() {
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/ch/11/mix ON -6.0 OFF +0 OFF -oo"));
+    state.process(make_node_message("/ch/11/config \"Vox A\" 1 RD 11"));
+    state.process(make_node_message("/ch/12/mix ON -10.0 OFF +0 OFF -oo"));
+    state.process(make_node_message("/ch/12/config \"Vox B\" 1 RD 12"));
+
+    let diffs = state.compare_strips(&FaderIndex::Channel(11), &FaderIndex::Channel(12));
+    let fields:Vec<&str> = diffs.iter().map(|v| v.field).collect();
+
+    assert_eq!(fields, vec!["label", "level"]);
+    assert_eq!(diffs[1].to_string(), "level: -6.0 dB -> -10.0 dB");
+
+    assert!(state.compare_strips(&FaderIndex::Channel(11), &FaderIndex::Channel(11)).is_empty());
+    assert!(state.compare_strips(&FaderIndex::Channel(11), &FaderIndex::Unknown).is_empty());
+}
+
+#[test]
+fn typed_meters() {
+    let blob_for = |count : usize| {
+        let mut bytes = i32::try_from(count).expect("small count").to_le_bytes().to_vec();
+        for v in 0..count {
+            bytes.extend((f32::from(u16::try_from(v).expect("small index")) / 100.0).to_le_bytes());
+        }
+        MeterBlob::from(bytes)
+    };
+
+    let Ok(x32::Meters::Overview { channels, buses, matrices, .. }) = blob_for(70).decode(0) else { panic!("expected overview") };
+    assert_eq!(channels[0], 0.0);
+    assert_eq!(buses[0], 0.48);
+    assert_eq!(matrices[5], 0.69);
+
+    let Ok(x32::Meters::Outputs { main, mono, mono_gr, .. }) = blob_for(49).decode(2) else { panic!("expected outputs") };
+    assert_eq!(main, [0.22, 0.23]);
+    assert_eq!(mono, 0.24);
+    assert_eq!(mono_gr, 0.48);
+
+    assert!(blob_for(10).decode(1).is_err());
+    assert_eq!(blob_for(2).decode(9), Ok(x32::Meters::Raw(x32::MeterBank::Other(9), vec![0.0, 0.01])));
+    assert_eq!(x32::MeterBank::from_id(5).id(), 5);
+}
+
+#[test]
+fn copy_strip() {
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/ch/11/mix ON -6.0 OFF +0 OFF -oo"));
+    state.process(make_node_message("/ch/11/config \"Vox A\" 1 RD 11"));
+    state.process(make_node_message("/ch/12/mix OFF -10.0 OFF +0 OFF -oo"));
+    state.process(make_node_message("/ch/12/config \"Vox B\" 1 RD 12"));
+
+    let plan = x32::ConsoleCommand::copy_strip(&state, &FaderIndex::Channel(11), &FaderIndex::Channel(12), x32::CopyScope::Mix)
+        .expect("valid strips");
+
+    let fields:Vec<&str> = plan.diff().iter().map(|v| v.field).collect();
+    assert_eq!(fields, vec!["level", "on"]);
+    assert_eq!(plan.diff()[0].to_string(), "level: -10.0 dB -> -6.0 dB");
+
+    let addresses:Vec<&str> = plan.messages().iter().map(|v| v.address.as_str()).collect();
+    assert_eq!(addresses, vec!["/ch/12/mix/fader", "/ch/12/mix/on"]);
+
+    for buffer in plan.to_buffers() { state.process(buffer); }
+
+    assert!(state.compare_strips(&FaderIndex::Channel(11), &FaderIndex::Channel(12)).iter().all(|v| v.field == "label"));
+    assert!(x32::ConsoleCommand::copy_strip(&state, &FaderIndex::Channel(11), &FaderIndex::Channel(12), x32::CopyScope::Mix)
+        .expect("valid strips")
+        .is_empty());
+    assert!(x32::ConsoleCommand::copy_strip(&state, &FaderIndex::Unknown, &FaderIndex::Channel(12), x32::CopyScope::All).is_err());
+
+    state.process(make_node_message("/ch/11/preamp +4.5 OFF OFF 24 -300"));
+    let plan = x32::ConsoleCommand::copy_strip(&state, &FaderIndex::Channel(11), &FaderIndex::Channel(12), x32::CopyScope::Trim)
+        .expect("valid strips");
+    let addresses:Vec<&str> = plan.messages().iter().map(|v| v.address.as_str()).collect();
+    assert_eq!(addresses, vec!["/ch/12/preamp/trim"]);
+
+    for buffer in plan.to_buffers() { state.process(buffer); }
+    assert_eq!(state.fader(&FaderIndex::Channel(12)).and_then(Fader::trim).map(|v| v.1), Some(String::from("+4.5 dB")));
+    assert!(x32::ConsoleCommand::copy_strip(&state, &FaderIndex::Channel(11), &FaderIndex::Bus(1), x32::CopyScope::Trim)
+        .expect("valid strips")
+        .is_empty());
+}
+
+#[test]
+fn send_matrix() {
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/ch/05/mix/03/level").float(0.6).build());
+    state.process(osc::Message::builder("/ch/05/mix/03/on").int(0).build());
+    state.process(make_node_message("/bus/02/mix/04 ON -10.0"));
+
+    let send = state.send(&FaderIndex::Channel(5), &FaderIndex::Bus(3)).expect("tracked send");
+    assert_eq!(send.level, 0.6);
+    assert!(!send.is_on);
+
+    let send = state.send(&FaderIndex::Bus(2), &FaderIndex::Matrix(4)).expect("tracked send");
+    assert!(send.is_on);
+    assert_eq!(send.level, Fader::level_from_string("-10.0"));
+
+    assert_eq!(state.send(&FaderIndex::Channel(5), &FaderIndex::Bus(4)), None);
+    assert_eq!(state.sends.from_strip(&FaderIndex::Channel(5)).count(), 1);
+
+    state.process(osc::Message::builder("/ch/05/mix/17/level").float(0.6).build());
+    state.process(osc::Message::builder("/dca/1/mix/01/level").float(0.6).build());
+    assert_eq!(state.sends.len(), 2);
+
+    state.reset();
+    assert!(state.sends.is_empty());
+}
+
+#[test]
+fn preamp_state() {
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/headamp/004/gain").float(0.5).build());
+    state.process(osc::Message::builder("/headamp/004/phantom").int(1).build());
+    state.process(osc::Message::builder("/ch/05/preamp/invert").int(1).build());
+    state.process(make_node_message("/ch/06/preamp -6.0 OFF ON 24 101"));
+    state.process(make_node_message("/headamp/005 +30.5 OFF"));
+
+    let preamp = state.preamp(&FaderIndex::Channel(5)).expect("tracked preamp");
+    assert_eq!(preamp.gain, 24.0);
+    assert!(preamp.phantom);
+    assert!(preamp.invert);
+
+    let preamp = state.preamp(&FaderIndex::Channel(6)).expect("tracked preamp");
+    assert_eq!(preamp.trim, -6.0);
+    assert_eq!(preamp.gain, 30.5);
+    assert!(!preamp.invert);
+
+    state.process(osc::Message::builder("/headamp/100/gain").float(0.5).build());
+    assert_eq!(state.preamps.len(), 2);
+    assert_eq!(state.preamp(&FaderIndex::Channel(7)), None);
+}
+
+#[test]
+fn eq_state() {
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/ch/03/eq/on").int(1).build());
+    state.process(osc::Message::builder("/ch/03/eq/2/type").int(4).build());
+    state.process(osc::Message::builder("/ch/03/eq/2/g").float(1.0).build());
+    state.process(osc::Message::builder("/ch/03/eq/2/f").float(0.0).build());
+    state.process(make_node_message("/bus/02/eq ON"));
+    state.process(make_node_message("/bus/02/eq/6 HCut 1k97 -3.50 0.7"));
+
+    let eq = state.eq(&FaderIndex::Channel(3)).expect("tracked eq");
+    assert!(eq.is_on);
+    assert_eq!(eq.bands.len(), 4);
+    assert_eq!(eq.bands[1].kind, x32::EqType::HighShelf);
+    assert_eq!(eq.bands[1].gain, 15.0);
+    assert_eq!(eq.bands[1].frequency, 20.0);
+
+    let eq = state.eq(&FaderIndex::Bus(2)).expect("tracked eq");
+    assert_eq!(eq.bands.len(), 6);
+    assert_eq!(eq.bands[5].kind, x32::EqType::HighCut);
+    assert!((eq.bands[5].frequency - 1970.0).abs() < 0.01);
+    assert_eq!(eq.bands[5].gain, -3.5);
+    assert_eq!(eq.bands[5].q, 0.7);
+
+    state.process(osc::Message::builder("/ch/03/eq/5/g").float(1.0).build());
+    state.process(osc::Message::builder("/dca/1/eq/on").int(1).build());
+    assert_eq!(state.eqs.len(), 2);
+
+    state.reset();
+    assert_eq!(state.eq(&FaderIndex::Channel(3)), None);
+}
+
+#[test]
+fn mute_groups() {
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/ch/01/mix/on").int(1).build());
+    state.process(osc::Message::builder("/ch/02/mix/on").int(1).build());
+    state.process(osc::Message::builder("/ch/01/grp/mute").int(0b000_101).build());
+    state.process(make_node_message("/ch/02/grp %00000000 %000010"));
+
+    let fader = state.fader(&FaderIndex::Channel(1)).expect("valid fader");
+    assert_eq!(fader.mute_groups(), 0b101);
+    assert!(fader.in_mute_group(1));
+    assert!(fader.in_mute_group(3));
+    assert!(!fader.in_mute_group(2));
+    assert!(state.fader(&FaderIndex::Channel(2)).expect("valid fader").in_mute_group(2));
+
+    let mut update = x32::updates::FaderUpdate::new(FaderIndex::Channel(3));
+    update.mute_groups = Some(0b1100_0000);
+    let _ = state.faders.update(update);
+    let fader = state.fader(&FaderIndex::Channel(3)).expect("valid fader");
+    assert!(!fader.in_mute_group(7));
+    assert!(!fader.in_mute_group(8));
+
+    assert_eq!(state.is_effectively_muted(&FaderIndex::Channel(1)), Some(false));
+
+    state.process(osc::Message::builder("/config/mute/3").int(1).build());
+    assert_eq!(state.mute_groups, [false, false, true, false, false, false]);
+    assert_eq!(state.is_effectively_muted(&FaderIndex::Channel(1)), Some(true));
+    assert_eq!(state.is_effectively_muted(&FaderIndex::Channel(2)), Some(false));
+
+    state.process(make_node_message("/config/mute OFF ON OFF OFF OFF OFF"));
+    assert_eq!(state.mute_groups, [false, true, false, false, false, false]);
+    assert_eq!(state.is_effectively_muted(&FaderIndex::Channel(1)), Some(false));
+    assert_eq!(state.is_effectively_muted(&FaderIndex::Channel(2)), Some(true));
+
+    // muted channel, no groups
+    assert_eq!(state.is_effectively_muted(&FaderIndex::Channel(3)), Some(true));
+
+    state.reset();
+    assert_eq!(state.mute_groups, [false; 6]);
+    assert_eq!(state.fader(&FaderIndex::Channel(1)).expect("valid fader").mute_groups(), 0);
+}
+
+#[test]
+fn dca_effective_level() {
+    static LINEAR:x32_osc_state::enums::LinearTaper = x32_osc_state::enums::LinearTaper::new(-60.0, 0.0);
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/ch/01/mix ON -10.0 ON +0 OFF -oo"));
+    state.process(make_node_message("/dca/1 ON -5.0"));
+    state.process(make_node_message("/dca/2 ON +0.0"));
+    state.process(make_node_message("/ch/01/grp %00000011 %000000"));
+
+    let fader = state.fader(&FaderIndex::Channel(1)).expect("valid fader");
+    assert_eq!(fader.dca_groups(), 0b11);
+    assert_eq!(fader.dcas().collect::<Vec<_>>(), vec![FaderIndex::Dca(1), FaderIndex::Dca(2)]);
+
+    // levels from node strings are quantized to the console's 1024 steps
+    let (level, _) = state.effective_level(&FaderIndex::Channel(1)).expect("valid fader");
+    assert!((Fader::level_to_db(level) + 15.0).abs() < 0.2);
+
+    state.process(osc::Message::builder("/ch/01/grp/dca").int(0b10).build());
+    let (level, _) = state.effective_level(&FaderIndex::Channel(1)).expect("valid fader");
+    assert!((Fader::level_to_db(level) + 10.0).abs() < 0.2);
+
+    state.process(osc::Message::builder("/dca/2/on").int(0).build());
+    assert_eq!(state.effective_level(&FaderIndex::Channel(1)), Some((0.0, String::from("-oo dB"))));
+
+    assert_eq!(state.effective_level(&FaderIndex::Unknown), None);
+
+    // each fader's own taper - a linear channel under an X32 curve DCA
+    state.process(osc::Message::builder("/dca/2/on").int(1).build());
+    state.process(osc::Message::builder("/dca/2/fader").float(0.75).build());
+    state.process(osc::Message::builder("/ch/01/mix/fader").float(0.5).build());
+    state.faders.get_mut(&FaderIndex::Channel(1)).expect("valid fader").set_taper(&LINEAR);
+
+    let (level, display) = state.effective_level(&FaderIndex::Channel(1)).expect("valid fader");
+    assert!((level - 0.5).abs() < 0.001);
+    assert_eq!(display, "-30.0 dB");
+
+    state.process(osc::Message::builder("/dca/2/fader").float(0.5).build());
+    let (level, _) = state.effective_level(&FaderIndex::Channel(1)).expect("valid fader");
+    assert!((level - (1.0 - 40.0 / 60.0)).abs() < 0.001);
+}
+
+#[test]
+fn solo_state() {
+    let mut state = X32Console::default();
+
+    assert_eq!(state.process(osc::Message::builder("/-stat/solosw/05").int(1).build()), X32ProcessResult::Solo(true));
+    assert_eq!(state.process(make_node_message("/-stat/solosw/73 ON")), X32ProcessResult::Solo(true));
+    assert!(state.solo.is_soloed(&FaderIndex::Channel(5)));
+    assert!(state.solo.is_soloed(&FaderIndex::Dca(1)));
+    assert_eq!(state.solo.strips().count(), 2);
+
+    state.process(osc::Message::builder("/-stat/solosw/05").int(0).build());
+    assert_eq!(state.process(make_node_message("/-stat/solosw/73 OFF")), X32ProcessResult::Solo(false));
+
+    assert_eq!(state.process(osc::Message::builder("/-stat/solo").int(1).build()), X32ProcessResult::Solo(true));
+    assert!(state.solo.is_active());
+
+    // fx returns are not tracked
+    assert_eq!(state.process(osc::Message::builder("/-stat/solosw/41").int(1).build()), X32ProcessResult::NoOperation);
+
+    state.reset();
+    assert!(!state.solo.is_active());
+}
+
+#[test]
+fn recorder_state() {
+    use std::time::Duration;
+    use x32::{RecorderKind, TransportState};
+
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/-stat/tape/state").int(4).build());
+    state.process(osc::Message::builder("/-stat/tape/etime").int(95).build());
+    state.process(osc::Message::builder("/-stat/urec/state").int(2).build());
+    state.process(osc::Message::builder("/-stat/urec/etime").int(1500).build());
+    state.process(osc::Message::builder("/-stat/urec/rtime").int(3600).build());
+
+    assert!(state.recorder(RecorderKind::Tape).is_recording());
+    assert_eq!(state.tape.elapsed, Duration::from_secs(95));
+    assert_eq!(state.card.state, TransportState::Play);
+    assert_eq!(state.card.elapsed, Duration::from_millis(1500));
+    assert_eq!(state.card.remaining, Duration::from_secs(3600));
+
+    state.process(make_node_message("/-stat/urec/state REC"));
+    assert!(state.card.is_recording());
+
+    for v in 0..=6 {
+        assert_eq!(TransportState::from_int(RecorderKind::Tape, v).as_int(RecorderKind::Tape), Some(v));
+    }
+    for v in 0..=3 {
+        assert_eq!(TransportState::from_int(RecorderKind::Card, v).as_int(RecorderKind::Card), Some(v));
+    }
+    assert_eq!(TransportState::Rewind.as_int(RecorderKind::Card), None);
+    assert!(Vec::<osc::Buffer>::from(x32::ConsoleRequest::Recorder(RecorderKind::Card, TransportState::FastForward)).is_empty());
+
+    state.reset();
+    assert_eq!(state.card.state, TransportState::Stop);
+}
+
+#[test]
+fn output_routing() {
+    use x32::{OutputPort, OutputSource};
+
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/outputs/main/07/src").int(10).build());
+    state.process(osc::Message::builder("/outputs/main/03/src").int(26).build());
+    state.process(make_node_message("/outputs/p16/01 74 POST"));
+
+    assert_eq!(state.routing.output(&OutputPort::Main(7)), Some(OutputSource::Bus(7)));
+    assert_eq!(state.routing.output(&OutputPort::P16(1)), Some(OutputSource::MonitorLeft));
+
+    // default routing - XLR 7 is main output 7
+    assert_eq!(state.routing.xlr_source(7), Some(OutputSource::Bus(7)));
+
+    state.process(osc::Message::builder("/config/routing/OUT/5-8").int(40).build());
+    assert_eq!(state.routing.block("OUT", "5-8"), Some("OUT1-4"));
+    assert_eq!(state.routing.xlr_output(7), Some(OutputPort::Main(3)));
+    assert_eq!(state.routing.xlr_source(7), Some(OutputSource::Channel(1)));
+
+    state.process(make_node_message("/config/routing/OUT OUT1-4 AN1-4 OUT9-12 OUT13-16"));
+    assert_eq!(state.routing.block("OUT", "5-8"), Some("AN1-4"));
+    assert_eq!(state.routing.xlr_output(7), None);
+
+    state.process(osc::Message::builder("/outputs/main/17/src").int(1).build());
+    state.process(osc::Message::builder("/outputs/main/01/src").int(99).build());
+    assert_eq!(state.routing.output(&OutputPort::Main(1)), None);
+
+    state.reset();
+    assert_eq!(state.routing.output(&OutputPort::Main(7)), None);
+}
+
+#[test]
+fn user_controls() {
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/config/userctrl/B/color").int(2).build());
+    state.process(osc::Message::builder("/config/userctrl/B/enc/2").string("MP13000").build());
+    state.process(osc::Message::builder("/config/userctrl/B/btn/7").string("O0102").build());
+    state.process(make_node_message("/config/userctrl/C RD"));
+    state.process(make_node_message("/config/userctrl/C/btn \"\" P0500 \"\" \"\" \"\" \"\" \"\" X0001"));
+
+    let bank = state.user_bank('B').expect("valid bank");
+    assert_eq!(bank.color, FaderColor::Green);
+    assert_eq!(bank.encoder(2), Some("MP13000"));
+    assert_eq!(bank.button(7), Some("O0102"));
+    assert_eq!(bank.button(5), Some(""));
+    assert_eq!(bank.button(4), None);
+    assert_eq!(bank.button_assignment(7), Some(x32::UserAssignment::Code { family : 'O', argument : String::from("0102") }));
+    assert_eq!(bank.button_assignment(5), Some(x32::UserAssignment::Unassigned));
+
+    let bank = state.user_bank('C').expect("valid bank");
+    assert_eq!(bank.color, FaderColor::Red);
+    assert_eq!(bank.button(6), Some("P0500"));
+    assert_eq!(bank.button(12), Some("X0001"));
+
+    assert!(state.user_bank('D').is_none());
+}
+
+#[test]
+fn monitor_state() {
+    let mut state = X32Console::default();
+
+    state.process(osc::Message::builder("/config/talk/enable").int(1).build());
+    state.process(osc::Message::builder("/config/talk/A/destmap").int(0b1010).build());
+    state.process(osc::Message::builder("/-stat/talk/A").int(1).build());
+    state.process(osc::Message::builder("/config/solo/level").float(0.75).build());
+    state.process(osc::Message::builder("/config/solo/dim").int(1).build());
+    state.process(make_node_message("/config/talk/B -10.0 OFF ON %000000000000000001"));
+
+    assert!(state.monitor.talk_enable);
+    assert!(state.monitor.is_talking());
+    assert!(state.monitor.talkback[0].talks_to_bus(2));
+    assert!(!state.monitor.talkback[0].talks_to_bus(3));
+    assert_eq!(state.monitor.level, 0.75);
+    assert!(state.monitor.dim);
+    assert!(!state.monitor.mono);
+
+    let talk_b = state.monitor.talkback[1];
+    assert!(talk_b.latch);
+    assert!(talk_b.talks_to_bus(1));
+    assert_eq!(Fader::level_to_string(talk_b.level), "-10.0 dB");
+
+    state.process(osc::Message::builder("/-stat/talk/A").int(0).build());
+    assert!(!state.monitor.is_talking());
+}
+
+#[test]
+fn scene_safes() {
+    let mut state = X32Console::default();
+    assert!(state.safes().is_empty());
+
+    state.process(make_node_message("/-show/showfile/show \"Sunday\" 0 0 0 0 %0000000000000101 %0000000000000001 0 0 %0000000100000000 0"));
+    assert!(state.safes().is_strip_safe(&FaderIndex::Channel(1)));
+    assert!(!state.safes().is_strip_safe(&FaderIndex::Channel(2)));
+    assert!(state.safes().is_strip_safe(&FaderIndex::Channel(3)));
+    assert!(state.safes().is_strip_safe(&FaderIndex::Channel(17)));
+    assert!(state.safes().is_strip_safe(&FaderIndex::Dca(1)));
+
+    state.process(osc::Message::builder("/-show/showfile/show/chan16").int(0).build());
+    state.process(osc::Message::builder("/-show/showfile/show/buses").int(0b100).build());
+    assert_eq!(state.safes().safe_strips(), vec![FaderIndex::Channel(17), FaderIndex::Bus(3), FaderIndex::Dca(1)]);
+
+    state.reset();
+    assert!(state.safes().is_empty());
+
+    state.process(make_node_message("/config/scene_safes 0 0 0 0 %0000000000000010 0 0 0 0 0"));
+    assert_eq!(state.safes().safe_strips(), vec![FaderIndex::Channel(2)]);
+
+    state.process(osc::Message::builder("/config/scene_safes/lrmtxdca").int(0b1).build());
+    assert!(state.safes().is_strip_safe(&FaderIndex::Main(1)));
+    assert!(state.safes().is_strip_safe(&FaderIndex::Channel(2)));
+}
+
+#[test]
+fn fader_change_flags() {
+    use x32_osc_state::enums::FaderChange;
+
+    let mut state = X32Console::default();
+
+    let X32ProcessResult::Fader((_, change)) = state.process(make_node_message("/ch/01/mix ON -10.0 ON -20 OFF -oo")) else {
+        panic!("expected fader result");
+    };
+    assert!(change.contains(FaderChange::LEVEL | FaderChange::MUTE | FaderChange::PAN));
+    assert!(!change.contains(FaderChange::LABEL));
+
+    let X32ProcessResult::Fader((_, change)) = state.process(osc::Message::builder("/ch/01/mix/on").int(1).build()) else {
+        panic!("expected fader result");
+    };
+    assert!(change.is_empty());
+
+    let X32ProcessResult::Fader((_, change)) = state.process(make_node_message("/ch/01/config \"Kick\" 1 RD 1")) else {
+        panic!("expected fader result");
+    };
+    assert_eq!(change, FaderChange::LABEL | FaderChange::COLOR);
+}
+
+#[test]
+fn process_bundles() {
+    let mut state = X32Console::default();
+
+    let bundle = osc::Bundle::new_with_messages(vec![
+        osc::Message::builder("/ch/01/mix/fader").float(0.5).build(),
+        osc::Message::builder("/ch/02/mix/fader").float(0.25).build(),
+        osc::Message::builder("/not/tracked").int(1).build(),
+        osc::Message::builder("/-stat/solosw/03").int(1).build(),
+    ]);
+    let buffer = osc::Buffer::try_from(osc::Packet::Bundle(bundle)).expect("valid bundle");
+
+    let results = state.process_datagram(buffer);
+    assert_eq!(results.len(), 4);
+    assert!(matches!(&results[0], X32ProcessResult::Fader((fader, _)) if fader.source() == &FaderIndex::Channel(1)));
+    assert!(matches!(&results[1], X32ProcessResult::Fader((fader, _)) if fader.source() == &FaderIndex::Channel(2)));
+    assert_eq!(results[2], X32ProcessResult::Unhandled { address : String::from("/not/tracked") });
+    assert_eq!(results[3], X32ProcessResult::Solo(true));
+
+    let results = state.process_all(vec![
+        osc::Message::builder("/ch/03/mix/on").int(1).build(),
+        osc::Message::builder("/ch/04/mix/on").int(1).build(),
+    ]);
+    assert_eq!(results.len(), 2);
+
+    assert!(state.process_datagram(osc::Buffer::from(vec![1, 2, 3])).is_empty());
+}
+
+#[test]
+fn observers() {
+    let mut state = X32Console::default();
+
+    let cues = state.subscribe(x32::EventFilter::CurrentCue);
+    let strip = state.subscribe(x32::EventFilter::Fader(FaderIndex::Channel(2)));
+    let dropped = state.subscribe(x32::EventFilter::All);
+    drop(dropped);
+    assert_eq!(state.observers().len(), 3);
+
+    state.process(osc::Message::builder("/-show/prepos/current").int(3).build());
+    state.process(osc::Message::builder("/ch/02/mix/on").int(1).build());
+    state.process(osc::Message::builder("/ch/02/mix/on").int(1).build());
+    state.process(osc::Message::builder("/ch/03/mix/on").int(1).build());
+
+    assert_eq!(cues.try_iter().count(), 1);
+    assert_eq!(strip.try_iter().count(), 1);
+    assert_eq!(state.observers().len(), 2);
+}
+
+#[test]
+fn change_generations() {
+    use x32::ChangeKey;
+
+    let mut state = X32Console::default();
+    assert!(state.changes_since(0).keys.is_empty());
+
+    state.process(osc::Message::builder("/ch/01/mix/fader").float(0.5).build());
+    state.process(osc::Message::builder("/-show/prepos/current").int(2).build());
+
+    let first = state.changes_since(0);
+    assert_eq!(first.generation, 2);
+    assert_eq!(first.keys, vec![ChangeKey::Fader(FaderIndex::Channel(1)), ChangeKey::CurrentCue]);
+
+    // repeated values are not changes
+    state.process(osc::Message::builder("/ch/01/mix/fader").float(0.5).build());
+    assert!(state.changes_since(first.generation).keys.is_empty());
+
+    state.process(osc::Message::builder("/ch/02/mix/fader").float(0.5).build());
+    state.process(osc::Message::builder("/ch/01/mix/fader").float(0.7).build());
+    state.process(osc::Message::builder("/ch/01/mix/fader").float(0.8).build());
+
+    let second = state.changes_since(first.generation);
+    assert_eq!(second.generation, 5);
+    assert_eq!(second.keys, vec![ChangeKey::Fader(FaderIndex::Channel(1)), ChangeKey::Fader(FaderIndex::Channel(2))]);
+
+    // replacing the state records what it changed
+    state.process(make_node_message("/-show/showfile/scene/004 \"Act 2\" \"\" %111111110 1"));
+    let snapshot = state.snapshot();
+    let strip = state.subscribe(x32::EventFilter::Fader(FaderIndex::Channel(2)));
+    let before_reset = state.changes_since(0).generation;
+
+    state.reset();
+    let cleared = state.changes_since(before_reset);
+    assert_eq!(cleared.keys, vec![ChangeKey::Fader(FaderIndex::Channel(1)), ChangeKey::Fader(FaderIndex::Channel(2)), ChangeKey::Scene(4)]);
+    assert_eq!(strip.try_iter().count(), 1);
+
+    state.restore(&snapshot);
+    let restored = state.changes_since(cleared.generation);
+    assert_eq!(restored.keys, cleared.keys);
+    assert_eq!(strip.try_iter().count(), 1);
+
+    state.restore(&snapshot);
+    assert!(state.changes_since(restored.generation).keys.is_empty());
+}
+
+#[test]
+fn staleness() {
+    use std::time::Duration;
+
+    let mut state = X32Console::default();
+    assert_eq!(state.stale_faders(Duration::from_secs(3600)).len(), 72);
+    assert_eq!(state.subsystem_age(x32::Subsystem::Faders), None);
+
+    state.process(osc::Message::builder("/ch/01/mix/fader").float(0.5).build());
+    state.process(osc::Message::builder("/dca/2/on").int(1).build());
+
+    let outdated = state.stale_faders(Duration::from_secs(3600));
+    assert_eq!(outdated.len(), 70);
+    assert!(!outdated.contains(&FaderIndex::Channel(1)));
+    assert!(!outdated.contains(&FaderIndex::Dca(2)));
+    assert!(state.fader(&FaderIndex::Channel(1)).expect("valid fader").age().is_some());
+    assert!(state.fader(&FaderIndex::Channel(2)).expect("valid fader").age().is_none());
+    assert!(state.subsystem_age(x32::Subsystem::Faders).is_some());
+    assert_eq!(state.subsystem_age(x32::Subsystem::CueList), None);
+
+    let now = std::time::Instant::now();
+    state.process_at(osc::Message::builder("/ch/02/mix/fader").float(0.5).build(), now);
+    assert_eq!(state.subsystem_updated(x32::Subsystem::Faders), Some(now));
+
+    state.reset();
+    assert_eq!(state.stale_faders(Duration::from_secs(3600)).len(), 72);
+}
+
+#[test]
+fn targeted_refresh() {
+    use std::time::Duration;
+
+    let mut state = X32Console::default();
+    assert_eq!(state.refresh_requests(Duration::from_secs(3600)).len(), x32::ConsoleRequest::full_update().len());
+
+    state.process(make_node_message("/ch/01/mix ON -10.0 ON +0 OFF -oo"));
+    state.process(make_node_message("/ch/01/config \"Kick\" 1 RD 1"));
+    state.process(osc::Message::builder("/-show/prepos/current").int(3).build());
+
+    // channel 1 (2 requests) and current cue are no longer needed
+    assert_eq!(state.refresh_requests(Duration::from_secs(3600)).len(), x32::ConsoleRequest::full_update().len() - 3);
+
+    // freshness is per node - a reported mix does not cover the config
+    state.process(make_node_message("/ch/02/mix ON -10.0 ON +0 OFF -oo"));
+    let requests = state.refresh_requests(Duration::from_secs(3600));
+    assert_eq!(requests.len(), x32::ConsoleRequest::full_update().len() - 4);
+    assert!(requests.contains(&osc::Message::new_with_string("/node", "ch/02/config").try_into().expect("valid buffer")));
+    assert_eq!(state.node_age("ch/01/config").map(|v| v < Duration::from_secs(3600)), Some(true));
+
+    // only requested nodes are tracked - other traffic is not remembered
+    let tracked = state.replied().len();
+    state.process(osc::Message::builder("/fx/1/par/01").float(0.5).build());
+    state.process(osc::Message::builder("/ch/03/mix/fader").float(0.5).build());
+    assert_eq!(state.replied().len(), tracked);
+    assert_eq!(state.node_age("fx/1/par/01"), None);
+
+    // everything is stale with a zero threshold
+    assert_eq!(state.refresh_requests(Duration::ZERO).len(), x32::ConsoleRequest::full_update().len());
+
+    state.reset();
+    assert_eq!(state.node_age("ch/01/config"), None);
+}
+
+#[test]
+fn step_through_cues() {
+    let mut state = X32Console::default();
+
+    state.process(make_node_message("/-show/showfile/cue/000 100 \"Cue Idx0 Num100\" 1 1 0 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/cue/001 110 \"Cue Idx1 Skipped\" 0 -1 -1 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/cue/002 200 \"Cue Idx2 Num200\" 1 -1 -1 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/scene/001 \"SceneAAA\" \"aaa\" %111111110 1"));
+    assert!(state.cues[&1].skip);
+    assert!(!state.cues[&2].skip);
+
+    let first = state.request(x32::ConsoleRequest::NextCue());
+    assert_eq!(first, Vec::<osc::Buffer>::from(x32::ConsoleRequest::GoCue(0)));
+    assert!(state.request(x32::ConsoleRequest::PrevCue()).is_empty());
+
+    state.process(make_node_message("/-show/prepos/current 0"));
+    assert_eq!(state.request(x32::ConsoleRequest::NextCue()), Vec::<osc::Buffer>::from(x32::ConsoleRequest::GoCue(2)));
+
+    state.process(make_node_message("/-show/prepos/current 2"));
+    assert!(state.request(x32::ConsoleRequest::NextCue()).is_empty());
+    assert_eq!(state.request(x32::ConsoleRequest::PrevCue()), Vec::<osc::Buffer>::from(x32::ConsoleRequest::GoCue(0)));
+
+    state.process(make_node_message("/-prefs/show_control SCENES"));
+    assert_eq!(state.request(x32::ConsoleRequest::PrevCue()), Vec::<osc::Buffer>::from(x32::ConsoleRequest::LoadScene(1)));
+
+    let msg = osc::Message::try_from(first[0].clone()).expect("valid message");
+    assert_eq!(msg.address, "/-action/gocue");
+    assert_eq!(msg.args, vec![osc::Type::Integer(0)]);
+}
+
+#[test]
+fn link_health() {
+    use std::time::{Duration, Instant};
+
+    let mut state = X32Console::default();
+    let events = state.subscribe(x32::EventFilter::Connection);
+    assert_eq!(state.link().state(), x32::ConnectionState::Lost);
+
+    assert_eq!(state.process(osc::Buffer::from(vec![0x1, 0x2])), X32ProcessResult::NoOperation);
+    assert_eq!(state.link().state(), x32::ConnectionState::Lost);
+
+    let result = state.process(osc::Message::builder("/ch/01/mix/fader").float(0.5).build());
+    assert!(matches!(result, X32ProcessResult::Fader(_)));
+    assert_eq!(state.link().state(), x32::ConnectionState::Connected);
+    assert_eq!(state.check_link(Instant::now()), X32ProcessResult::NoOperation);
+
+    let later = Instant::now() + Duration::from_secs(6);
+    assert_eq!(state.check_link(later), X32ProcessResult::Connection(x32::ConnectionState::Degraded));
+    assert_eq!(state.check_link(later), X32ProcessResult::NoOperation);
+    assert_eq!(state.check_link(later + Duration::from_secs(10)), X32ProcessResult::Connection(x32::ConnectionState::Lost));
+
+    let results = state.process_all([osc::Message::builder("/ch/01/mix/fader").float(0.6).build()]);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0], X32ProcessResult::Connection(x32::ConnectionState::Connected));
+    assert!(matches!(results[1], X32ProcessResult::Fader(_)));
+    assert_eq!(state.link().state(), x32::ConnectionState::Connected);
+
+    let much_later = later + Duration::from_secs(60);
+    assert_eq!(state.check_link(much_later), X32ProcessResult::Connection(x32::ConnectionState::Lost));
+    let result = state.process_at(osc::Message::builder("/ch/01/mix/fader").float(0.7).build(), much_later);
+    assert_eq!(result, X32ProcessResult::Connection(x32::ConnectionState::Connected));
+    assert!((state.faders.get(&FaderIndex::Channel(1)).expect("channel 1").level().0 - 0.7).abs() < f32::EPSILON);
+
+    let seen:Vec<X32ProcessResult> = events.try_iter().collect();
+    assert_eq!(seen, vec![
+        X32ProcessResult::Connection(x32::ConnectionState::Connected),
+        X32ProcessResult::Connection(x32::ConnectionState::Degraded),
+        X32ProcessResult::Connection(x32::ConnectionState::Lost),
+        X32ProcessResult::Connection(x32::ConnectionState::Connected),
+        X32ProcessResult::Connection(x32::ConnectionState::Lost),
+        X32ProcessResult::Connection(x32::ConnectionState::Connected),
+    ]);
+}
+
+#[test]
+fn capture_and_replay() {
+    use std::time::Duration;
+    use x32_osc_state::x32::capture::{Recorder, Replayer};
+    const HEADER_LEN : usize = 8;
+
+    let mut live = X32Console::default();
+    let mut recorder = Recorder::new(vec![]).expect("header written");
+
+    let traffic:Vec<osc::Buffer> = vec![
+        osc::Message::builder("/ch/02/mix/fader").float(0.25).build().try_into().expect("valid buffer"),
+        osc::Buffer::from(vec![1, 2, 3]),
+        make_node_message("/-show/showfile/cue/000 100 \"Cue Idx0 Num100\" 1 -1 -1 0 1 0 0").try_into().expect("valid buffer"),
+    ];
+    for buffer in traffic {
+        recorder.process(&mut live, buffer).expect("record written");
+    }
+    let capture = recorder.finish().expect("capture flushed");
+
+    let records:Vec<(Duration, osc::Buffer)> = Replayer::new(capture.as_slice()).expect("valid capture")
+        .collect::<Result<_, _>>().expect("valid records");
+    assert_eq!(records.len(), 3);
+    assert!(records.windows(2).all(|v| v[0].0 <= v[1].0));
+
+    let mut replayed = X32Console::default();
+    let results = Replayer::new(capture.as_slice()).expect("valid capture").replay(&mut replayed, true).expect("capture replayed");
+    assert_eq!(results.len(), 2);
+    assert_eq!(replayed.fader(&FaderIndex::Channel(2)).zip(live.fader(&FaderIndex::Channel(2))).map(|(a, b)| a.changes_from(b)), Some(x32_osc_state::enums::FaderChange::NONE));
+    assert_eq!(replayed.cue_list_size(), (1, 0, 0));
+
+    assert!(Replayer::new(&b"not a capture"[..]).is_err());
+    assert!(Replayer::new(&capture[..capture.len() - 1]).expect("valid header").any(|v| v.is_err()));
+
+    // a record cut off inside its timestamp is an error, not a clean end
+    let truncated = &capture[..HEADER_LEN + 3];
+    let error = Replayer::new(truncated).expect("valid header").find_map(Result::err).expect("truncated record");
+    assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+
+    let mut huge = capture[..HEADER_LEN].to_vec();
+    huge.extend([0_u8; 8]);
+    huge.extend(0x7fff_ffff_u32.to_le_bytes());
+    let error = Replayer::new(huge.as_slice()).expect("valid header").find_map(Result::err).expect("oversized record");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    // the longest record the recorder accepts replays, one byte more is refused
+    let mut recorder = Recorder::new(vec![]).expect("header written");
+    let largest = osc::Buffer::from(vec![0_u8; 65_535]);
+    recorder.record_at(Duration::ZERO, &largest).expect("largest record written");
+    let error = recorder.record_at(Duration::ZERO, &osc::Buffer::from(vec![0_u8; 65_536])).expect_err("oversized record");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+    let capture = recorder.finish().expect("capture flushed");
+    let records:Vec<(Duration, osc::Buffer)> = Replayer::new(capture.as_slice()).expect("valid capture")
+        .collect::<Result<_, _>>().expect("valid records");
+    assert_eq!(records, vec![(Duration::ZERO, largest)]);
+}
+
+#[test]
+fn show_file_import() {
+    let text = "#2.7#\n\
+        show \"Spring Concert\" 0 0 0 0 0 0 0 0 0 0 \"4.06\"\n\
+        cue/000 100 \"House Open\" 0 1 -1 0 1 0 0\n\
+        cue/001 110 \"Welcome\" 0 2 0 0 1 0 0\n\
+        cue/002 200 \"Act One\" 0 -1 -1 0 1 0 0\n\
+        scene/001 \"Preshow\" \"\" %000000000 1\n\
+        scene/002 \"Speech\" \"\" %000000000 1\n\
+        snippet/000 \"Mics Up\" 1 1 0 32768 1\n\
+        ch/01/config \"Ignored\" 1 RD 1\n";
+
+    let show = x32::ShowFile::parse(text).expect("valid show");
+    assert_eq!(show.version(), "2.7");
+    assert_eq!(show.name(), "Spring Concert");
+    assert_eq!(show.lines().len(), 7);
+
+    let mut state = X32Console::default();
+    state.process(make_node_message("/-show/showfile/cue/050 100 \"Stale\" 1 -1 -1 0 1 0 0"));
+    show.apply_to(&mut state);
+
+    assert_eq!(state.cue_list_size(), (3, 2, 1));
+    assert_eq!(state.cue(1).expect("valid cue").cue_number, "1.1.0");
+    assert_eq!(state.cue(1).expect("valid cue").scene, Some(2));
+    assert_eq!(state.cue(1).expect("valid cue").snippet, Some(0));
+    assert!(state.cue(50).is_none());
+    assert_eq!(state.fader(&FaderIndex::Channel(1)).expect("valid fader").label(), "");
+
+    assert_eq!(x32::ShowFile::parse("cue/000 100 \"x\" 0 1 -1 0 1 0 0"), Err(x32_osc_state::enums::Error::X32(x32_osc_state::enums::X32Error::InvalidFile)));
+    assert_eq!(x32::ShowFile::parse("#2.7#\ncue/000 100"), Err(x32_osc_state::enums::Error::X32(x32_osc_state::enums::X32Error::MalformedPacket)));
+}
+
+#[test]
+fn snippet_file() {
+    let text = "#2.7# \"Band On\" 1 1 0 32768 1\n\
+        /ch/01/mix ON -10.0 ON +0 OFF -oo\n\
+        /ch/01/config \"Kick In\" 1 RD 1\n\
+        /dca/1 ON +0.0\n\
+        /ch/01/preamp +5.0 OFF OFF 24 160\n\
+        /fx/1/type PLATE\n";
+
+    let snippet = x32::SnippetFile::parse(text).expect("valid snippet");
+    assert_eq!(snippet.version(), "2.7");
+    assert_eq!(snippet.name(), "Band On");
+    assert_eq!(snippet.commands().len(), 5);
+    assert_eq!(snippet.commands()[0].to_string(), "/ch/01/mix ON -10.0 ON +0 OFF -oo");
+
+    let mut state = X32Console::default();
+    assert_eq!(snippet.apply_to(&mut state), 4);
+    assert!(state.fader(&FaderIndex::Dca(1)).expect("valid fader").is_on().0);
+    assert_eq!(state.fader(&FaderIndex::Channel(1)).expect("valid fader").level().1, "-10.0 dB");
+    assert_eq!(state.fader(&FaderIndex::Channel(1)).expect("valid fader").label(), "Kick In");
+
+    let buffers:Vec<osc::Buffer> = snippet.requests().into_iter().flat_map(|v| state.request(v)).collect();
+    assert_eq!(buffers.len(), 5);
+    let first = osc::Message::try_from(buffers[0].clone()).expect("valid message");
+    assert_eq!(first.address, "/");
+    assert_eq!(first.args, vec![osc::Type::String(String::from("/ch/01/mix ON -10.0 ON +0 OFF -oo"))]);
+
+    assert!(x32::SnippetFile::parse("/ch/01/mix ON -10.0").is_err());
+}
+
+#[test]
+fn export_cue_list() {
+    use x32_osc_state::display::ExportFormat;
+
+    let mut state = X32Console::default();
+    state.process(make_node_message("/-show/showfile/cue/000 100 \"Doors, House\" 1 1 -1 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/cue/001 110 \"A|B\" 1 -1 0 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/scene/001 \"Preshow\" \"\" %111111110 1"));
+    state.process(make_node_message("/-show/showfile/snippet/000 \"Mics\" 1 1 0 32768 1 "));
+
+    assert_eq!(state.export_cues(ExportFormat::Csv),
+        "Cue,Name,Scene,Snippet\n\
+         1.0.0,\"Doors, House\",01:Preshow,--\n\
+         1.1.0,A|B,--,00:Mics\n");
+
+    assert_eq!(state.export_cues(ExportFormat::Markdown),
+        "| Cue | Name | Scene | Snippet |\n\
+         |---|---|---|---|\n\
+         | 1.0.0 | Doors, House | 01:Preshow | -- |\n\
+         | 1.1.0 | A\\|B | -- | 00:Mics |\n");
+}
+
+#[test]
+fn active_cue_summary() {
+    use x32_osc_state::enums::CueSummary;
+
+    let mut state = X32Console::default();
+    state.process(make_node_message("/-show/showfile/cue/000 100 \"Intro\" 1 1 0 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/scene/001 \"SceneAAA\" \"aaa\" %111111110 1"));
+    state.process(make_node_message("/-show/showfile/snippet/000 \"Snip-001\" 1 1 0 32768 1 "));
+    assert_eq!(state.active_cue_info(), None);
+
+    state.process(make_node_message("/-show/prepos/current 0"));
+    assert_eq!(state.active_cue_info(), Some(CueSummary {
+        number : String::from("1.0.0"),
+        name : String::from("Intro"),
+        scene : Some((1, Some(String::from("SceneAAA")))),
+        snippet : Some((0, Some(String::from("Snip-001")))),
+    }));
+
+    state.process(make_node_message("/-prefs/show_control SCENES"));
+    state.process(make_node_message("/-show/prepos/current 1"));
+    assert_eq!(state.active_cue_info(), Some(CueSummary {
+        number : String::from("01"),
+        name : String::from("SceneAAA"),
+        scene : Some((1, Some(String::from("SceneAAA")))),
+        snippet : None,
+    }));
+
+    state.process(make_node_message("/-show/prepos/current 5"));
+    assert_eq!(state.active_cue_info(), None);
+    assert_eq!(state.active_cue(), "Scene: --");
+
+    state.process(make_node_message("/-prefs/show_control CUES"));
+    state.process(make_node_message("/-show/showfile/cue/001 110 \"Verse\" 1 4 -1 0 1 0 0"));
+    state.process(make_node_message("/-show/prepos/current 1"));
+    assert_eq!(state.active_cue_info().and_then(|v| v.scene), Some((4, None)));
+    assert_eq!(state.active_cue(), "Cue: 1.1.0 :: Verse [--] [--]");
+}
+
+#[test]
+fn find_faders_by_name() {
+    let mut state = X32Console::default();
+    state.process(make_node_message("/ch/12/config \"Pastor Mic\" 1 RD 1"));
+    state.process(make_node_message("/ch/03/config \"Pastor Mic Spare\" 1 RD 1"));
+    state.process(make_node_message("/bus/02/config \"pastor mic\" 1 RD 1"));
+
+    assert_eq!(state.faders.find_by_name("Pastor Mic"), vec![FaderIndex::Channel(12)]);
+    assert_eq!(state.faders.find_by_name("Ch05"), vec![FaderIndex::Channel(5)]);
+    assert_eq!(state.faders.find_by_name_ignore_case(" PASTOR MIC "), vec![FaderIndex::Channel(12), FaderIndex::Bus(2)]);
+    assert_eq!(state.faders.find_by_name_fuzzy("past mic"), vec![FaderIndex::Channel(12), FaderIndex::Bus(2), FaderIndex::Channel(3)]);
+    assert_eq!(state.faders.find_by_name_fuzzy("spare"), vec![FaderIndex::Channel(3)]);
+    assert!(state.faders.find_by_name_fuzzy("  ").is_empty());
+    assert!(state.faders.find_by_name("Nobody").is_empty());
+}
+
+#[test]
+fn iterate_faders() {
+    use x32_osc_state::enums::FaderBankKey;
+
+    let mut state = X32Console::default();
+    state.process(make_node_message("/bus/04/config \"Wedge\" 1 RD 1"));
+
+    assert_eq!(state.faders.iter().count(), 72);
+    assert_eq!(state.faders.iter().next().map(|(idx, _)| idx.clone()), Some(FaderIndex::Channel(1)));
+    assert!(state.faders.iter().all(|(idx, fader)| fader.source() == idx));
+
+    let buses:Vec<String> = state.faders.iter_bank(&FaderBankKey::Bus).map(|(_, f)| f.name().to_owned()).collect();
+    assert_eq!(buses.len(), 16);
+    assert_eq!(buses[3], "Wedge");
+
+    let mut dcas = 0;
+    for (idx, _) in &state.faders {
+        if matches!(idx, FaderIndex::Dca(_)) { dcas += 1; }
+    }
+    assert_eq!(dcas, 8);
+
+    let last = state.faders.clone().into_iter().last().expect("faders");
+    assert_eq!(last.source(), &FaderIndex::Main(2));
+    assert_eq!(state.faders.clone().into_iter().len(), 72);
+    assert!(state.faders.clone().into_iter().zip(&state.faders).all(|(owned, (idx, _))| owned.source() == idx));
+}
+
+#[test]
+fn borrow_faders() {
+    let mut state = X32Console::default();
+    state.process(make_node_message("/ch/05/config \"Snare\" 1 YE 1"));
+
+    let borrowed = state.faders.get_ref(&FaderIndex::Channel(5)).expect("valid fader");
+    assert_eq!(borrowed.name(), "Snare");
+    assert_eq!(Some(borrowed), state.fader(&FaderIndex::Channel(5)));
+    assert_eq!(state.faders.get(&FaderIndex::Channel(5)).as_ref(), Some(borrowed));
+
+    assert!(state.faders.get_ref(&FaderIndex::Channel(33)).is_none());
+    assert!(state.faders.get_ref(&FaderIndex::Unknown).is_none());
+}
+
+#[test]
+fn fader_trim() {
+    use x32_osc_state::enums::FaderChange;
+
+    let mut state = X32Console::default();
+    assert_eq!(state.fader(&FaderIndex::Channel(2)).expect("valid fader").trim(), None);
+
+    let result = state.process(osc::Message::builder("/ch/02/preamp/trim").float(0.75).build());
+    let X32ProcessResult::Fader((fader, change)) = result else { panic!("expected fader result") };
+    assert!(change.contains(FaderChange::TRIM));
+    assert_eq!(fader.trim(), Some((9.0, String::from("+9.0 dB"))));
+
+    state.process(make_node_message("/ch/02/preamp -4.5 OFF ON 24 101"));
+    let fader = state.fader(&FaderIndex::Channel(2)).expect("valid fader");
+    assert_eq!(fader.trim(), Some((-4.5, String::from("-4.5 dB"))));
+    assert_eq!(fader.pan(), (0.5, String::from("C")));
+
+    assert_eq!(
+        state.process(osc::Message::builder("/ch/02/preamp/invert").int(1).build()),
+        X32ProcessResult::NoOperation
+    );
+
+    state.reset();
+    assert_eq!(state.fader(&FaderIndex::Channel(2)).expect("valid fader").trim(), None);
+}
+
+#[test]
+fn cue_list_edits() {
+    use x32_osc_state::enums::ShowMode;
+    use x32_osc_state::x32::EventFilter;
+
+    let mut state = X32Console::default();
+    let edits = state.subscribe(EventFilter::CueList);
+
+    assert_eq!(
+        state.process(make_node_message("/-show/showfile/cue/004 100 \"Intro\" 1 -1 -1 0 1 0 0")),
+        X32ProcessResult::CueListChanged { kind : ShowMode::Cues, index : 4 }
+    );
+    assert_eq!(
+        state.process(make_node_message("/-show/showfile/scene/012 \"Verse\" \"\" %000000000 1")),
+        X32ProcessResult::CueListChanged { kind : ShowMode::Scenes, index : 12 }
+    );
+    assert_eq!(
+        state.process(make_node_message("/-show/showfile/snippet/099 \"Fx\" 1 1 0 32768 1")),
+        X32ProcessResult::CueListChanged { kind : ShowMode::Snippets, index : 99 }
+    );
+
+    // beyond the console's list size, still tracked
+    assert_eq!(
+        state.process(make_node_message("/-show/showfile/snippet/150 \"Fx\" 1 1 0 32768 1")),
+        X32ProcessResult::CueListChanged { kind : ShowMode::Snippets, index : 150 }
+    );
+
+    // position changes stay CurrentCue
+    assert!(matches!(state.process(make_node_message("/-show/prepos/current 4")), X32ProcessResult::CurrentCue(_)));
+
+    assert_eq!(edits.try_iter().count(), 4);
+}
+
+#[test]
+fn unhandled_messages() {
+    use x32_osc_state::x32::EventFilter;
+
+    let mut state = X32Console::default();
+    let unhandled = state.subscribe(EventFilter::Unhandled);
+
+    assert_eq!(
+        state.process(osc::Message::builder("/fx/1/par/01").float(0.5).build()),
+        X32ProcessResult::Unhandled { address : String::from("/fx/1/par/01") }
+    );
+
+    // undecodable data is not a message at all
+    assert_eq!(state.process(osc::Buffer::from(vec![1, 2, 3])), X32ProcessResult::NoOperation);
+
+    assert_eq!(unhandled.try_iter().count(), 1);
+    assert_eq!(state.compatibility_report().unknown, 1);
+}
+
+#[test]
+fn process_errors() {
+    use x32_osc_state::enums::{Error, X32Error};
+
+    let mut state = X32Console::default();
+    let errors = state.subscribe_errors();
+
+    assert!(state.try_process(osc::Buffer::from(vec![1, 2, 3])).is_err());
+    assert_eq!(
+        state.try_process(osc::Message::builder("/ch/40/mix/fader").float(0.5).build()),
+        Err(Error::X32(X32Error::InvalidFader))
+    );
+    assert!(matches!(
+        state.try_process(osc::Message::builder("/ch/01/mix/fader").float(0.5).build()),
+        Ok(X32ProcessResult::Fader(_))
+    ));
+    assert!(matches!(
+        state.try_process(osc::Message::builder("/fx/1/par/01").float(0.5).build()),
+        Ok(X32ProcessResult::Unhandled { .. })
+    ));
+
+    // process still folds errors into NoOperation
+    assert_eq!(state.process(osc::Buffer::from(vec![1, 2, 3])), X32ProcessResult::NoOperation);
+
+    assert_eq!(errors.try_iter().count(), 3);
+    assert_eq!(state.compatibility_report().malformed, 3);
+    assert_eq!(state.compatibility_report().unknown, 1);
+}
+
+#[test]
+fn traffic_stats() {
+    use x32_osc_state::enums::{Error, X32Error};
+
+    let mut state = X32Console::default();
+
+    let fader:osc::Buffer = osc::Message::builder("/ch/01/mix/fader").float(0.5).build().try_into().expect("valid buffer");
+    let fader_bytes = fader.len();
+    state.process(fader.clone());
+    state.process(fader);
+    state.process(make_node_message("/ch/02/mix ON -10.0 OFF +0 OFF -oo"));
+    state.process(osc::Buffer::from(vec![1, 2, 3]));
+    state.process(osc::Message::builder("/ch/40/mix/fader").float(0.5).build());
+
+    let counters = state.stats();
+    assert_eq!(counters.packets, 5);
+    assert_eq!(counters.bytes, u64::try_from(fader_bytes * 2 + 3).expect("fits"));
+    assert_eq!(counters.failures, 2);
+    assert_eq!(counters.last_error, Some(Error::X32(X32Error::InvalidFader)));
+    assert_eq!(counters.by_address.get("/ch/02/mix"), Some(&1));
+    assert_eq!(counters.busiest(1), vec![("/ch/01/mix/fader", 2)]);
+
+    let period = state.take_stats();
+    assert_eq!(period.packets, 5);
+    assert_eq!(state.stats().packets, 0);
+    assert!(state.stats().by_address.is_empty());
+
+    let bundle:osc::Buffer = osc::Bundle::new_with_messages(vec![
+        osc::Message::builder("/ch/01/mix/fader").float(0.5).build(),
+        osc::Message::builder("/ch/02/mix/fader").float(0.5).build(),
+    ]).try_into().expect("valid buffer");
+    let bundle_bytes = bundle.len();
+    state.process_datagram(bundle);
+    state.process_datagram(osc::Buffer::from(vec![1, 2, 3]));
+    assert_eq!(state.stats().packets, 3);
+    assert_eq!(state.stats().bytes, u64::try_from(bundle_bytes + 3).expect("fits"));
+    assert_eq!(state.stats().failures, 1);
+
+    for index in 0..x32::Stats::MAX_ADDRESSES + 10 {
+        state.process(osc::Message::builder(&format!("/junk/{index}")).int(1).build());
+    }
+    assert_eq!(state.stats().by_address.len(), x32::Stats::MAX_ADDRESSES);
+    assert_eq!(state.stats().other, 12);
+}
+
+#[test]
+fn process_at_receive_time() {
+    use std::time::{Duration, Instant};
+
+    let mut state = X32Console::default();
+    let received = Instant::now().checked_sub(Duration::from_secs(120)).expect("valid instant");
+
+    let result = state.process_at(osc::Message::builder("/ch/01/mix/fader").float(0.5).build(), received);
+    assert!(matches!(result, X32ProcessResult::Fader(_)));
+    state.process(osc::Message::builder("/ch/02/mix/fader").float(0.5).build());
+
+    assert!(state.fader(&FaderIndex::Channel(1)).and_then(Fader::age).expect("fader updated") >= Duration::from_secs(120));
+    assert!(state.fader(&FaderIndex::Channel(2)).and_then(Fader::age).expect("fader updated") < Duration::from_secs(120));
+
+    let outdated = state.stale_faders(Duration::from_secs(60));
+    assert!(outdated.contains(&FaderIndex::Channel(1)));
+    assert!(!outdated.contains(&FaderIndex::Channel(2)));
+
+    let mut replayed = X32Console::default();
+    replayed.process_at(make_node_message("/ch/03/mix ON -10.0 OFF +0 OFF -oo"), received);
+    assert!(replayed.subsystem_age(x32::Subsystem::Faders).expect("subsystem updated") >= Duration::from_secs(120));
+    assert!(replayed.try_process_at(osc::Buffer::from(vec![1, 2, 3]), received).is_err());
+}
+
+#[test]
+fn sparse_cue_lists() {
+    let mut state = X32Console::default();
+    assert!(state.cues.is_empty());
+
+    state.process(make_node_message("/-show/showfile/scene/099 \"Last\" \"\" %000000000 1"));
+    state.process(make_node_message("/-show/showfile/cue/750 100 \"Late\" 0 99 -1 0 1 0 0"));
+    state.process(make_node_message("/-show/showfile/cue/002 100 \"Early\" 0 -1 -1 0 1 0 0"));
+
+    assert_eq!(state.cue_list_size(), (2, 1, 0));
+    assert_eq!(state.cues.keys().copied().collect::<Vec<_>>(), vec![2, 750]);
+    assert_eq!(state.cue(750).map(|v| v.name.as_str()), Some("Late"));
+    assert_eq!(state.cue_scene(state.cue(750).expect("valid cue")), Some("Last"));
+
+    let copy = state.clone();
+    state.clear_cues();
+
+    assert_eq!(copy.scene(99), Some("Last"));
+    assert_eq!(state.scene(99), None);
+    assert_eq!(state.cue_list_size(), (0, 0, 0));
+}
+
+#[test]
+fn remove_cue_entries() {
+    use x32_osc_state::enums::ShowMode;
+    use x32_osc_state::x32::{ChangeKey, EventFilter};
+
+    let mut state = X32Console::default();
+    for index in [1, 2, 5, 9] {
+        state.process(make_node_message(&format!("/-show/showfile/cue/{index:03} 100 \"Cue\" 0 -1 -1 0 1 0 0")));
+    }
+    state.process(make_node_message("/-show/showfile/scene/003 \"Verse\" \"\" %000000000 1"));
+
+    let edits = state.subscribe(EventFilter::CueList);
+    let since = state.changes_since(0).generation;
+
+    assert_eq!(state.remove_cue(ShowMode::Cues, 2), X32ProcessResult::CueListChanged { kind : ShowMode::Cues, index : 2 });
+    assert_eq!(state.remove_cue(ShowMode::Cues, 2), X32ProcessResult::NoOperation);
+    assert_eq!(state.remove_cue(ShowMode::Snippets, 3), X32ProcessResult::NoOperation);
+
+    #[expect(clippy::reversed_empty_ranges)]
+    let inverted = state.clear_range(ShowMode::Cues, 10..5);
+    assert!(inverted.is_empty());
+    assert_eq!(state.clear_range(ShowMode::Cues, 4..).len(), 2);
+    assert_eq!(state.cues.keys().copied().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(state.scene(3), Some("Verse"));
+
+    assert_eq!(state.clear_range(ShowMode::Scenes, ..).len(), 1);
+    assert_eq!(state.cue_list_size(), (1, 0, 0));
+
+    assert_eq!(edits.try_iter().count(), 4);
+    assert!(state.changes_since(since).keys.contains(&ChangeKey::Cue(5)));
+}
+
+#[test]
+fn shared_fader_names() {
+    use std::sync::Arc;
+
+    let mut state = X32Console::default();
+    let unlabeled = state.fader(&FaderIndex::Channel(3)).expect("valid fader");
+    assert_eq!(unlabeled.name(), "Ch03");
+    assert_eq!(&*unlabeled.shared_label(), "");
+
+    state.process(make_node_message("/ch/03/config \"Bass\" 1 GN 1"));
+    let fader = state.fader(&FaderIndex::Channel(3)).expect("valid fader");
+    let held = fader.shared_name();
+
+    assert_eq!(&*held, "Bass");
+    assert!(Arc::ptr_eq(&held, &fader.shared_label()));
+    assert!(Arc::ptr_eq(&held, &fader.clone().shared_name()));
+
+    // repeating the label keeps the shared copy
+    state.process(make_node_message("/ch/03/config \"Bass\" 1 GN 1"));
+    assert!(Arc::ptr_eq(&held, &state.fader(&FaderIndex::Channel(3)).expect("valid fader").shared_name()));
+
+    state.process(osc::Message::builder("/ch/03/config/name").string("").build());
+    assert_eq!(state.fader(&FaderIndex::Channel(3)).expect("valid fader").name(), "Ch03");
+    assert_eq!(&*held, "Bass");
+}
+
+#[test]
+fn xair_dialect() {
+    let mut state = X32Console::new();
+    state.dialect = x32::Dialect::XAir;
+
+    let lr = state.process(osc::Message::builder("/lr/mix/fader").float(0.25).build());
+    let bus = state.process(make_node_message("/bus/3/mix ON -10 OFF +0"));
+
+    assert!(matches!(lr, X32ProcessResult::Fader((v, _)) if *v.source() == FaderIndex::Main(1)));
+    assert!(matches!(bus, X32ProcessResult::Fader((v, _)) if *v.source() == FaderIndex::Bus(3)));
+    assert_eq!(state.fader(&FaderIndex::Main(1)).map(|v| v.level().0), Some(0.25));
+
+    let sent:Vec<osc::Message> = state.request(x32::ConsoleRequest::SetLevel(FaderIndex::Bus(2), x32::FaderLevel::new(0.5).expect("finite level"))).into_iter()
+        .chain(state.request(x32::ConsoleRequest::Fader(FaderIndex::Main(1))))
+        .chain(state.request(x32::ConsoleRequest::KeepAlive()))
+        .map(|v| osc::Message::try_from(v).expect("valid message"))
+        .collect();
+
+    assert_eq!(sent[0].address, "/bus/2/mix/fader");
+    assert_eq!(sent[1].args, vec![osc::Type::String(String::from("lr/mix"))]);
+    assert_eq!(sent[2].args, vec![osc::Type::String(String::from("lr/config"))]);
+    assert_eq!(sent[3].address, "/xremotenfb");
+
+    assert_eq!(state.full_update().len(), 27 * 2);
+    assert!(state.stale_faders(std::time::Duration::ZERO).iter().all(|v| x32::Dialect::XAir.has_fader(v)));
+    assert!(!x32::Dialect::XAir.has_fader(&FaderIndex::Matrix(1)));
+}
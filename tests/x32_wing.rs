@@ -0,0 +1,47 @@
+#![cfg(feature = "wing")]
+use x32_osc_state::{osc, x32, X32Console, X32ProcessResult};
+use x32_osc_state::enums::{Error, Fader, FaderIndex, X32Error};
+
+#[test]
+fn wing_replies() {
+    let mut state = X32Console::new();
+    state.dialect = x32::Dialect::Wing;
+
+    let level = state.process(osc::Message::builder("/ch/5/fdr").string("0.0").float(0.75).float(0.0).build());
+    let mute = state.process(osc::Message::builder("/bus/2/mute").int(1).build());
+    let name = state.process(osc::Message::new_with_string("/main/1/name", "Main LR"));
+    let bottom = state.process(osc::Message::new_with_string("/dca/3/fdr", "-oo"));
+
+    assert!(matches!(level, X32ProcessResult::Fader(_)));
+    assert!(matches!(mute, X32ProcessResult::Fader(_)));
+    assert!(matches!(name, X32ProcessResult::Fader(_)));
+    assert!(matches!(bottom, X32ProcessResult::Fader(_)));
+
+    assert_eq!(state.fader(&FaderIndex::Channel(5)).map(|v| v.level().1), Some(String::from("+0.0 dB")));
+    assert_eq!(state.fader(&FaderIndex::Bus(2)).map(|v| v.is_on().0), Some(false));
+    assert_eq!(state.fader(&FaderIndex::Main(1)).map(Fader::name), Some("Main LR"));
+    assert_eq!(state.fader(&FaderIndex::Dca(3)).map(|v| v.level().0), Some(0.0));
+
+    assert_eq!(state.try_process(osc::Message::builder("/ch/40/fdr").float(0.0).build()), Err(Error::X32(X32Error::InvalidFader)));
+    assert_eq!(state.try_process(osc::Message::new("/ch/1/fdr")), Err(Error::X32(X32Error::MalformedPacket)));
+    assert!(matches!(state.process(osc::Message::new("/ch/1/mix/fader")), X32ProcessResult::Unhandled { .. }));
+}
+
+#[test]
+fn wing_requests() {
+    let mut state = X32Console::new();
+    state.dialect = x32::Dialect::Wing;
+
+    let sent:Vec<osc::Message> = state.request(x32::ConsoleRequest::Fader(FaderIndex::Aux(2))).into_iter()
+        .chain(state.request(x32::ConsoleRequest::SetMute(FaderIndex::Channel(7), true)))
+        .chain(state.request(x32::ConsoleRequest::KeepAlive()))
+        .map(|v| osc::Message::try_from(v).expect("valid message"))
+        .collect();
+
+    let addresses:Vec<&str> = sent.iter().map(|v| v.address.as_str()).collect();
+    assert_eq!(addresses, vec!["/aux/2/fdr", "/aux/2/mute", "/aux/2/name", "/ch/7/mute", "/*S"]);
+    assert_eq!(sent[3].args, vec![osc::Type::Integer(1)]);
+
+    assert!(state.request(x32::ConsoleRequest::ShowInfo()).is_empty());
+    assert_eq!(state.full_update().len(), 3 * 72);
+}